@@ -29,6 +29,25 @@
 //! }
 //! ```
 //!
+//! A closure which returns a `Result` will throw the error into JavaScript
+//! when it returns `Err`:
+//!
+//! ```rust
+//! let parse = |text: String| -> Result< i32, String > {
+//!     text.parse().map_err( |_| format!( "not a number: {}", text ) )
+//! };
+//!
+//! js! {
+//!     var parse = @{parse};
+//!     try {
+//!         parse( "Bob" );
+//!     } catch( error ) {
+//!         console.log( error );
+//!     }
+//!     parse.drop();
+//! }
+//! ```
+//!
 //! You can also pass arbitrary structures thanks to [serde]:
 //!
 //! ```rust
@@ -179,10 +198,14 @@ pub use webcore::value::{
 pub use webcore::number::Number;
 pub use webcore::object::Object;
 pub use webcore::array::Array;
+pub use webcore::map::{Map, MapEntries};
+pub use webcore::set::Set;
 pub use webcore::symbol::Symbol;
+pub use webcore::big_int::BigInt;
+pub use webcore::iterator::JsIterator;
 
 pub use webcore::unsafe_typed_array::UnsafeTypedArray;
-pub use webcore::once::Once;
+pub use webcore::once::{Once, FnOnceHandle};
 pub use webcore::instance_of::InstanceOf;
 pub use webcore::reference_type::ReferenceType;
 pub use webcore::serialization::JsSerialize;
@@ -217,27 +240,63 @@ pub mod web {
         interval_buffered
     };
 
+    #[cfg(feature = "futures-support")]
+    pub use webapi::event_target::NextEvent;
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub use webapi::js_module::import_module;
+
     pub use webapi::window::{
         Window,
-        window
+        window,
+        ScrollToOptions,
+        ScrollBehavior
     };
+    pub use webapi::window_proxy::WindowProxy;
+    pub use webapi::js_module::JsModule;
+    pub use webapi::message_port::{MessageChannel, MessagePort};
     pub use webapi::document::{
         Document,
-        document
+        document,
+        VisibilityState
     };
+    pub use webapi::cookies::{Cookies, CookieOptions, SameSite};
+    pub use webapi::navigator::{
+        Navigator,
+        navigator,
+        BeaconData
+    };
+    pub use webapi::clipboard::{Clipboard, ClipboardItem};
+    pub use webapi::animation::{Animation, AnimationPlayState, KeyframeAnimationOptions, FillMode, PlaybackDirection};
+    pub use webapi::media_query_list::{MediaQueryList, MediaQueryListener};
     pub use webapi::global::{
         set_timeout,
-        alert
+        alert,
+        has_property,
+        download_bytes
+    };
+    pub use webapi::css::{
+        css_supports,
+        css_supports_condition
     };
+    pub use webapi::css_style_sheet::{StyleSheet, CssStyleSheet, CssRule};
     pub use webapi::cross_origin_setting::CrossOriginSetting;
     pub use webapi::date::Date;
+    pub use webapi::intl::{NumberFormat, DateTimeFormat};
     pub use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle};
     pub use webapi::window::RequestAnimationFrameHandle;
-    pub use webapi::node::{INode, Node, CloneKind, NodeType};
+    pub use webapi::render_loop::RenderLoop;
+    pub use webapi::hotkey::{Hotkey, HotkeyRegistry, HotkeyHandle};
+    pub use webapi::node::{INode, Node, CloneKind, NodeType, DocumentPosition};
     pub use webapi::element::{IElement, Element};
+    pub use webapi::element_builder::ElementBuilder;
     pub use webapi::document_fragment::DocumentFragment;
+    pub use webapi::range::Range;
+    pub use webapi::selection::Selection;
     pub use webapi::text_node::TextNode;
-    pub use webapi::html_element::{IHtmlElement, HtmlElement, Rect};
+    pub use webapi::character_data::ICharacterData;
+    pub use webapi::comment::{Comment, ProcessingInstruction};
+    pub use webapi::html_element::{IHtmlElement, HtmlElement, Rect, ContentEditable};
     pub use webapi::window_or_worker::IWindowOrWorker;
     pub use webapi::parent_node::IParentNode;
     pub use webapi::non_element_parent_node::INonElementParentNode;
@@ -247,18 +306,56 @@ pub mod web {
     pub use webapi::storage::Storage;
     pub use webapi::location::Location;
     pub use webapi::array_buffer::ArrayBuffer;
-    pub use webapi::typed_array::TypedArray;
+    pub use webapi::typed_array::{TypedArray, U8Clamped};
+    pub use webapi::data_view::DataView;
     pub use webapi::file_reader::{FileReader, FileReaderResult, FileReaderReadyState};
     pub use webapi::file_list::FileList;
     pub use webapi::history::History;
     pub use webapi::web_socket::{WebSocket, SocketCloseCode, SocketBinaryType, SocketReadyState};
-    pub use webapi::rendering_context::{RenderingContext, CanvasRenderingContext2d, CanvasGradient, CanvasPattern, CanvasStyle, CompositeOperation, FillRule, ImageData, LineCap, LineJoin, Repetition, TextAlign, TextBaseline, TextMetrics};
+    pub use webapi::rtc::{
+        RtcPeerConnection,
+        RtcConfiguration,
+        RtcIceServer,
+        RtcSessionDescription,
+        RtcSdpType,
+        RtcIceCandidate,
+        RtcDataChannel,
+        RtcDataChannelState
+    };
+    pub use webapi::rendering_context::{RenderingContext, CanvasImageSource, CanvasRenderingContext2d, CanvasGradient, CanvasPattern, CanvasStyle, Path2D, CompositeOperation, FillRule, ImageData, LineCap, LineJoin, Repetition, TextAlign, TextBaseline, TextMetrics};
     pub use webapi::mutation_observer::{MutationObserver, MutationObserverHandle, MutationObserverInit, MutationRecord};
-    pub use webapi::xml_http_request::{XmlHttpRequest, XhrReadyState, XhrResponseType};
+    pub use webapi::tree_walker::{TreeWalker, TreeWalkerHandle, WhatToShow, NodeFilterResult};
+    pub use webapi::xml_http_request::{XmlHttpRequest, XhrReadyState, XhrResponseType, XmlHttpRequestUpload};
+    pub use webapi::readable_stream::{ReadableStream, ReadableStreamReader};
+    pub use webapi::response::Response;
+    pub use webapi::image_bitmap::{ImageBitmap, ImageBitmapSource};
+    pub use webapi::offscreen_canvas::{OffscreenCanvas, OffscreenRenderingContext};
+    pub use webapi::webgl_rendering_context::WebGlRenderingContext;
     pub use webapi::blob::{IBlob, Blob};
+    pub use webapi::url::{Url, UrlSearchParams, ObjectUrl};
+    pub use webapi::url_components::IUrlComponents;
     pub use webapi::html_collection::HtmlCollection;
     pub use webapi::child_node::IChildNode;
     pub use webapi::gamepad::{Gamepad, GamepadButton, GamepadMappingType};
+    pub use webapi::permissions::{Permissions, PermissionStatus, PermissionState};
+    pub use webapi::service_worker::{
+        ServiceWorkerContainer,
+        ServiceWorkerRegistrationOptions,
+        ServiceWorkerRegistration,
+        ServiceWorker,
+        ServiceWorkerState
+    };
+    pub use webapi::cache_storage::{CacheStorage, Cache};
+    pub use webapi::battery::BatteryManager;
+    pub use webapi::screen::{Screen, ScreenOrientation, OrientationType, OrientationLockType};
+    pub use webapi::performance::{
+        Performance,
+        IPerformanceEntry,
+        PerformanceEntry,
+        IPerformanceResourceTiming,
+        PerformanceResourceTiming,
+        PerformanceNavigationTiming
+    };
 
     /// A module containing error types.
     pub mod error {
@@ -273,13 +370,27 @@ pub mod web {
             NotSupportedError,
             SecurityError,
             SyntaxError,
-            TypeError,
             InvalidCharacterError,
-            AbortError
+            AbortError,
+            NotAllowedError,
+            InvalidNodeTypeError,
+            DataCloneError,
+            NamespaceError
         };
-        pub use webapi::error::{IError, Error};
+        pub use webapi::error::{IError, Error, TypeError, RangeError};
+        pub use webapi::html_elements::ImageError;
         pub use webapi::rendering_context::{AddColorStopError, DrawImageError, GetImageDataError};
+        pub use webapi::image_bitmap::CreateImageBitmapError;
+        pub use webapi::clipboard::{ClipboardError, ClipboardItemGetTypeError};
+        pub use webapi::service_worker::ServiceWorkerRegisterError;
+        pub use webapi::range::SetRangeBoundaryError;
+        pub use webapi::css_style_sheet::InsertRuleError;
         pub use webapi::html_elements::UnknownValueError;
+        pub use webapi::node::{InsertNodeError, ReparentError};
+        pub use webapi::element::SetAttributeNsError;
+        pub use webapi::element_builder::BuildElementError;
+        pub use webapi::hotkey::HotkeyParseError;
+        pub use webapi::cookies::InvalidCookieError;
     }
 
     /// A module containing HTML DOM elements.
@@ -290,6 +401,9 @@ pub mod web {
         pub use webapi::html_elements::CanvasElement;
         pub use webapi::html_elements::SelectElement;
         pub use webapi::html_elements::OptionElement;
+        pub use webapi::html_elements::AnchorElement;
+        pub use webapi::html_elements::{TableElement, TableRowElement, TableCellElement};
+        pub use webapi::html_elements::IFrameElement;
     }
 
     /// A module containing JavaScript DOM events.
@@ -362,7 +476,13 @@ pub mod web {
             SocketErrorEvent,
             SocketOpenEvent,
             SocketMessageEvent,
-            SocketMessageData
+            SocketMessageData,
+            MessageEvent
+        };
+
+        pub use webapi::events::rtc::{
+            RtcIceCandidateEvent,
+            RtcDataChannelEvent
         };
 
         pub use webapi::events::history::{
@@ -377,10 +497,37 @@ pub mod web {
             ResourceErrorEvent,
             ResizeEvent,
             ScrollEvent,
+            ScrollEndEvent,
             InputEvent,
             ReadyStateChangeEvent,
             SubmitEvent,
-            SelectionChangeEvent
+            SelectionChangeEvent,
+            BeforeUnloadEvent,
+            VisibilityChangeEvent,
+            OnlineEvent,
+            OfflineEvent
+        };
+
+        pub use webapi::events::battery::{
+            ChargingChangeEvent,
+            LevelChangeEvent,
+            ChargingTimeChangeEvent,
+            DischargingTimeChangeEvent
+        };
+
+        pub use webapi::events::clipboard::{
+            IClipboardEvent,
+            ClipboardRelatedEvent,
+            CopyEvent,
+            CutEvent,
+            PasteEvent
+        };
+
+        pub use webapi::events::error::{
+            ErrorEvent,
+            IPromiseRejectionEvent,
+            UnhandledRejectionEvent,
+            RejectionHandledEvent
         };
 
         pub use webapi::events::focus::{
@@ -389,6 +536,28 @@ pub mod web {
             BlurEvent
         };
 
+        pub use webapi::events::transition::{
+            ITransitionEvent,
+            TransitionEvent,
+            TransitionRunEvent,
+            TransitionStartEvent,
+            TransitionEndEvent,
+            TransitionCancelEvent,
+            IAnimationEvent,
+            AnimationEvent,
+            AnimationStartEvent,
+            AnimationEndEvent,
+            AnimationIterationEvent,
+            AnimationCancelEvent
+        };
+
+        pub use webapi::events::device::{
+            DeviceOrientationEvent,
+            DeviceMotionEvent,
+            DeviceAcceleration,
+            DeviceRotationRate
+        };
+
         pub use webapi::events::gamepad::{
             IGamepadEvent,
             GamepadConnectedEvent,
@@ -451,6 +620,8 @@ pub mod traits {
         IElement,
         IHtmlElement,
         IBlob,
+        ICharacterData,
+        IUrlComponents,
 
         // Mixins.
         IWindowOrWorker,
@@ -0,0 +1,286 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::dom_exception::AbortError;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// Specifies whether the effects of an animation are applied before it starts
+/// and after it ends.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EffectTiming/fill)
+// https://drafts.csswg.org/web-animations-1/#enumdef-fillmode
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FillMode {
+    /// The effects are only applied while the animation is running.
+    None,
+    /// The first keyframe is applied during the delay before the animation starts.
+    Backwards,
+    /// The last keyframe is retained after the animation ends.
+    Forwards,
+    /// Both `Backwards` and `Forwards`.
+    Both,
+    /// Same as `None` for keyframe animations.
+    Auto
+}
+
+/// Specifies in which direction an animation is played.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EffectTiming/direction)
+// https://drafts.csswg.org/web-animations-1/#enumdef-playbackdirection
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PlaybackDirection {
+    /// Every iteration is played forwards.
+    Normal,
+    /// Every iteration is played backwards.
+    Reverse,
+    /// The direction changes after every iteration, starting forwards.
+    Alternate,
+    /// The direction changes after every iteration, starting backwards.
+    AlternateReverse
+}
+
+/// The play state of an [Animation](struct.Animation.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playState)
+// https://drafts.csswg.org/web-animations-1/#enumdef-animationplaystate
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AnimationPlayState {
+    /// The animation has no current time.
+    Idle,
+    /// The animation is running.
+    Running,
+    /// The animation is paused.
+    Paused,
+    /// The animation has reached its end.
+    Finished
+}
+
+/// The timing options of an animation created with [`IElement::animate`](trait.IElement.html#method.animate).
+///
+/// You will usually start from [`KeyframeAnimationOptions::default()`](#impl-Default) and change the fields which you need.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/animate#Parameters)
+// https://drafts.csswg.org/web-animations-1/#dictdef-keyframeanimationoptions
+#[derive(Clone, Debug)]
+pub struct KeyframeAnimationOptions< 'a > {
+    /// The duration of a single iteration, in milliseconds.
+    pub duration: f64,
+
+    /// The delay before the animation starts, in milliseconds.
+    pub delay: f64,
+
+    /// The number of times the animation is repeated; use `std::f64::INFINITY` to repeat it forever.
+    pub iterations: f64,
+
+    /// The timing function used to scale the time, for example `"ease-in-out"` or `"steps(4)"`.
+    pub easing: &'a str,
+
+    /// Specifies whether the effects are applied before the animation starts and after it ends.
+    pub fill: FillMode,
+
+    /// Specifies in which direction the animation is played.
+    pub direction: PlaybackDirection,
+}
+
+impl Default for KeyframeAnimationOptions< 'static > {
+    fn default() -> Self {
+        KeyframeAnimationOptions {
+            duration: 0.0,
+            delay: 0.0,
+            iterations: 1.0,
+            easing: "linear",
+            fill: FillMode::Auto,
+            direction: PlaybackDirection::Normal
+        }
+    }
+}
+
+/// An animation running on an element, as returned by [`IElement::animate`](trait.IElement.html#method.animate).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation)
+// https://drafts.csswg.org/web-animations-1/#the-animation-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Animation")]
+#[reference(subclass_of(EventTarget))]
+pub struct Animation( Reference );
+
+impl IEventTarget for Animation {}
+
+impl Animation {
+    pub(crate) fn new( target: &Reference, keyframes: &Value, options: KeyframeAnimationOptions ) -> Self {
+        js!(
+            return @{target}.animate( @{keyframes}, {
+                duration: @{options.duration},
+                delay: @{options.delay},
+                iterations: @{options.iterations},
+                easing: @{options.easing},
+                fill: @{match options.fill {
+                    FillMode::None => "none",
+                    FillMode::Backwards => "backwards",
+                    FillMode::Forwards => "forwards",
+                    FillMode::Both => "both",
+                    FillMode::Auto => "auto"
+                }},
+                direction: @{match options.direction {
+                    PlaybackDirection::Normal => "normal",
+                    PlaybackDirection::Reverse => "reverse",
+                    PlaybackDirection::Alternate => "alternate",
+                    PlaybackDirection::AlternateReverse => "alternate-reverse"
+                }}
+            } );
+        ).try_into().unwrap()
+    }
+
+    /// Starts or resumes the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/play)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-play
+    pub fn play( &self ) {
+        js! { @(no_return)
+            @{self}.play();
+        }
+    }
+
+    /// Pauses the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/pause)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-pause
+    pub fn pause( &self ) {
+        js! { @(no_return)
+            @{self}.pause();
+        }
+    }
+
+    /// Cancels the animation and removes all of its effects.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/cancel)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-cancel
+    pub fn cancel( &self ) {
+        js! { @(no_return)
+            @{self}.cancel();
+        }
+    }
+
+    /// Seeks to the end of the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/finish)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-finish
+    pub fn finish( &self ) {
+        js! { @(no_return)
+            @{self}.finish();
+        }
+    }
+
+    /// Reverses the playback direction of the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/reverse)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-reverse
+    pub fn reverse( &self ) {
+        js! { @(no_return)
+            @{self}.reverse();
+        }
+    }
+
+    /// Returns the current time of the animation in milliseconds, or `None` if the animation is idle.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/currentTime)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-currenttime
+    pub fn current_time( &self ) -> Option< f64 > {
+        js!(
+            return @{self}.currentTime;
+        ).try_into().unwrap()
+    }
+
+    /// Seeks the animation to the given time in milliseconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/currentTime)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-currenttime
+    pub fn set_current_time( &self, time: f64 ) {
+        js! { @(no_return)
+            @{self}.currentTime = @{time};
+        }
+    }
+
+    /// Returns the playback rate of the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playbackRate)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-playbackrate
+    pub fn playback_rate( &self ) -> f64 {
+        js!(
+            return @{self}.playbackRate;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the playback rate of the animation; negative values play it backwards.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playbackRate)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-playbackrate
+    pub fn set_playback_rate( &self, rate: f64 ) {
+        js! { @(no_return)
+            @{self}.playbackRate = @{rate};
+        }
+    }
+
+    /// Returns the play state of the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playState)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-playstate
+    pub fn play_state( &self ) -> AnimationPlayState {
+        let state: String = js!(
+            return @{self}.playState;
+        ).try_into().unwrap();
+
+        match state.as_str() {
+            "idle" => AnimationPlayState::Idle,
+            "running" => AnimationPlayState::Running,
+            "paused" => AnimationPlayState::Paused,
+            "finished" => AnimationPlayState::Finished,
+            _ => panic!( "Unexpected playState value: {:?}", state )
+        }
+    }
+
+    /// Returns a future which resolves once the animation finishes.
+    ///
+    /// The future fails with an `AbortError` if the animation is canceled.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/finished)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-finished
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn finished( &self ) -> PromiseFuture< Animation, AbortError > {
+        js!(
+            return @{self}.finished;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::element::IElement;
+
+    #[test]
+    fn test_animate() {
+        let element = document().create_element( "div" ).unwrap();
+        let keyframes = js!( return [ { opacity: 0 }, { opacity: 1 } ]; );
+        let animation = element.animate( &keyframes, KeyframeAnimationOptions {
+            duration: 1000.0,
+            ..KeyframeAnimationOptions::default()
+        });
+
+        animation.pause();
+        assert_eq!( animation.play_state(), AnimationPlayState::Paused );
+
+        animation.set_current_time( 500.0 );
+        assert_eq!( animation.current_time(), Some( 500.0 ) );
+
+        animation.finish();
+        assert_eq!( animation.play_state(), AnimationPlayState::Finished );
+
+        animation.cancel();
+        assert_eq!( animation.play_state(), AnimationPlayState::Idle );
+        assert_eq!( animation.current_time(), None );
+    }
+}
@@ -29,6 +29,25 @@ impl ArrayBuffer {
         let length = js!( return @{reference}.byteLength; ).try_into().unwrap();
         length
     }
+
+    /// Returns the length of the buffer, in bytes; same as [len](#method.len).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength)
+    #[inline]
+    pub fn byte_length( &self ) -> u64 {
+        self.len()
+    }
+
+    /// Returns a new `ArrayBuffer` containing a copy of the bytes of this buffer
+    /// from `start` up to (but not including) `end`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-arraybuffer.prototype.slice
+    pub fn slice( &self, start: u64, end: u64 ) -> ArrayBuffer {
+        let start: Value = start.try_into().unwrap();
+        let end: Value = end.try_into().unwrap();
+        js!( return @{self}.slice( @{start}, @{end} ); ).try_into().unwrap()
+    }
 }
 
 // TODO: Implement for other types.
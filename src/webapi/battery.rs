@@ -0,0 +1,66 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+/// The `BatteryManager` interface provides information about the system's battery charge level.
+///
+/// Changes are reported through the [ChargingChangeEvent](event/struct.ChargingChangeEvent.html),
+/// [LevelChangeEvent](event/struct.LevelChangeEvent.html),
+/// [ChargingTimeChangeEvent](event/struct.ChargingTimeChangeEvent.html) and
+/// [DischargingTimeChangeEvent](event/struct.DischargingTimeChangeEvent.html) events.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager)
+// https://w3c.github.io/battery/#the-batterymanager-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "BatteryManager")]
+#[reference(subclass_of(EventTarget))]
+pub struct BatteryManager( Reference );
+
+impl IEventTarget for BatteryManager {}
+
+impl BatteryManager {
+    /// Returns whether the battery is currently being charged.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/charging)
+    // https://w3c.github.io/battery/#dom-batterymanager-charging
+    pub fn charging( &self ) -> bool {
+        js!(
+            return @{self}.charging;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the charge level of the battery, from `0.0` to `1.0`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/level)
+    // https://w3c.github.io/battery/#dom-batterymanager-level
+    pub fn level( &self ) -> f64 {
+        js!(
+            return @{self}.level;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the remaining time in seconds until the battery is fully charged.
+    ///
+    /// This is `0.0` if the battery is already full, and positive infinity
+    /// if it's discharging or the time is unknown.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/chargingTime)
+    // https://w3c.github.io/battery/#dom-batterymanager-chargingtime
+    pub fn charging_time( &self ) -> f64 {
+        js!(
+            return @{self}.chargingTime;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the remaining time in seconds until the battery is completely discharged.
+    ///
+    /// This is positive infinity if the battery is charging or the time is unknown.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/dischargingTime)
+    // https://w3c.github.io/battery/#dom-batterymanager-dischargingtime
+    pub fn discharging_time( &self ) -> f64 {
+        js!(
+            return @{self}.dischargingTime;
+        ).try_into().unwrap()
+    }
+}
@@ -0,0 +1,171 @@
+use webcore::value::Reference;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::try_from::TryInto;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::response::Response;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// The storage for the named [Cache](struct.Cache.html)s of the current origin.
+///
+/// You can get it with [`Window::caches`](struct.Window.html#method.caches).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage)
+// https://w3c.github.io/ServiceWorker/#cachestorage
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "CacheStorage")]
+pub struct CacheStorage( Reference );
+
+impl CacheStorage {
+    /// Returns a future which resolves to the cache with the given `name`,
+    /// creating it if it doesn't exist yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/open)
+    // https://w3c.github.io/ServiceWorker/#cache-storage-open
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn open( &self, name: &str ) -> PromiseFuture< Cache, Error > {
+        js!(
+            return @{self}.open( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to whether a cache with the given `name` exists.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/has)
+    // https://w3c.github.io/ServiceWorker/#cache-storage-has
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn has( &self, name: &str ) -> PromiseFuture< bool, Error > {
+        js!(
+            return @{self}.has( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Deletes the cache with the given `name`, and returns a future which
+    /// resolves to whether it existed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/delete)
+    // https://w3c.github.io/ServiceWorker/#cache-storage-delete
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn delete( &self, name: &str ) -> PromiseFuture< bool, Error > {
+        js!(
+            return @{self}.delete( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the names of all the caches.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/keys)
+    // https://w3c.github.io/ServiceWorker/#cache-storage-keys
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn keys( &self ) -> PromiseFuture< Vec< String >, Error > {
+        js!(
+            return @{self}.keys();
+        ).try_into().unwrap()
+    }
+}
+
+/// A named store of responses, keyed by the URL of their request.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Cache)
+// https://w3c.github.io/ServiceWorker/#cache
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Cache")]
+pub struct Cache( Reference );
+
+impl Cache {
+    /// Stores `response` as the response for `url`, replacing any previous one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Cache/put)
+    // https://w3c.github.io/ServiceWorker/#cache-put
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn put( &self, url: &str, response: &Response ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.put( @{url}, @{response} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the response stored for `url`, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Cache/match)
+    // https://w3c.github.io/ServiceWorker/#cache-match
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn match_( &self, url: &str ) -> PromiseFuture< Option< Response >, Error > {
+        js!(
+            return @{self}.match( @{url} );
+        ).try_into().unwrap()
+    }
+
+    /// Fetches `url` and stores the response. The future fails if the response
+    /// doesn't have a status in the range 200-299.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Cache/add)
+    // https://w3c.github.io/ServiceWorker/#cache-add
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn add( &self, url: &str ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.add( @{url} );
+        ).try_into().unwrap()
+    }
+
+    /// Fetches all of the `urls` and stores their responses. Nothing is stored
+    /// if any of them fails.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Cache/addAll)
+    // https://w3c.github.io/ServiceWorker/#cache-addAll
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn add_all( &self, urls: &[&str] ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.addAll( @{urls} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes the response stored for `url`, and returns a future which
+    /// resolves to whether there was one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Cache/delete)
+    // https://w3c.github.io/ServiceWorker/#cache-delete
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn delete( &self, url: &str ) -> PromiseFuture< bool, Error > {
+        js!(
+            return @{self}.delete( @{url} );
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test", feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use webapi::window::window;
+
+    #[test]
+    fn test_cache_round_trip() {
+        let caches = match window().caches() {
+            Some( caches ) => caches,
+            None => return
+        };
+
+        let response: Response = js!( return new Response( "hello", { status: 201 } ); ).try_into().unwrap();
+        PromiseFuture::spawn_local(
+            caches.open( "stdweb-test" )
+                .and_then( move |cache| cache.put( "/stdweb-test", &response ).map( move |_| cache ) )
+                .and_then( |cache| cache.match_( "/stdweb-test" ).map( move |response| (cache, response) ) )
+                .and_then( |(cache, response)| {
+                    assert_eq!( response.unwrap().status(), 201 );
+                    cache.delete( "/stdweb-test" ).map( move |deleted| (cache, deleted) )
+                })
+                .and_then( |(cache, deleted)| {
+                    assert!( deleted );
+                    cache.match_( "/stdweb-test" )
+                })
+                .and_then( move |response| {
+                    assert!( response.is_none() );
+                    caches.delete( "stdweb-test" )
+                })
+                .map( |deleted| assert!( deleted ) )
+                .recover( |error| panic!( "cache round trip failed: {:?}", error ) )
+        );
+    }
+}
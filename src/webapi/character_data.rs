@@ -0,0 +1,120 @@
+use webcore::try_from::TryInto;
+use webapi::node::INode;
+use webapi::element::Element;
+use webapi::dom_exception::IndexSizeError;
+
+/// The `CharacterData` interface is implemented by the nodes which contain
+/// text, like [TextNode](struct.TextNode.html).
+///
+/// Offsets and counts are in UTF-16 code units, like in JavaScript.
+///
+/// You most likely don't want to `use` this directly; instead
+/// you should `use stdweb::traits::*;`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData)
+// https://dom.spec.whatwg.org/#interface-characterdata
+pub trait ICharacterData: INode {
+    /// Returns the text contained in this node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/data)
+    // https://dom.spec.whatwg.org/#dom-characterdata-data
+    #[inline]
+    fn data( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.data;
+        ).try_into().unwrap()
+    }
+
+    /// Replaces the text contained in this node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/data)
+    // https://dom.spec.whatwg.org/#dom-characterdata-data
+    #[inline]
+    fn set_data( &self, data: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.data = @{data};
+        }
+    }
+
+    /// Returns the length of the text contained in this node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/length)
+    // https://dom.spec.whatwg.org/#dom-characterdata-length
+    #[inline]
+    fn length( &self ) -> u32 {
+        js!(
+            return @{self.as_ref()}.length;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `count` code units of the text, starting at `offset`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/substringData)
+    // https://dom.spec.whatwg.org/#dom-characterdata-substringdata
+    fn substring_data( &self, offset: u32, count: u32 ) -> Result< String, IndexSizeError > {
+        js_try!(
+            return @{self.as_ref()}.substringData( @{offset}, @{count} );
+        ).unwrap()
+    }
+
+    /// Returns the next sibling of this node which is an element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NonDocumentTypeChildNode/nextElementSibling)
+    // https://dom.spec.whatwg.org/#dom-nondocumenttypechildnode-nextelementsibling
+    fn next_element_sibling( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.nextElementSibling;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the previous sibling of this node which is an element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NonDocumentTypeChildNode/previousElementSibling)
+    // https://dom.spec.whatwg.org/#dom-nondocumenttypechildnode-previouselementsibling
+    fn previous_element_sibling( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.previousElementSibling;
+        ).try_into().unwrap()
+    }
+
+    /// Appends `data` to the text contained in this node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/appendData)
+    // https://dom.spec.whatwg.org/#dom-characterdata-appenddata
+    #[inline]
+    fn append_data( &self, data: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.appendData( @{data} );
+        }
+    }
+
+    /// Inserts `data` into the text contained in this node, at `offset`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/insertData)
+    // https://dom.spec.whatwg.org/#dom-characterdata-insertdata
+    fn insert_data( &self, offset: u32, data: &str ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.insertData( @{offset}, @{data} );
+        ).unwrap()
+    }
+
+    /// Removes `count` code units of the text, starting at `offset`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/deleteData)
+    // https://dom.spec.whatwg.org/#dom-characterdata-deletedata
+    fn delete_data( &self, offset: u32, count: u32 ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.deleteData( @{offset}, @{count} );
+        ).unwrap()
+    }
+
+    /// Replaces `count` code units of the text, starting at `offset`, with `data`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CharacterData/replaceData)
+    // https://dom.spec.whatwg.org/#dom-characterdata-replacedata
+    fn replace_data( &self, offset: u32, count: u32, data: &str ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.replaceData( @{offset}, @{count}, @{data} );
+        ).unwrap()
+    }
+}
@@ -0,0 +1,134 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::blob::Blob;
+use webapi::dom_exception::{NotAllowedError, NotFoundError};
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// Provides access to the system clipboard.
+///
+/// You can get it with [`Navigator::clipboard`](struct.Navigator.html#method.clipboard).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard)
+// https://w3c.github.io/clipboard-apis/#clipboard-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Clipboard")]
+#[reference(subclass_of(EventTarget))]
+pub struct Clipboard( Reference );
+
+impl IEventTarget for Clipboard {}
+
+/// An item of the clipboard, which holds the same data in one or more formats.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardItem)
+// https://w3c.github.io/clipboard-apis/#clipboarditem
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ClipboardItem")]
+pub struct ClipboardItem( Reference );
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that the `Clipboard` methods may fail with.
+    ClipboardError,
+    /// A NotAllowedError if the user or the browser denied access to the clipboard
+    NotAllowedError
+}
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that [`ClipboardItem::get_type`](struct.ClipboardItem.html#method.get_type) may fail with.
+    ClipboardItemGetTypeError,
+    /// A NotFoundError if the item doesn't contain data of the requested type
+    NotFoundError
+}
+
+impl ClipboardItem {
+    /// Creates a new clipboard item from a list of `(mime type, data)` pairs,
+    /// for example `[("image/png".to_owned(), blob)]`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardItem/ClipboardItem)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboarditem-clipboarditem
+    pub fn new( items: &[(String, Blob)] ) -> Self {
+        let types: Vec< &str > = items.iter().map( |&( ref mime, _ )| mime.as_str() ).collect();
+        let blobs: Vec< &Blob > = items.iter().map( |&( _, ref blob )| blob ).collect();
+
+        js!(
+            var types = @{types};
+            var blobs = @{blobs};
+            var items = {};
+            for( var i = 0; i < types.length; ++i ) {
+                items[ types[ i ] ] = blobs[ i ];
+            }
+            return new ClipboardItem( items );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the mime types which are available in this item.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardItem/types)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboarditem-types
+    pub fn types( &self ) -> Vec< String > {
+        js!(
+            return @{self}.types;
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the data of this item with the given mime type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardItem/getType)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboarditem-gettype
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn get_type( &self, mime_type: &str ) -> PromiseFuture< Blob, ClipboardItemGetTypeError > {
+        js!(
+            return @{self}.getType( @{mime_type} );
+        ).try_into().unwrap()
+    }
+}
+
+impl Clipboard {
+    /// Writes the given items to the clipboard, replacing its current contents.
+    ///
+    /// The future fails with a `NotAllowedError` if the user or the browser denied access to the clipboard.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/write)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-write
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn write( &self, items: &[ClipboardItem] ) -> PromiseFuture< (), ClipboardError > {
+        js!(
+            return @{self}.write( @{items} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the items currently on the clipboard.
+    ///
+    /// The future fails with a `NotAllowedError` if the user or the browser denied access to the clipboard.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/read)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-read
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn read( &self ) -> PromiseFuture< Vec< ClipboardItem >, ClipboardError > {
+        js!(
+            return @{self}.read();
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn blob( data: &str, mime_type: &str ) -> Blob {
+        js!( return new Blob( [ @{data} ], { type: @{mime_type} } ); ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_clipboard_item_types() {
+        let item = ClipboardItem::new( &[
+            ( "text/plain".to_owned(), blob( "text", "text/plain" ) ),
+            ( "text/html".to_owned(), blob( "<b>text</b>", "text/html" ) )
+        ]);
+
+        let mut types = item.types();
+        types.sort();
+        assert_eq!( types, vec![ "text/html".to_owned(), "text/plain".to_owned() ] );
+    }
+}
@@ -0,0 +1,75 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::character_data::ICharacterData;
+
+/// A `Comment` is a node containing a comment, like `<!-- this one -->`.
+///
+/// Comments aren't rendered, which makes them useful as placeholders
+/// marking a position in the DOM.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Comment)
+// https://dom.spec.whatwg.org/#comment
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Comment")]
+#[reference(subclass_of(EventTarget, Node))]
+pub struct Comment( Reference );
+
+impl IEventTarget for Comment {}
+impl INode for Comment {}
+impl ICharacterData for Comment {}
+
+/// A `ProcessingInstruction` is a node containing an instruction
+/// like `<?xml-stylesheet href="style.css"?>`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ProcessingInstruction)
+// https://dom.spec.whatwg.org/#processinginstruction
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ProcessingInstruction")]
+#[reference(subclass_of(EventTarget, Node))]
+pub struct ProcessingInstruction( Reference );
+
+impl IEventTarget for ProcessingInstruction {}
+impl INode for ProcessingInstruction {}
+impl ICharacterData for ProcessingInstruction {}
+
+impl ProcessingInstruction {
+    /// Returns the application the instruction is targeted at, for example `xml-stylesheet`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ProcessingInstruction/target)
+    // https://dom.spec.whatwg.org/#dom-processinginstruction-target
+    pub fn target( &self ) -> String {
+        js!(
+            return @{self}.target;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_comment() {
+        let comment = document().create_comment( "placeholder" );
+        assert_eq!( comment.data(), "placeholder" );
+        comment.set_data( "anchor" );
+        assert_eq!( comment.data(), "anchor" );
+
+        let parent = document().create_element( "div" ).unwrap();
+        parent.append_child( &comment );
+        assert_eq!( parent.text_content().unwrap(), "" );
+    }
+
+    #[test]
+    fn test_processing_instruction() {
+        let instruction = document().create_processing_instruction( "xml-stylesheet", "href=\"style.css\"" ).unwrap();
+        assert_eq!( instruction.target(), "xml-stylesheet" );
+        assert_eq!( instruction.data(), "href=\"style.css\"" );
+
+        assert!( document().create_processing_instruction( "target", "?>" ).is_err() );
+        assert!( document().create_processing_instruction( "not valid", "" ).is_err() );
+    }
+}
@@ -73,3 +73,131 @@ macro_rules! console {
     ( log, $( $args:expr ),+ ) => { __internal_console_unsafe!( log, $( $args ),+ ) };
     ( error, $( $args:expr ),+ ) => { __internal_console_unsafe!( error, $( $args ),+ ) };
 }
+
+// Which of these is defined is decided when stdweb itself is compiled, so
+// the `console-debug` feature and `debug_assertions` refer to stdweb's build
+// rather than to the crate using the macros.
+#[cfg(any(feature = "console-debug", debug_assertions))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __internal_console_debug {
+    ( $name:ident, $( $args:expr ),+ ) => { __internal_console_unsafe!( $name, $( $args ),+ ) };
+}
+
+#[cfg(not(any(feature = "console-debug", debug_assertions)))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __internal_console_debug {
+    ( $name:ident, $( $args:expr ),+ ) => {{
+        // The arguments are still type checked, but never evaluated.
+        if false {
+            $( let _ = &$args; )+
+        }
+        ()
+    }};
+}
+
+/// Prints a message with `console.log`.
+///
+/// This compiles to nothing unless stdweb is built with `debug_assertions`
+/// or with the `console-debug` feature enabled, so it can be left in release code
+/// without any overhead. The arguments are the same as with [`console!`](macro.console.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/log)
+#[macro_export]
+macro_rules! console_log {
+    ( $( $args:expr ),+ ) => { __internal_console_debug!( log, $( $args ),+ ) };
+}
+
+/// Prints a debug message with `console.debug`.
+///
+/// Like [`console_log!`](macro.console_log.html), this compiles to nothing unless stdweb is built
+/// with `debug_assertions` or with the `console-debug` feature enabled.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/debug)
+#[macro_export]
+macro_rules! console_debug {
+    ( $( $args:expr ),+ ) => { __internal_console_debug!( debug, $( $args ),+ ) };
+}
+
+/// Prints a message along with a stack trace with `console.trace`.
+///
+/// Like [`console_log!`](macro.console_log.html), this compiles to nothing unless stdweb is built
+/// with `debug_assertions` or with the `console-debug` feature enabled.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/trace)
+#[macro_export]
+macro_rules! console_trace {
+    ( $( $args:expr ),+ ) => { __internal_console_debug!( trace, $( $args ),+ ) };
+}
+
+/// Prints a warning with `console.warn`.
+///
+/// Unlike [`console_log!`](macro.console_log.html), this is always emitted.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/warn)
+#[macro_export]
+macro_rules! console_warn {
+    ( $( $args:expr ),+ ) => { __internal_console_unsafe!( warn, $( $args ),+ ) };
+}
+
+/// Prints an error with `console.error`.
+///
+/// Unlike [`console_log!`](macro.console_log.html), this is always emitted.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/error)
+#[macro_export]
+macro_rules! console_error {
+    ( $( $args:expr ),+ ) => { __internal_console_unsafe!( error, $( $args ),+ ) };
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webcore::value::Value;
+
+    fn capture< F: FnOnce() >( name: &str, callback: F ) -> Value {
+        js! { @(no_return)
+            var name = @{name};
+            var original = console[ name ];
+            var calls = [];
+            console[ name ] = function() {
+                calls.push( Array.prototype.slice.call( arguments ) );
+            };
+            console[ name ].original = original;
+            console[ name ].calls = calls;
+        }
+
+        callback();
+
+        js!(
+            var name = @{name};
+            var calls = console[ name ].calls;
+            console[ name ] = console[ name ].original;
+            return calls;
+        )
+    }
+
+    #[test]
+    fn test_always_emitted() {
+        let calls = capture( "warn", || console_warn!( "warning", 1 ) );
+        assert_eq!( js!( return @{&calls}.length; ), 1 );
+        assert_eq!( js!( return @{&calls}[ 0 ][ 0 ]; ), "warning" );
+        assert_eq!( js!( return @{&calls}[ 0 ][ 1 ]; ), 1 );
+
+        let calls = capture( "error", || console_error!( "error" ) );
+        assert_eq!( js!( return @{&calls}.length; ), 1 );
+    }
+
+    #[test]
+    fn test_debug_only() {
+        let expected = if cfg!( any( feature = "console-debug", debug_assertions ) ) { 1 } else { 0 };
+        for &name in &[ "log", "debug", "trace" ] {
+            let calls = capture( name, || match name {
+                "log" => console_log!( "message" ),
+                "debug" => console_debug!( "message" ),
+                _ => console_trace!( "message" )
+            });
+            assert_eq!( js!( return @{&calls}.length; ), expected );
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use webcore::try_from::TryInto;
+use webapi::document::Document;
+use webapi::date::Date;
+
+/// The `SameSite` attribute of a cookie, which controls whether it is sent with cross-site requests.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie/SameSite)
+// https://tools.ietf.org/html/draft-ietf-httpbis-rfc6265bis-02#section-5.3.7
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SameSite {
+    /// The cookie is only sent with same-site requests.
+    Strict,
+    /// The cookie is also sent when the user navigates to the site from another site.
+    Lax,
+    /// The cookie is sent with all requests; this requires the cookie to be `secure`.
+    None
+}
+
+/// Specifies a cookie which should be written by [`Cookies::set`](struct.Cookies.html#method.set).
+///
+/// You will usually create it with [`CookieOptions::new`](#method.new) and
+/// then change the fields which you need.
+#[derive(Clone, Debug)]
+pub struct CookieOptions< 'a > {
+    /// The name of the cookie.
+    pub name: &'a str,
+
+    /// The value of the cookie.
+    ///
+    /// It is percent-encoded before being written, so it can contain any characters.
+    pub value: &'a str,
+
+    /// If `Some` the cookie will expire at the given date; otherwise it's a session cookie.
+    pub expires: Option< &'a Date >,
+
+    /// If `Some` the cookie will expire after the given amount of seconds.
+    ///
+    /// This takes precedence over [`expires`](#structfield.expires).
+    pub max_age: Option< i64 >,
+
+    /// If `Some` the cookie will only be visible to pages under the given path.
+    pub path: Option< &'a str >,
+
+    /// If `Some` the cookie will be visible to the given domain and its subdomains.
+    pub domain: Option< &'a str >,
+
+    /// If `true` the cookie will only be sent over secure connections.
+    pub secure: bool,
+
+    /// If `Some` it controls whether the cookie is sent with cross-site requests.
+    pub same_site: Option< SameSite >,
+}
+
+impl< 'a > CookieOptions< 'a > {
+    /// Creates options for a session cookie with the given `name` and `value`
+    /// and with all the other attributes unset.
+    pub fn new( name: &'a str, value: &'a str ) -> Self {
+        CookieOptions {
+            name,
+            value,
+            expires: None,
+            max_age: None,
+            path: None,
+            domain: None,
+            secure: false,
+            same_site: None
+        }
+    }
+}
+
+/// Returned by [`Cookies::set`](struct.Cookies.html#method.set) when the cookie's name, path
+/// or domain contains a `;` or a control character, which could otherwise be used to add
+/// other attributes to the cookie.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidCookieError( &'static str );
+
+impl ::std::fmt::Display for InvalidCookieError {
+    fn fmt( &self, formatter: &mut ::std::fmt::Formatter ) -> ::std::fmt::Result {
+        write!( formatter, "The cookie's {} contains a ';' or a control character", self.0 )
+    }
+}
+
+impl ::std::error::Error for InvalidCookieError {
+    fn description( &self ) -> &str {
+        "The cookie contains a ';' or a control character"
+    }
+}
+
+fn validate( field: &'static str, value: &str ) -> Result< (), InvalidCookieError > {
+    if value.chars().any( |c| c == ';' || c.is_control() ) {
+        Err( InvalidCookieError( field ) )
+    } else {
+        Ok(())
+    }
+}
+
+/// Gives access to the cookies of a [`Document`](struct.Document.html).
+///
+/// This is returned by [`Document::cookies`](struct.Document.html#method.cookies), and
+/// takes care of parsing and serializing the `document.cookie` string.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/cookie)
+// https://html.spec.whatwg.org/#dom-document-cookie
+#[derive(Clone, Debug)]
+pub struct Cookies( Document );
+
+fn encode( value: &str ) -> String {
+    js!(
+        return encodeURIComponent( @{value} );
+    ).try_into().unwrap()
+}
+
+fn decode( value: &str ) -> String {
+    js!(
+        var value = @{value};
+        try {
+            return decodeURIComponent( value );
+        } catch( error ) {
+            return value;
+        }
+    ).try_into().unwrap()
+}
+
+impl Cookies {
+    pub(crate) fn new( document: Document ) -> Self {
+        Cookies( document )
+    }
+
+    fn raw( &self ) -> String {
+        js!(
+            return @{&self.0}.cookie;
+        ).try_into().unwrap()
+    }
+
+    fn set_raw( &self, cookie: &str ) {
+        js! { @(no_return)
+            @{&self.0}.cookie = @{cookie};
+        }
+    }
+
+    /// Returns the value of the cookie with the given `name`, if it exists.
+    pub fn get( &self, name: &str ) -> Option< String > {
+        let name = encode( name );
+        self.raw()
+            .split( ';' )
+            .filter_map( |pair| {
+                let mut parts = pair.trim().splitn( 2, '=' );
+                match ( parts.next(), parts.next() ) {
+                    ( Some( key ), Some( value ) ) if key == name => Some( decode( value ) ),
+                    _ => None
+                }
+            })
+            .next()
+    }
+
+    /// Writes a cookie as specified by the `options`.
+    ///
+    /// Fails if the name, the path or the domain contains a `;` or a control character.
+    pub fn set( &self, options: CookieOptions ) -> Result< (), InvalidCookieError > {
+        validate( "name", options.name )?;
+        if let Some( path ) = options.path {
+            validate( "path", path )?;
+        }
+        if let Some( domain ) = options.domain {
+            validate( "domain", domain )?;
+        }
+
+        let mut cookie = format!( "{}={}", encode( options.name ), encode( options.value ) );
+
+        if let Some( expires ) = options.expires {
+            cookie.push_str( "; expires=" );
+            cookie.push_str( &expires.to_utc_string() );
+        }
+
+        if let Some( max_age ) = options.max_age {
+            cookie.push_str( &format!( "; max-age={}", max_age ) );
+        }
+
+        if let Some( path ) = options.path {
+            cookie.push_str( "; path=" );
+            cookie.push_str( path );
+        }
+
+        if let Some( domain ) = options.domain {
+            cookie.push_str( "; domain=" );
+            cookie.push_str( domain );
+        }
+
+        if options.secure {
+            cookie.push_str( "; secure" );
+        }
+
+        if let Some( same_site ) = options.same_site {
+            cookie.push_str( match same_site {
+                SameSite::Strict => "; samesite=strict",
+                SameSite::Lax => "; samesite=lax",
+                SameSite::None => "; samesite=none"
+            });
+        }
+
+        self.set_raw( &cookie );
+        Ok(())
+    }
+
+    /// Removes the cookie with the given `name`.
+    ///
+    /// The `path` must be the same as the one the cookie was set with.
+    pub fn remove( &self, name: &str, path: Option< &str > ) -> Result< (), InvalidCookieError > {
+        let mut options = CookieOptions::new( name, "" );
+        options.max_age = Some( 0 );
+        options.path = path;
+        self.set( options )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_cookies() {
+        let cookies = document().cookies();
+        assert_eq!( cookies.get( "stdweb test" ), None );
+
+        cookies.set( CookieOptions::new( "stdweb test", "a value; with = special characters" ) ).unwrap();
+        assert_eq!( cookies.get( "stdweb test" ), Some( "a value; with = special characters".to_owned() ) );
+
+        cookies.remove( "stdweb test", None ).unwrap();
+        assert_eq!( cookies.get( "stdweb test" ), None );
+    }
+
+    #[test]
+    fn test_attribute_injection_is_refused() {
+        let cookies = document().cookies();
+
+        let mut options = CookieOptions::new( "stdweb injection", "value" );
+        options.path = Some( "/; SameSite=None" );
+        assert_eq!( cookies.set( options ), Err( InvalidCookieError( "path" ) ) );
+
+        let mut options = CookieOptions::new( "stdweb injection", "value" );
+        options.domain = Some( "example.com\r\nSet-Cookie: other=1" );
+        assert_eq!( cookies.set( options ), Err( InvalidCookieError( "domain" ) ) );
+
+        let options = CookieOptions::new( "stdweb; injection", "value" );
+        assert_eq!( cookies.set( options ), Err( InvalidCookieError( "name" ) ) );
+
+        assert!( cookies.remove( "stdweb injection", Some( "/\u{7f}" ) ).is_err() );
+        assert_eq!( cookies.get( "stdweb injection" ), None );
+    }
+}
@@ -0,0 +1,36 @@
+use webcore::try_from::TryInto;
+
+/// Returns `true` if the browser supports the given CSS `property` with the given `value`,
+/// for example `css_supports( "display", "grid" )`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSS/supports)
+// https://drafts.csswg.org/css-conditional-3/#dom-css-supports
+pub fn css_supports( property: &str, value: &str ) -> bool {
+    js!(
+        return CSS.supports( @{property}, @{value} );
+    ).try_into().unwrap()
+}
+
+/// Returns `true` if the browser supports the given CSS condition, which uses the same
+/// syntax as an `@supports` rule, for example `"(display: grid) and (not (display: inline-grid))"`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSS/supports)
+// https://drafts.csswg.org/css-conditional-3/#dom-css-supports-conditiontext
+pub fn css_supports_condition( condition: &str ) -> bool {
+    js!(
+        return CSS.supports( @{condition} );
+    ).try_into().unwrap()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_supports() {
+        assert!( css_supports( "display", "block" ) );
+        assert!( !css_supports( "display", "not-a-display-value" ) );
+        assert!( css_supports_condition( "(display: block)" ) );
+        assert!( !css_supports_condition( "(not-a-property: 1)" ) );
+    }
+}
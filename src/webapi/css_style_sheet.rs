@@ -0,0 +1,190 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::dom_exception::{SyntaxError, IndexSizeError, HierarchyRequestError, InvalidStateError, NotAllowedError};
+
+/// The `StyleSheet` interface represents a single style sheet of the document.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/StyleSheet)
+// https://drafts.csswg.org/cssom/#the-stylesheet-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "StyleSheet")]
+pub struct StyleSheet( Reference );
+
+impl StyleSheet {
+    /// Returns the location of the style sheet, or `None` for inline style sheets.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/StyleSheet/href)
+    // https://drafts.csswg.org/cssom/#dom-stylesheet-href
+    pub fn href( &self ) -> Option< String > {
+        js!(
+            return @{self}.href;
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the style sheet is prevented from applying to the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/StyleSheet/disabled)
+    // https://drafts.csswg.org/cssom/#dom-stylesheet-disabled
+    pub fn disabled( &self ) -> bool {
+        js!(
+            return @{self}.disabled;
+        ).try_into().unwrap()
+    }
+
+    /// Sets whether the style sheet is prevented from applying to the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/StyleSheet/disabled)
+    // https://drafts.csswg.org/cssom/#dom-stylesheet-disabled
+    pub fn set_disabled( &self, disabled: bool ) {
+        js! { @(no_return)
+            @{self}.disabled = @{disabled};
+        }
+    }
+}
+
+/// The `CssStyleSheet` interface represents a single CSS style sheet.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet)
+// https://drafts.csswg.org/cssom/#the-cssstylesheet-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "CSSStyleSheet")]
+#[reference(subclass_of(StyleSheet))]
+pub struct CssStyleSheet( Reference );
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that [`CssStyleSheet::insert_rule`](struct.CssStyleSheet.html#method.insert_rule)
+    /// may throw.
+    InsertRuleError,
+    /// The rule couldn't be parsed.
+    SyntaxError,
+    /// The index is larger than the number of rules.
+    IndexSizeError,
+    /// The rule can't be inserted at the given index, for example an `@import` after a style rule.
+    HierarchyRequestError,
+    /// The rule is an `@namespace` rule and the style sheet already contains other kinds of rules.
+    InvalidStateError
+}
+
+impl CssStyleSheet {
+    /// Creates a new empty constructed style sheet which isn't attached to any document.
+    ///
+    /// Constructed style sheets can be shared between documents through
+    /// [`Document::set_adopted_style_sheets`](struct.Document.html#method.set_adopted_style_sheets).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet/CSSStyleSheet)
+    // https://drafts.csswg.org/cssom/#dom-cssstylesheet-cssstylesheet
+    pub fn new() -> Self {
+        js!(
+            return new CSSStyleSheet();
+        ).try_into().unwrap()
+    }
+
+    /// Replaces the rules of the style sheet with the rules parsed from `text`.
+    ///
+    /// Fails with a `NotAllowedError` if the style sheet wasn't created
+    /// with [`CssStyleSheet::new`](#method.new).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet/replaceSync)
+    // https://drafts.csswg.org/cssom/#dom-cssstylesheet-replacesync
+    pub fn replace_sync( &self, text: &str ) -> Result< (), NotAllowedError > {
+        js_try!( @(no_return)
+            @{self}.replaceSync( @{text} );
+        ).unwrap()
+    }
+
+    /// Returns the rules of the style sheet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet/cssRules)
+    // https://drafts.csswg.org/cssom/#dom-cssstylesheet-cssrules
+    pub fn css_rules( &self ) -> Vec< CssRule > {
+        js!(
+            return Array.prototype.slice.call( @{self}.cssRules );
+        ).try_into().unwrap()
+    }
+
+    /// Inserts a new `rule` at the given `index` and returns the index of the inserted rule.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet/insertRule)
+    // https://drafts.csswg.org/cssom/#dom-cssstylesheet-insertrule
+    pub fn insert_rule( &self, rule: &str, index: u32 ) -> Result< u32, InsertRuleError > {
+        js_try!(
+            return @{self}.insertRule( @{rule}, @{index} );
+        ).unwrap()
+    }
+
+    /// Removes the rule at the given `index`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet/deleteRule)
+    // https://drafts.csswg.org/cssom/#dom-cssstylesheet-deleterule
+    pub fn delete_rule( &self, index: u32 ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self}.deleteRule( @{index} );
+        ).unwrap()
+    }
+}
+
+/// The `CssRule` interface represents a single CSS rule, such as a style rule
+/// or an `@keyframes` rule.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSRule)
+// https://drafts.csswg.org/cssom/#the-cssrule-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "CSSRule")]
+pub struct CssRule( Reference );
+
+impl CssRule {
+    /// Returns the textual representation of the rule.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSRule/cssText)
+    // https://drafts.csswg.org/cssom/#dom-cssrule-csstext
+    pub fn css_text( &self ) -> String {
+        js!(
+            return @{self}.cssText;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_insert_and_delete_rule() {
+        let sheet = document().create_style_sheet();
+        assert!( sheet.css_rules().is_empty() );
+
+        assert_eq!( sheet.insert_rule( ".foo { color: red; }", 0 ).unwrap(), 0 );
+        assert_eq!( sheet.insert_rule( "@keyframes spin { to { transform: rotate(360deg); } }", 1 ).unwrap(), 1 );
+        let rules = sheet.css_rules();
+        assert_eq!( rules.len(), 2 );
+        assert!( rules[ 0 ].css_text().contains( ".foo" ) );
+
+        match sheet.insert_rule( "}{", 0 ) {
+            Err( InsertRuleError::SyntaxError( _ ) ) => {},
+            _ => panic!( "Expected a SyntaxError" )
+        }
+
+        sheet.delete_rule( 0 ).unwrap();
+        assert_eq!( sheet.css_rules().len(), 1 );
+        assert!( sheet.delete_rule( 5 ).is_err() );
+
+        let sheets = document().style_sheets();
+        let sheet: StyleSheet = sheet.into();
+        assert!( sheets.contains( &sheet ) );
+    }
+
+    #[test]
+    fn test_constructed_style_sheet() {
+        let sheet = CssStyleSheet::new();
+        sheet.replace_sync( ".foo { color: red; } .bar { color: blue; }" ).unwrap();
+        assert_eq!( sheet.css_rules().len(), 2 );
+
+        document().set_adopted_style_sheets( &[ sheet.clone() ] );
+        assert_eq!( document().adopted_style_sheets(), vec![ sheet ] );
+        document().set_adopted_style_sheets( &[] );
+        assert!( document().adopted_style_sheets().is_empty() );
+
+        assert!( document().create_style_sheet().replace_sync( ".foo {}" ).is_err() );
+    }
+}
@@ -0,0 +1,156 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::array_buffer::ArrayBuffer;
+use webapi::error::RangeError;
+
+/// The `DataView` view provides a low-level interface for reading and writing
+/// numbers of multiple types in an [ArrayBuffer](struct.ArrayBuffer.html), at any
+/// offset and with any endianness.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-dataview-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DataView")]
+pub struct DataView( Reference );
+
+macro_rules! byte_accessors {
+    ($getter:ident, $setter:ident, $ty:ty, $js_type:ident) => {
+        /// Reads the number at the given `byte_offset`.
+        ///
+        /// Fails if the value doesn't fit into the view at that offset.
+        #[allow(trivial_numeric_casts)]
+        pub fn $getter( &self, byte_offset: u32 ) -> Result< $ty, RangeError > {
+            let value: Result< f64, RangeError > = js_try!(
+                return @{self}[ @{concat!( "get", stringify!( $js_type ) )} ]( @{byte_offset} );
+            ).unwrap();
+
+            value.map( |value| value as $ty )
+        }
+
+        /// Writes the number at the given `byte_offset`.
+        ///
+        /// Fails if the value doesn't fit into the view at that offset.
+        pub fn $setter( &self, byte_offset: u32, value: $ty ) -> Result< (), RangeError > {
+            js_try!( @(no_return)
+                @{self}[ @{concat!( "set", stringify!( $js_type ) )} ]( @{byte_offset}, @{value} );
+            ).unwrap()
+        }
+    };
+
+    ($getter:ident, $setter:ident, $ty:ty, $js_type:ident, endian) => {
+        /// Reads the number at the given `byte_offset`, in little endian
+        /// byte order if `little_endian` is `true` and in big endian byte order otherwise.
+        ///
+        /// Fails if the value doesn't fit into the view at that offset.
+        #[allow(trivial_numeric_casts)]
+        pub fn $getter( &self, byte_offset: u32, little_endian: bool ) -> Result< $ty, RangeError > {
+            let value: Result< f64, RangeError > = js_try!(
+                return @{self}[ @{concat!( "get", stringify!( $js_type ) )} ]( @{byte_offset}, @{little_endian} );
+            ).unwrap();
+
+            value.map( |value| value as $ty )
+        }
+
+        /// Writes the number at the given `byte_offset`, in little endian
+        /// byte order if `little_endian` is `true` and in big endian byte order otherwise.
+        ///
+        /// Fails if the value doesn't fit into the view at that offset.
+        pub fn $setter( &self, byte_offset: u32, value: $ty, little_endian: bool ) -> Result< (), RangeError > {
+            js_try!( @(no_return)
+                @{self}[ @{concat!( "set", stringify!( $js_type ) )} ]( @{byte_offset}, @{value}, @{little_endian} );
+            ).unwrap()
+        }
+    };
+}
+
+impl DataView {
+    /// Creates a new `DataView` spanning the whole `buffer`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview-buffer-byteoffset-bytelength
+    pub fn new( buffer: &ArrayBuffer ) -> Self {
+        js!( return new DataView( @{buffer} ); ).try_into().unwrap()
+    }
+
+    /// Creates a new `DataView` over `byte_length` bytes of `buffer` starting at `byte_offset`.
+    ///
+    /// Fails if the given range doesn't lie within the `buffer`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview-buffer-byteoffset-bytelength
+    pub fn with_range( buffer: &ArrayBuffer, byte_offset: u32, byte_length: u32 ) -> Result< Self, RangeError > {
+        js_try!(
+            return new DataView( @{buffer}, @{byte_offset}, @{byte_length} );
+        ).unwrap()
+    }
+
+    /// Returns the [ArrayBuffer](struct.ArrayBuffer.html) referenced by this view.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-dataview.prototype.buffer
+    pub fn buffer( &self ) -> ArrayBuffer {
+        js!( return @{self}.buffer; ).try_into().unwrap()
+    }
+
+    /// Returns the length of this view, in bytes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-dataview.prototype.bytelength
+    pub fn byte_length( &self ) -> u32 {
+        js!( return @{self}.byteLength; ).try_into().unwrap()
+    }
+
+    /// Returns the offset of this view from the start of its buffer, in bytes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-dataview.prototype.byteoffset
+    pub fn byte_offset( &self ) -> u32 {
+        js!( return @{self}.byteOffset; ).try_into().unwrap()
+    }
+
+    byte_accessors!( get_i8, set_i8, i8, Int8 );
+    byte_accessors!( get_u8, set_u8, u8, Uint8 );
+    byte_accessors!( get_i16, set_i16, i16, Int16, endian );
+    byte_accessors!( get_u16, set_u16, u16, Uint16, endian );
+    byte_accessors!( get_i32, set_i32, i32, Int32, endian );
+    byte_accessors!( get_u32, set_u32, u32, Uint32, endian );
+    byte_accessors!( get_f32, set_f32, f32, Float32, endian );
+    byte_accessors!( get_f64, set_f64, f64, Float64, endian );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_endianness() {
+        let buffer = ArrayBuffer::new( 8 ).unwrap();
+        let view = DataView::new( &buffer );
+        view.set_u16( 0, 0x0102, false ).unwrap();
+        view.set_u16( 2, 0x0102, true ).unwrap();
+        view.set_f32( 4, 1.5, true ).unwrap();
+
+        assert_eq!( view.get_u8( 0 ).unwrap(), 0x01 );
+        assert_eq!( view.get_u8( 2 ).unwrap(), 0x02 );
+        assert_eq!( view.get_u32( 0, false ).unwrap(), 0x01020201 );
+        assert_eq!( view.get_f32( 4, true ).unwrap(), 1.5 );
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let buffer = ArrayBuffer::new( 4 ).unwrap();
+        let view = DataView::new( &buffer );
+        assert!( view.get_u32( 1, true ).is_err() );
+        assert!( view.set_f64( 0, 1.0, true ).is_err() );
+        assert!( DataView::with_range( &buffer, 2, 4 ).is_err() );
+    }
+
+    #[test]
+    fn slice_of_array_buffer() {
+        let buffer = ArrayBuffer::new( 4 ).unwrap();
+        DataView::new( &buffer ).set_u8( 2, 42 ).unwrap();
+        let slice = buffer.slice( 2, 4 );
+        assert_eq!( slice.byte_length(), 2 );
+        assert_eq!( DataView::new( &slice ).get_u8( 0 ).unwrap(), 42 );
+    }
+}
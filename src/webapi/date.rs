@@ -1,5 +1,6 @@
 use webcore::value::Reference;
 use webcore::try_from::TryInto;
+use webcore::object::Object;
 
 /// [(JavaScript docs)](https://developer.mozilla.org/en/docs/Web/JavaScript/Reference/Global_Objects/Date)
 /// https://www.ecma-international.org/ecma-262/6.0/#sec-date-constructor
@@ -480,6 +481,54 @@ impl Date {
         ).try_into().unwrap()
     }
 
+    /// The toLocaleDateString() method returns a string with a language sensitive representation of the date portion of this date.
+    ///
+    /// `locale` is a BCP 47 language tag (for example `"en-US"`); if it's `None` the browser's default locale is used.
+    /// `options` is an object with the same properties as the options of
+    /// [Intl.DateTimeFormat](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-13.3.2
+    pub fn to_locale_date_string(&self, locale: Option<&str>, options: Option<&Object>) -> String {
+        js!(
+            var locale = @{locale};
+            var options = @{options};
+            return @{self}.toLocaleDateString(locale === null ? undefined : locale, options === null ? undefined : options);
+        ).try_into().unwrap()
+    }
+
+    /// The toLocaleString() method returns a string with a language sensitive representation of this date.
+    ///
+    /// `locale` is a BCP 47 language tag (for example `"en-US"`); if it's `None` the browser's default locale is used.
+    /// `options` is an object with the same properties as the options of
+    /// [Intl.DateTimeFormat](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-13.3.1
+    pub fn to_locale_string(&self, locale: Option<&str>, options: Option<&Object>) -> String {
+        js!(
+            var locale = @{locale};
+            var options = @{options};
+            return @{self}.toLocaleString(locale === null ? undefined : locale, options === null ? undefined : options);
+        ).try_into().unwrap()
+    }
+
+    /// The toLocaleTimeString() method returns a string with a language sensitive representation of the time portion of this date.
+    ///
+    /// `locale` is a BCP 47 language tag (for example `"en-US"`); if it's `None` the browser's default locale is used.
+    /// `options` is an object with the same properties as the options of
+    /// [Intl.DateTimeFormat](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-13.3.3
+    pub fn to_locale_time_string(&self, locale: Option<&str>, options: Option<&Object>) -> String {
+        js!(
+            var locale = @{locale};
+            var options = @{options};
+            return @{self}.toLocaleTimeString(locale === null ? undefined : locale, options === null ? undefined : options);
+        ).try_into().unwrap()
+    }
+
     /// The toString() method returns a string representing the specified Date object.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString)
@@ -785,6 +834,15 @@ mod tests {
         assert_eq!(now.to_iso_string(), "1975-08-19T23:15:30.000Z");
     }
 
+    #[test]
+    fn test_date_to_locale_string() {
+        let now = Date::from_time(Date::utc(96, 1, 2, 3, 4, 5, 0));
+        let options: Object = js!( return { timeZone: "UTC" }; ).try_into().unwrap();
+        assert!(now.to_locale_string(Some("en-US"), Some(&options)).starts_with("2/2/1996, 3:04:05"));
+        assert_eq!(now.to_locale_date_string(Some("en-US"), Some(&options)), "2/2/1996");
+        assert!(now.to_locale_time_string(Some("en-US"), Some(&options)).starts_with("3:04:05"));
+    }
+
     #[test]
     fn test_date_to_time_string() {
         // not easy to test this due to time-zones
@@ -3,12 +3,20 @@ use webcore::try_from::TryInto;
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
 use webapi::element::Element;
+use webapi::element_builder::ElementBuilder;
 use webapi::html_element::HtmlElement;
 use webapi::document_fragment::DocumentFragment;
 use webapi::text_node::TextNode;
+use webapi::comment::{Comment, ProcessingInstruction};
+use webapi::html_collection::HtmlCollection;
+use webapi::range::Range;
 use webapi::location::Location;
+use webapi::cookies::Cookies;
+use webapi::css_style_sheet::{StyleSheet, CssStyleSheet};
+use webapi::tree_walker::{TreeWalkerHandle, WhatToShow, NodeFilterResult};
 use webapi::parent_node::IParentNode;
 use webapi::non_element_parent_node::INonElementParentNode;
+use webapi::dom_exception::InvalidCharacterError;
 use private::TODO;
 
 /// The `Document` interface represents any web page loaded in the browser and
@@ -27,6 +35,19 @@ impl INode for Document {}
 
 impl INonElementParentNode for Document {}
 
+/// The visibility state of a [Document](struct.Document.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState)
+// https://w3c.github.io/page-visibility/#visibilitystate-enum
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VisibilityState {
+    /// The page content may be at least partially visible.
+    Visible,
+    /// The page content is not visible to the user, e.g. because
+    /// the document is in a background tab or the window is minimized.
+    Hidden
+}
+
 /// A global instance of [Document](struct.Document.html).
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document)
@@ -58,6 +79,12 @@ impl Document {
         }
     }
 
+    /// Returns a builder for an element with the given `tag`, which allows
+    /// its attributes and children to be specified before it's created.
+    pub fn build_element( &self, tag: &str ) -> ElementBuilder {
+        ElementBuilder::new( self.clone(), tag )
+    }
+
     /// Creates a new text node.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createTextNode)
@@ -68,6 +95,58 @@ impl Document {
         }
     }
 
+    /// Creates a new comment node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createComment)
+    // https://dom.spec.whatwg.org/#dom-document-createcomment
+    pub fn create_comment( &self, data: &str ) -> Comment {
+        js!(
+            return @{self}.createComment( @{data} );
+        ).try_into().unwrap()
+    }
+
+    /// Creates a new processing instruction node.
+    ///
+    /// Fails if `target` isn't a valid XML name, or if `data` contains `?>`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createProcessingInstruction)
+    // https://dom.spec.whatwg.org/#dom-document-createprocessinginstruction
+    pub fn create_processing_instruction( &self, target: &str, data: &str ) -> Result< ProcessingInstruction, InvalidCharacterError > {
+        js_try!(
+            return @{self}.createProcessingInstruction( @{target}, @{data} );
+        ).unwrap()
+    }
+
+    /// Creates a new [Range](struct.Range.html) which is collapsed at the start of the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createRange)
+    // https://dom.spec.whatwg.org/#dom-document-createrange
+    pub fn create_range( &self ) -> Range {
+        js!( return @{self}.createRange(); ).try_into().unwrap()
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all the elements in the
+    /// document which have all of the given space-separated class names.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementsByClassName)
+    // https://dom.spec.whatwg.org/#dom-document-getelementsbyclassname
+    pub fn get_elements_by_class_name( &self, class_names: &str ) -> HtmlCollection {
+        unsafe {
+            js!( return @{self}.getElementsByClassName( @{class_names} ); ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all the elements in the
+    /// document with the given tag name; `"*"` matches all elements.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementsByTagName)
+    // https://dom.spec.whatwg.org/#dom-document-getelementsbytagname
+    pub fn get_elements_by_tag_name( &self, tag: &str ) -> HtmlCollection {
+        unsafe {
+            js!( return @{self}.getElementsByTagName( @{tag} ); ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// Returns a [Location](struct.Location.html) object which contains
     /// information about the URL of the document and provides methods
     /// for changing that URL and loading another URL.
@@ -142,6 +221,84 @@ impl Document {
         }
     }
 
+    /// Creates a [`TreeWalker`](struct.TreeWalker.html) which traverses the subtree
+    /// rooted at `root`, visiting only the kinds of nodes in `what_to_show` which
+    /// are accepted by `filter`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createTreeWalker)
+    // https://dom.spec.whatwg.org/#dom-document-createtreewalker
+    pub fn create_tree_walker< T, F >( &self, root: &T, what_to_show: WhatToShow, filter: F ) -> TreeWalkerHandle
+        where T: INode, F: FnMut( Node ) -> NodeFilterResult + 'static
+    {
+        TreeWalkerHandle::new( self, root, what_to_show, filter )
+    }
+
+    /// Returns the style sheets which are linked into or embedded in the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentOrShadowRoot/styleSheets)
+    // https://drafts.csswg.org/cssom/#dom-documentorshadowroot-stylesheets
+    pub fn style_sheets( &self ) -> Vec< StyleSheet > {
+        js!(
+            return Array.prototype.slice.call( @{self}.styleSheets );
+        ).try_into().unwrap()
+    }
+
+    /// Creates a new empty style sheet by appending a `<style>` element to the
+    /// head of the document, and returns it.
+    pub fn create_style_sheet( &self ) -> CssStyleSheet {
+        js!(
+            var document = @{self};
+            var style = document.createElement( "style" );
+            ( document.head || document.documentElement ).appendChild( style );
+            return style.sheet;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the constructed style sheets which are applied to the document
+    /// in addition to its own style sheets.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/adoptedStyleSheets)
+    // https://drafts.csswg.org/cssom/#dom-documentorshadowroot-adoptedstylesheets
+    pub fn adopted_style_sheets( &self ) -> Vec< CssStyleSheet > {
+        js!(
+            return Array.prototype.slice.call( @{self}.adoptedStyleSheets );
+        ).try_into().unwrap()
+    }
+
+    /// Sets the constructed style sheets which are applied to the document.
+    ///
+    /// Every style sheet must have been created with
+    /// [`CssStyleSheet::new`](struct.CssStyleSheet.html#method.new).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/adoptedStyleSheets)
+    // https://drafts.csswg.org/cssom/#dom-documentorshadowroot-adoptedstylesheets
+    pub fn set_adopted_style_sheets( &self, sheets: &[CssStyleSheet] ) {
+        js! { @(no_return)
+            @{self}.adoptedStyleSheets = @{sheets};
+        }
+    }
+
+    /// Returns the topmost element at the given coordinates, relative to the viewport.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentOrShadowRoot/elementFromPoint)
+    // https://drafts.csswg.org/cssom-view/#dom-document-elementfrompoint
+    pub fn element_from_point( &self, x: f64, y: f64 ) -> Option< Element > {
+        js!(
+            return @{self}.elementFromPoint( @{x}, @{y} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns all the elements at the given coordinates, relative to the viewport,
+    /// ordered from the topmost to the bottommost one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentOrShadowRoot/elementsFromPoint)
+    // https://drafts.csswg.org/cssom-view/#dom-document-elementsfrompoint
+    pub fn elements_from_point( &self, x: f64, y: f64 ) -> Vec< Element > {
+        js!(
+            return @{self}.elementsFromPoint( @{x}, @{y} );
+        ).try_into().unwrap()
+    }
+
     /// Returns the Element that the pointer is locked to, if it is locked to any
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentOrShadowRoot/pointerLockElement)
@@ -166,4 +323,71 @@ impl Document {
             @{self}.exitPointerLock();
         );
     }
+
+    /// Returns a helper which can be used to read and write the cookies of the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/cookie)
+    // https://html.spec.whatwg.org/#dom-document-cookie
+    pub fn cookies( &self ) -> Cookies {
+        Cookies::new( self.clone() )
+    }
+
+    /// Executes a formatting command, such as `"bold"` or `"insertText"`, on the
+    /// currently focused editable region of the document.
+    ///
+    /// Returns `false` if the command is unsupported or disabled.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/execCommand)
+    // https://w3c.github.io/editing/docs/execCommand/#execcommand()
+    pub fn exec_command( &self, command: &str, show_ui: bool, value: Option< &str > ) -> bool {
+        js!(
+            var value = @{value};
+            return @{self}.execCommand( @{command}, @{show_ui}, value === null ? undefined : value );
+        ).try_into().unwrap()
+    }
+
+    /// Returns whenever the page is considered hidden to the user.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/hidden)
+    // https://w3c.github.io/page-visibility/#dom-document-hidden
+    pub fn hidden( &self ) -> bool {
+        js!(
+            return @{self}.hidden;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the visibility of the document, that is in which context
+    /// this element is now visible.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState)
+    // https://w3c.github.io/page-visibility/#dom-document-visibilitystate
+    pub fn visibility_state( &self ) -> VisibilityState {
+        let state: String = js!(
+            return @{self}.visibilityState;
+        ).try_into().unwrap();
+
+        match state.as_ref() {
+            "visible" => VisibilityState::Visible,
+            "hidden" => VisibilityState::Hidden,
+            // `prerender` was dropped from the spec; older browsers treat it as not visible.
+            "prerender" => VisibilityState::Hidden,
+            other => panic!( "Unexpected visibilityState value: {:?}", other )
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_from_point() {
+        assert_eq!( document().element_from_point( -1.0, -1.0 ), None );
+        assert!( document().elements_from_point( -1.0, -1.0 ).is_empty() );
+
+        let stack = document().elements_from_point( 0.0, 0.0 );
+        if let Some( topmost ) = document().element_from_point( 0.0, 0.0 ) {
+            assert_eq!( stack.first(), Some( &topmost ) );
+        }
+    }
 }
@@ -1,9 +1,13 @@
 use webcore::value::Reference;
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
+use webapi::parent_node::IParentNode;
 
 /// A reference to a JavaScript object DocumentFragment.
 ///
+/// Fragments can be queried through [IParentNode](trait.IParentNode.html) before
+/// their contents are inserted into the document.
+///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentFragment)
 // https://dom.spec.whatwg.org/#documentfragment
 #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
@@ -12,4 +16,26 @@ use webapi::node::{INode, Node};
 pub struct DocumentFragment( Reference );
 
 impl IEventTarget for DocumentFragment {}
-impl INode for DocumentFragment {}
\ No newline at end of file
+impl INode for DocumentFragment {}
+impl IParentNode for DocumentFragment {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::element::IElement;
+
+    #[test]
+    fn test_query_selector() {
+        let fragment = document().create_document_fragment();
+        let parent = document().create_element( "div" ).unwrap();
+        let child = document().create_element( "span" ).unwrap();
+        child.class_list().add( "x" ).unwrap();
+        parent.append_child( &child );
+        fragment.append_child( &parent );
+
+        assert_eq!( fragment.query_selector( ".x" ).unwrap().unwrap(), child );
+        assert!( fragment.query_selector( ".y" ).unwrap().is_none() );
+        assert_eq!( fragment.query_selector_all( "div, span" ).unwrap().len(), 2 );
+    }
+}
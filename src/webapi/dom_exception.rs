@@ -99,17 +99,6 @@ impl IDomException for InvalidStateError {}
 
 error_boilerplate! { InvalidStateError, name = "InvalidStateError" }
 
-/// Used to indicate an unsuccessful operation when none of the other NativeError objects are an appropriate indication of the failure cause.
-// https://tc39.github.io/ecma262/#sec-native-error-types-used-in-this-standard-typeerror
-#[derive(Clone, Debug, ReferenceType)]
-#[reference(subclass_of(Error, DomException))]
-pub struct TypeError( Reference );
-
-impl IError for TypeError {}
-impl IDomException for TypeError {}
-
-error_boilerplate! { TypeError, name = "TypeError" }
-
 /// Used to indicate an unsuccessful operation when none of the other NativeError objects are an appropriate indication of the failure cause.
 // https://heycam.github.io/webidl/#notsupportederror
 #[derive(Clone, Debug, ReferenceType)]
@@ -154,6 +143,53 @@ impl IDomException for AbortError {}
 
 error_boilerplate! { AbortError, name = "AbortError" }
 
+/// Used to indicate that the request is not allowed by the user agent or the platform
+/// in the current context, for example because the user denied permission.
+// https://heycam.github.io/webidl/#notallowederror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct NotAllowedError( Reference );
+
+impl IError for NotAllowedError {}
+impl IDomException for NotAllowedError {}
+
+error_boilerplate! { NotAllowedError, name = "NotAllowedError" }
+
+/// Used to indicate that the supplied node is incorrect or has an incorrect ancestor for this operation.
+// https://heycam.github.io/webidl/#invalidnodetypeerror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct InvalidNodeTypeError( Reference );
+
+impl IError for InvalidNodeTypeError {}
+impl IDomException for InvalidNodeTypeError {}
+
+error_boilerplate! { InvalidNodeTypeError, name = "InvalidNodeTypeError" }
+
+/// Used to indicate that an object couldn't be cloned or transferred, for example
+/// when sending it to another context with `postMessage`.
+// https://heycam.github.io/webidl/#datacloneerror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct DataCloneError( Reference );
+
+impl IError for DataCloneError {}
+impl IDomException for DataCloneError {}
+
+error_boilerplate! { DataCloneError, name = "DataCloneError" }
+
+/// Used to indicate that an operation isn't allowed by namespaces in XML,
+/// for example an attribute with a prefix but no namespace.
+// https://heycam.github.io/webidl/#namespaceerror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct NamespaceError( Reference );
+
+impl IError for NamespaceError {}
+impl IDomException for NamespaceError {}
+
+error_boilerplate! { NamespaceError, name = "NamespaceError" }
+
 #[cfg(all(test, feature = "web_test"))]
 mod test {
     use super::*;
@@ -1,11 +1,15 @@
-use webcore::value::Reference;
-use webcore::try_from::TryInto;
-use webapi::dom_exception::{InvalidCharacterError, InvalidPointerId};
-use webapi::event_target::{IEventTarget, EventTarget};
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::dom_exception::{InvalidCharacterError, InvalidPointerId, NamespaceError, SyntaxError};
+use webapi::error::TypeError;
+use webapi::event::ConcreteEvent;
+use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle};
 use webapi::node::{INode, Node};
 use webapi::token_list::TokenList;
+use webapi::html_collection::HtmlCollection;
 use webapi::parent_node::IParentNode;
 use webapi::child_node::IChildNode;
+use webapi::animation::{Animation, KeyframeAnimationOptions};
 
 /// The `IElement` interface represents an object of a [Document](struct.Document.html).
 /// This interface describes methods and properties common to all
@@ -26,6 +30,93 @@ pub trait IElement: INode + IParentNode + IChildNode {
         }
     }
 
+    /// Returns a live [TokenList](struct.TokenList.html) for the given space-separated
+    /// attribute, for example `"rel"` on a link or `"sandbox"` on an iframe.
+    ///
+    /// The `class`, `rel` and `for` attributes are mapped to their `classList`, `relList`
+    /// and `htmlFor` properties, and any other attribute the element reflects as a
+    /// `DOMTokenList` (like `sandbox`, `sizes` or `part`) is returned as is. For every other
+    /// attribute (like `ping` or a `data-` attribute) the returned list reads and writes
+    /// the attribute's value directly.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList)
+    // https://dom.spec.whatwg.org/#interface-domtokenlist
+    fn token_list( &self, attribute: &str ) -> TokenList {
+        let list = js!(
+            var element = @{self.as_ref()};
+            var properties = {
+                "class": "classList",
+                "rel": "relList",
+                "for": "htmlFor"
+            };
+            var attribute = @{attribute};
+            var list = element[ properties[ attribute ] || attribute ];
+            if( list instanceof DOMTokenList ) {
+                return list;
+            }
+
+            // A `DOMTokenList` can't be constructed from script, so we build an
+            // object with the same prototype which is backed by the attribute.
+            var tokens = function() {
+                var value = element.getAttribute( attribute ) || "";
+                return value.split( new RegExp( "[ \\t\\n\\f\\r]+" ) ).filter( function( token ) {
+                    return token !== "";
+                }).filter( function( token, index, array ) {
+                    return array.indexOf( token ) === index;
+                });
+            };
+            var update = function( list ) {
+                element.setAttribute( attribute, list.join( " " ) );
+            };
+            list = Object.create( DOMTokenList.prototype );
+            Object.defineProperty( list, "length", { get: function() { return tokens().length; } });
+            Object.defineProperty( list, "value", {
+                get: function() { return element.getAttribute( attribute ) || ""; },
+                set: function( value ) { element.setAttribute( attribute, value ); }
+            });
+            list.item = function( index ) {
+                var list = tokens();
+                return index < list.length ? list[ index ] : null;
+            };
+            list.contains = function( token ) {
+                return tokens().indexOf( token ) !== -1;
+            };
+            list.add = function() {
+                var list = tokens();
+                for( var i = 0; i < arguments.length; ++i ) {
+                    if( list.indexOf( arguments[ i ] ) === -1 ) {
+                        list.push( arguments[ i ] );
+                    }
+                }
+                update( list );
+            };
+            list.remove = function() {
+                var removed = Array.prototype.slice.call( arguments );
+                update( tokens().filter( function( token ) {
+                    return removed.indexOf( token ) === -1;
+                }));
+            };
+            list.toggle = function( token, force ) {
+                var present = this.contains( token );
+                var wanted = force === undefined ? !present : !!force;
+                if( wanted && !present ) {
+                    this.add( token );
+                } else if( !wanted && present ) {
+                    this.remove( token );
+                }
+                return wanted;
+            };
+            list.toString = function() {
+                return this.value;
+            };
+            return list;
+        );
+
+        unsafe {
+            list.into_reference_unchecked().unwrap()
+        }
+    }
+
     /// The Element.hasAttribute() method returns a Boolean value indicating whether
     /// the specified element has the specified attribute or not.
     ///
@@ -61,6 +152,46 @@ pub trait IElement: INode + IParentNode + IChildNode {
         ).unwrap()
     }
 
+    /// Sets the values of several attributes at once, as if
+    /// [set_attribute](#method.set_attribute) was called for each `(name, value)` pair.
+    ///
+    /// The attributes are set in order; if one of the names is invalid
+    /// the remaining attributes are not set.
+    fn set_attributes( &self, attributes: &[(&str, &str)] ) -> Result< (), InvalidCharacterError > {
+        let names: Vec< &str > = attributes.iter().map( |&(name, _)| name ).collect();
+        let values: Vec< &str > = attributes.iter().map( |&(_, value)| value ).collect();
+        js_try!( @(no_return)
+            var element = @{self.as_ref()};
+            var names = @{names};
+            var values = @{values};
+            for( var i = 0; i < names.length; ++i ) {
+                element.setAttribute( names[ i ], values[ i ] );
+            }
+        ).unwrap()
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all the descendants of
+    /// this element which have all of the given space-separated class names.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getElementsByClassName)
+    // https://dom.spec.whatwg.org/#dom-element-getelementsbyclassname
+    fn get_elements_by_class_name( &self, class_names: &str ) -> HtmlCollection {
+        unsafe {
+            js!( return @{self.as_ref()}.getElementsByClassName( @{class_names} ); ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all the descendants of
+    /// this element with the given tag name; `"*"` matches all elements.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getElementsByTagName)
+    // https://dom.spec.whatwg.org/#dom-element-getelementsbytagname
+    fn get_elements_by_tag_name( &self, tag: &str ) -> HtmlCollection {
+        unsafe {
+            js!( return @{self.as_ref()}.getElementsByTagName( @{tag} ); ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// Gets the the number of pixels that an element's content is scrolled vertically.
     ///
     /// [(Javascript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollTop)
@@ -122,6 +253,54 @@ pub trait IElement: INode + IParentNode + IChildNode {
         }
     }
 
+    /// Returns whether the element has an attribute with the given `namespace` and `local_name`.
+    ///
+    /// A `namespace` of `None` refers to attributes without a namespace, like the ones
+    /// set with [set_attribute](#method.set_attribute).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/hasAttributeNS)
+    // https://dom.spec.whatwg.org/#dom-element-hasattributens
+    fn has_attribute_ns( &self, namespace: Option< &str >, local_name: &str ) -> bool {
+        js!(
+            return @{self.as_ref()}.hasAttributeNS( @{namespace}, @{local_name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the value of the attribute with the given `namespace` and `local_name`,
+    /// or `None` if there is no such attribute.
+    ///
+    /// For example the `href` of an SVG `<use>` element can be read with
+    /// `get_attribute_ns( Some( "http://www.w3.org/1999/xlink" ), "href" )`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getAttributeNS)
+    // https://dom.spec.whatwg.org/#dom-element-getattributens
+    fn get_attribute_ns( &self, namespace: Option< &str >, local_name: &str ) -> Option< String > {
+        js!(
+            return @{self.as_ref()}.getAttributeNS( @{namespace}, @{local_name} );
+        ).try_into().unwrap()
+    }
+
+    /// Sets the value of the attribute with the given `namespace` and `qualified_name`,
+    /// which may include a prefix, like `xlink:href`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/setAttributeNS)
+    // https://dom.spec.whatwg.org/#dom-element-setattributens
+    fn set_attribute_ns( &self, namespace: Option< &str >, qualified_name: &str, value: &str ) -> Result< (), SetAttributeNsError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.setAttributeNS( @{namespace}, @{qualified_name}, @{value} );
+        ).unwrap()
+    }
+
+    /// Removes the attribute with the given `namespace` and `local_name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/removeAttributeNS)
+    // https://dom.spec.whatwg.org/#dom-element-removeattributens
+    fn remove_attribute_ns( &self, namespace: Option< &str >, local_name: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.removeAttributeNS( @{namespace}, @{local_name} );
+        }
+    }
+
     /// The Element.hasAttributes() method returns Boolean value, indicating if
     /// the current element has any attributes or not.
     ///
@@ -163,6 +342,244 @@ pub trait IElement: INode + IParentNode + IChildNode {
     fn has_pointer_capture( &self, pointer_id: i32 ) -> bool {
         js!( return @{self.as_ref()}.hasPointerCapture( @{pointer_id} ); ).try_into().unwrap()
     }
+
+    /// Creates and starts a new [Animation](struct.Animation.html) on this element.
+    ///
+    /// The `keyframes` are passed as a JavaScript value, for example an array of
+    /// objects like `[ { opacity: 0 }, { opacity: 1 } ]`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/animate)
+    // https://drafts.csswg.org/web-animations-1/#dom-animatable-animate
+    fn animate( &self, keyframes: &Value, options: KeyframeAnimationOptions ) -> Animation {
+        Animation::new( self.as_ref(), keyframes, options )
+    }
+
+    /// Returns the value of the given ARIA attribute; the `aria-` prefix is added automatically,
+    /// so `get_aria( "label" )` returns the value of `aria-label`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques)
+    // https://www.w3.org/TR/wai-aria-1.1/#state_prop_def
+    fn get_aria( &self, name: &str ) -> Option< String > {
+        self.get_attribute( &format!( "aria-{}", name ) )
+    }
+
+    /// Sets the value of the given ARIA attribute; the `aria-` prefix is added automatically,
+    /// so `set_aria( "label", "Close" )` sets `aria-label`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques)
+    // https://www.w3.org/TR/wai-aria-1.1/#state_prop_def
+    fn set_aria( &self, name: &str, value: &str ) -> Result< (), InvalidCharacterError > {
+        self.set_attribute( &format!( "aria-{}", name ), value )
+    }
+
+    /// Sets the ARIA `role` attribute of the element, for example `"button"` or `"dialog"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles)
+    // https://www.w3.org/TR/wai-aria-1.1/#host_general_role
+    fn set_role( &self, role: &str ) {
+        self.set_attribute( "role", role ).unwrap();
+    }
+
+    /// Sets the `aria-hidden` attribute to `"true"` or `"false"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques/Using_the_aria-hidden_attribute)
+    // https://www.w3.org/TR/wai-aria-1.1/#aria-hidden
+    fn set_aria_hidden( &self, hidden: bool ) {
+        self.set_attribute( "aria-hidden", if hidden { "true" } else { "false" } ).unwrap();
+    }
+
+    /// Sets the `aria-label` attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques/Using_the_aria-label_attribute)
+    // https://www.w3.org/TR/wai-aria-1.1/#aria-label
+    fn set_aria_label( &self, label: &str ) {
+        self.set_attribute( "aria-label", label ).unwrap();
+    }
+
+    /// Sets the `aria-expanded` attribute to `"true"` or `"false"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques)
+    // https://www.w3.org/TR/wai-aria-1.1/#aria-expanded
+    fn set_aria_expanded( &self, expanded: bool ) {
+        self.set_attribute( "aria-expanded", if expanded { "true" } else { "false" } ).unwrap();
+    }
+
+    /// Returns the closest ancestor of the element (or the element itself) which
+    /// matches the given selector, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/closest)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-closest
+    fn closest( &self, selector: &str ) -> Result< Option< Element >, SyntaxError > {
+        js_try!(
+            return @{self.as_ref()}.closest( @{selector} );
+        ).unwrap()
+    }
+
+    /// Returns the next sibling of the element which is also an element,
+    /// skipping text and comment nodes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NonDocumentTypeChildNode/nextElementSibling)
+    // https://dom.spec.whatwg.org/#dom-nondocumenttypechildnode-nextelementsibling
+    fn next_element_sibling( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.nextElementSibling;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the previous sibling of the element which is also an element,
+    /// skipping text and comment nodes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NonDocumentTypeChildNode/previousElementSibling)
+    // https://dom.spec.whatwg.org/#dom-nondocumenttypechildnode-previouselementsibling
+    fn previous_element_sibling( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.previousElementSibling;
+        ).try_into().unwrap()
+    }
+
+    /// Replaces the contents of the element with the given `html`, with anything
+    /// which could run scripts (`<script>` elements, event handler attributes,
+    /// `javascript:` URLs and so on) stripped out.
+    ///
+    /// This uses the browser's built-in HTML sanitizer (`Element.setHTML`) where
+    /// it's available, which also works under a Trusted Types CSP. Otherwise the
+    /// `html` is parsed into an inert document and only a conservative set of
+    /// formatting elements and attributes is copied over.
+    ///
+    /// Parsing the `html` is itself a Trusted Types sink, so when Trusted Types are
+    /// available the fallback goes through a `stdweb-sanitizer` policy, which only ever
+    /// feeds the inert document. This returns a `TypeError` if the page's CSP doesn't
+    /// allow that policy to be created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/setHTML)
+    // https://wicg.github.io/sanitizer-api/#dom-element-sethtml
+    fn set_inner_html_sanitized( &self, html: &str ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            var element = @{self.as_ref()};
+            var owner = element.ownerDocument;
+            var html = @{html};
+            if( typeof element.setHTML === "function" ) {
+                element.setHTML( html );
+            } else {
+                var allowed_elements = [
+                    "a", "abbr", "b", "blockquote", "br", "code", "dd", "div", "dl", "dt", "em",
+                    "h1", "h2", "h3", "h4", "h5", "h6", "hr", "i", "li", "ol", "p", "pre", "q",
+                    "s", "small", "span", "strong", "sub", "sup", "u", "ul"
+                ];
+                var dropped_elements = [
+                    "script", "style", "template", "iframe", "object", "embed", "noscript", "svg", "math"
+                ];
+                var allowed_attributes = [ "class", "href", "title" ];
+                var safe_url = new RegExp( "^(https?:|mailto:|[^:]*$)", "i" );
+
+                if( typeof trustedTypes !== "undefined" && typeof trustedTypes.createPolicy === "function" ) {
+                    var policy = Module.STDWEB_PRIVATE.sanitizer_policy;
+                    if( !policy ) {
+                        policy = trustedTypes.createPolicy( "stdweb-sanitizer", {
+                            createHTML: function( html ) { return html; }
+                        });
+                        Module.STDWEB_PRIVATE.sanitizer_policy = policy;
+                    }
+                    html = policy.createHTML( html );
+                }
+
+                var parsed = new DOMParser().parseFromString( html, "text/html" );
+                var sanitize = function( source, target ) {
+                    for( var i = 0; i < source.childNodes.length; ++i ) {
+                        var node = source.childNodes[ i ];
+                        if( node.nodeType === Node.TEXT_NODE ) {
+                            target.appendChild( owner.createTextNode( node.data ) );
+                        } else if( node.nodeType === Node.ELEMENT_NODE ) {
+                            var name = node.localName;
+                            if( dropped_elements.indexOf( name ) !== -1 ) {
+                                continue;
+                            }
+
+                            if( allowed_elements.indexOf( name ) === -1 ) {
+                                sanitize( node, target );
+                                continue;
+                            }
+
+                            var copy = owner.createElement( name );
+                            for( var j = 0; j < node.attributes.length; ++j ) {
+                                var attribute = node.attributes[ j ];
+                                if( allowed_attributes.indexOf( attribute.name ) === -1 ) {
+                                    continue;
+                                }
+
+                                if( attribute.name === "href" && !safe_url.test( attribute.value.trim() ) ) {
+                                    continue;
+                                }
+
+                                copy.setAttribute( attribute.name, attribute.value );
+                            }
+
+                            sanitize( node, copy );
+                            target.appendChild( copy );
+                        }
+                    }
+                };
+
+                var fragment = owner.createDocumentFragment();
+                sanitize( parsed.body, fragment );
+                while( element.firstChild ) {
+                    element.removeChild( element.firstChild );
+                }
+                element.appendChild( fragment );
+            }
+        ).unwrap()
+    }
+
+    /// Adds a single event listener on this element which is only invoked for events
+    /// whose target lies within a descendant matching `selector`.
+    ///
+    /// The handler receives the event and the matching element, as found by calling
+    /// [closest](#method.closest) on the event's target. Only descendants are
+    /// matched; this element itself and elements outside of it are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `selector` is not a valid CSS selector.
+    fn on_delegated< T, F >( &self, selector: &str, mut handler: F ) -> EventListenerHandle
+        where Self: IEventTarget, T: ConcreteEvent, F: FnMut( T, Element ) + 'static
+    {
+        if let Err( error ) = self.closest( selector ) {
+            panic!( "Invalid selector {:?}: {:?}", selector, error );
+        }
+
+        let container: Node = self.as_ref().clone().downcast().unwrap();
+        let selector = selector.to_owned();
+        self.add_event_listener( move |event: T| {
+            let target: Node = match event.target().and_then( |target| target.as_ref().clone().downcast() ) {
+                Some( target ) => target,
+                None => return
+            };
+
+            let element = match target.as_ref().clone().downcast::< Element >() {
+                Some( element ) => element,
+                None => match target.parent_element() {
+                    Some( element ) => element,
+                    None => return
+                }
+            };
+
+            if let Ok( Some( matched ) ) = element.closest( &selector ) {
+                if matched.as_ref() != container.as_ref() && container.contains( &matched ) {
+                    handler( event, matched );
+                }
+            }
+        })
+    }
+}
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that [`IElement::set_attribute_ns`](trait.IElement.html#method.set_attribute_ns)
+    /// may throw.
+    SetAttributeNsError,
+    /// The qualified name contains characters which aren't allowed in names.
+    InvalidCharacterError,
+    /// The prefix and the namespace don't match, for example a prefix without a namespace.
+    NamespaceError
 }
 
 /// A reference to a JavaScript object which implements the [IElement](trait.IElement.html)
@@ -181,3 +598,273 @@ impl IElement for Element {}
 
 impl< T: IElement > IParentNode for T {}
 impl< T: IElement > IChildNode for T {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::{document, Document};
+    use webapi::error::IError;
+
+    #[test]
+    fn test_token_list() {
+        let link = document().create_element( "a" ).unwrap();
+        let rel = link.token_list( "rel" );
+        rel.add( "noopener" ).unwrap();
+        rel.add( "noreferrer" ).unwrap();
+        assert_eq!( link.get_attribute( "rel" ), Some( "noopener noreferrer".to_owned() ) );
+        rel.remove( "noopener" ).unwrap();
+        assert_eq!( link.get_attribute( "rel" ), Some( "noreferrer".to_owned() ) );
+
+        link.token_list( "class" ).add( "external" ).unwrap();
+        assert_eq!( link.class_list().contains( "external" ), true );
+    }
+
+    #[test]
+    fn test_attribute_backed_token_list() {
+        let link = document().create_element( "a" ).unwrap();
+        let ping = link.token_list( "ping" );
+        assert_eq!( ping.len(), 0 );
+        ping.add( "https://a.example" ).unwrap();
+        ping.add( "https://b.example" ).unwrap();
+        ping.add( "https://a.example" ).unwrap();
+        assert_eq!( link.get_attribute( "ping" ), Some( "https://a.example https://b.example".to_owned() ) );
+        assert_eq!( ping.len(), 2 );
+
+        link.set_attribute( "ping", "  https://b.example\thttps://c.example " ).unwrap();
+        assert!( ping.contains( "https://c.example" ) );
+        assert!( !ping.contains( "https://a.example" ) );
+        ping.remove( "https://b.example" ).unwrap();
+        assert_eq!( link.get_attribute( "ping" ), Some( "https://c.example".to_owned() ) );
+
+        let data = link.token_list( "data-tags" );
+        assert!( !data.contains( "a" ) );
+        data.add( "a" ).unwrap();
+        assert_eq!( link.get_attribute( "data-tags" ), Some( "a".to_owned() ) );
+    }
+
+    #[test]
+    fn test_get_elements_by() {
+        let parent = document().create_element( "div" ).unwrap();
+        let items = parent.get_elements_by_class_name( "item" );
+        let spans = parent.get_elements_by_tag_name( "span" );
+        assert_eq!( items.len(), 0 );
+
+        let child = document().create_element( "span" ).unwrap();
+        child.class_list().add( "item" ).unwrap();
+        parent.append_child( &child );
+
+        assert_eq!( items.len(), 1 );
+        assert_eq!( spans.len(), 1 );
+        assert_eq!( items.item( 0 ), Some( child ) );
+    }
+
+    #[test]
+    fn test_aria() {
+        let element = document().create_element( "div" ).unwrap();
+        assert_eq!( element.get_aria( "label" ), None );
+
+        element.set_aria_label( "Close" );
+        assert_eq!( element.get_attribute( "aria-label" ), Some( "Close".to_owned() ) );
+
+        element.set_aria( "controls", "menu" ).unwrap();
+        assert_eq!( element.get_aria( "controls" ), Some( "menu".to_owned() ) );
+
+        element.set_aria_hidden( true );
+        assert_eq!( element.get_aria( "hidden" ), Some( "true".to_owned() ) );
+        element.set_aria_expanded( false );
+        assert_eq!( element.get_aria( "expanded" ), Some( "false".to_owned() ) );
+
+        element.set_role( "button" );
+        assert_eq!( element.get_attribute( "role" ), Some( "button".to_owned() ) );
+    }
+
+    #[test]
+    fn test_set_attributes() {
+        let element = document().create_element( "div" ).unwrap();
+        element.set_attributes( &[("id", "foo"), ("title", "bar")] ).unwrap();
+        assert_eq!( element.get_attribute( "id" ), Some( "foo".to_owned() ) );
+        assert_eq!( element.get_attribute( "title" ), Some( "bar".to_owned() ) );
+        assert!( element.set_attributes( &[("!!", "baz")] ).is_err() );
+    }
+
+    #[test]
+    fn test_set_inner_html_sanitized() {
+        let element = document().create_element( "div" ).unwrap();
+        element.set_inner_html_sanitized( "<b onclick=\"alert(1)\">hi</b><script>alert(2)</script><a href=\"javascript:alert(3)\">link</a>" ).unwrap();
+
+        assert!( element.query_selector( "script" ).unwrap().is_none() );
+        let bold = element.query_selector( "b" ).unwrap().unwrap();
+        assert!( !bold.has_attribute( "onclick" ) );
+        assert_eq!( bold.text_content(), Some( "hi".to_owned() ) );
+        let link = element.query_selector( "a" ).unwrap().unwrap();
+        assert!( !link.has_attribute( "href" ) );
+
+        // The Trusted Types policy, if any, is only created once.
+        element.set_inner_html_sanitized( "<i>again</i>" ).unwrap();
+        assert_eq!( element.text_content(), Some( "again".to_owned() ) );
+    }
+
+    #[test]
+    fn test_set_inner_html_sanitized_uses_the_owner_document() {
+        let other: Document = js!( return document.implementation.createHTMLDocument( "" ); ).try_into().unwrap();
+        let element = other.create_element( "div" ).unwrap();
+        js! { @(no_return) @{&element}.setHTML = undefined; }
+
+        element.set_inner_html_sanitized( "<b>hi</b>" ).unwrap();
+        let bold = element.query_selector( "b" ).unwrap().unwrap();
+        assert_eq!( bold.owner_document(), Some( other ) );
+    }
+
+    #[test]
+    fn test_set_inner_html_sanitized_refused_policy() {
+        let element = document().create_element( "div" ).unwrap();
+
+        // Simulate a CSP which doesn't allow the policy to be created.
+        let installed: bool = js!(
+            var stub = {
+                createPolicy: function() {
+                    throw new TypeError( "Policy not allowed by CSP" );
+                }
+            };
+            try {
+                Object.defineProperty( window, "trustedTypes", { value: stub, configurable: true, writable: true } );
+            } catch( error ) {
+                return false;
+            }
+
+            @{&element}.setHTML = undefined;
+            Module.STDWEB_PRIVATE.saved_sanitizer_policy = Module.STDWEB_PRIVATE.sanitizer_policy;
+            Module.STDWEB_PRIVATE.sanitizer_policy = undefined;
+            return window.trustedTypes === stub;
+        ).try_into().unwrap();
+
+        if installed {
+            let result = element.set_inner_html_sanitized( "<b>hi</b>" );
+            js! { @(no_return)
+                delete window.trustedTypes;
+                Module.STDWEB_PRIVATE.sanitizer_policy = Module.STDWEB_PRIVATE.saved_sanitizer_policy;
+                delete Module.STDWEB_PRIVATE.saved_sanitizer_policy;
+            }
+
+            assert_eq!( result.unwrap_err().message(), "Policy not allowed by CSP" );
+            assert_eq!( element.child_nodes().len(), 0 );
+        }
+    }
+
+    #[test]
+    fn test_closest() {
+        let list = document().create_element( "ul" ).unwrap();
+        let item = document().create_element( "li" ).unwrap();
+        let span = document().create_element( "span" ).unwrap();
+        list.append_child( &item );
+        item.append_child( &span );
+
+        assert_eq!( span.closest( "li" ).unwrap(), Some( item ) );
+        assert_eq!( span.closest( "ul" ).unwrap(), Some( list ) );
+        assert_eq!( span.closest( "table" ).unwrap(), None );
+        assert!( span.closest( "!!" ).is_err() );
+    }
+
+    #[test]
+    fn test_element_siblings() {
+        let list = document().create_element( "ul" ).unwrap();
+        let first = document().create_element( "li" ).unwrap();
+        let second = document().create_element( "li" ).unwrap();
+        list.append_child( &first );
+        list.append_child( &document().create_text_node( " " ) );
+        list.append_child( &document().create_comment( "" ) );
+        list.append_child( &second );
+
+        assert_eq!( first.next_element_sibling(), Some( second.clone() ) );
+        assert_eq!( second.previous_element_sibling(), Some( first.clone() ) );
+        assert_eq!( first.previous_element_sibling(), None );
+        assert_eq!( second.next_element_sibling(), None );
+    }
+
+    #[test]
+    fn test_attribute_ns() {
+        let xlink = Some( "http://www.w3.org/1999/xlink" );
+        let element = document().create_element( "div" ).unwrap();
+
+        element.set_attribute_ns( xlink, "xlink:href", "#target" ).unwrap();
+        assert!( element.has_attribute_ns( xlink, "href" ) );
+        assert_eq!( element.get_attribute_ns( xlink, "href" ), Some( "#target".to_owned() ) );
+        assert_eq!( element.get_attribute( "xlink:href" ), Some( "#target".to_owned() ) );
+        assert!( !element.has_attribute_ns( None, "href" ) );
+
+        element.set_attribute_ns( None, "title", "plain" ).unwrap();
+        assert_eq!( element.get_attribute( "title" ), Some( "plain".to_owned() ) );
+        assert_eq!( element.get_attribute_ns( None, "title" ), Some( "plain".to_owned() ) );
+
+        element.remove_attribute_ns( xlink, "href" );
+        assert!( !element.has_attribute_ns( xlink, "href" ) );
+
+        match element.set_attribute_ns( None, "xlink:href", "" ) {
+            Err( SetAttributeNsError::NamespaceError( _ ) ) => (),
+            _ => panic!( "Expected NamespaceError" )
+        }
+        match element.set_attribute_ns( None, "a b", "" ) {
+            Err( SetAttributeNsError::InvalidCharacterError( _ ) ) => (),
+            _ => panic!( "Expected InvalidCharacterError" )
+        }
+    }
+
+    #[test]
+    fn test_on_delegated() {
+        use std::rc::Rc;
+        use std::cell::RefCell;
+        use webapi::events::mouse::ClickEvent;
+
+        let list = document().create_element( "ul" ).unwrap();
+        let item = document().create_element( "li" ).unwrap();
+        let span = document().create_element( "span" ).unwrap();
+        list.append_child( &item );
+        item.append_child( &span );
+
+        let matches = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = list.on_delegated( "li", {
+            let matches = matches.clone();
+            move |_: ClickEvent, matched| matches.borrow_mut().push( matched )
+        });
+
+        let click = || -> ClickEvent {
+            js!( return new MouseEvent( "click", { bubbles: true } ); ).try_into().unwrap()
+        };
+        span.dispatch_event( &click() ).unwrap();
+        list.dispatch_event( &click() ).unwrap();
+        assert_eq!( *matches.borrow(), vec![ item ] );
+
+        handle.remove();
+    }
+
+    #[test]
+    fn test_on_delegated_ignores_the_container() {
+        use std::rc::Rc;
+        use std::cell::RefCell;
+        use webapi::events::mouse::ClickEvent;
+
+        let list = document().create_element( "ul" ).unwrap();
+        let item = document().create_element( "li" ).unwrap();
+        list.class_list().add( "selectable" ).unwrap();
+        list.append_child( &item );
+
+        let matches = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = list.on_delegated( ".selectable", {
+            let matches = matches.clone();
+            move |_: ClickEvent, matched| matches.borrow_mut().push( matched )
+        });
+
+        let click = || -> ClickEvent {
+            js!( return new MouseEvent( "click", { bubbles: true } ); ).try_into().unwrap()
+        };
+        list.dispatch_event( &click() ).unwrap();
+        item.dispatch_event( &click() ).unwrap();
+        assert!( matches.borrow().is_empty() );
+
+        item.class_list().add( "selectable" ).unwrap();
+        item.dispatch_event( &click() ).unwrap();
+        assert_eq!( *matches.borrow(), vec![ item ] );
+
+        handle.remove();
+    }
+}
@@ -0,0 +1,140 @@
+use webcore::value::Value;
+use webcore::try_from::TryFrom;
+use webapi::document::Document;
+use webapi::element::Element;
+use webapi::node::INode;
+use webapi::dom_exception::{InvalidCharacterError, HierarchyRequestError};
+
+/// A builder for an [Element](struct.Element.html) with its attributes and children.
+///
+/// It's created by [Document::build_element](struct.Document.html#method.build_element).
+/// Nothing is done until [build](#method.build) is called, which then creates
+/// the whole element in one go.
+///
+/// # Examples
+///
+/// ```rust
+/// let row = document().build_element( "div" )
+///     .attr( "class", "row" )
+///     .text( "Hello!" )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ElementBuilder {
+    document: Document,
+    tag: String,
+    attributes: Vec< (String, String) >,
+    children: Vec< Value >
+}
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that [ElementBuilder::build](struct.ElementBuilder.html#method.build)
+    /// may throw.
+    BuildElementError,
+    /// The tag or one of the attribute names is not a valid name.
+    InvalidCharacterError,
+    /// One of the children can't be inserted into the element, for example a `Document`
+    /// or an ancestor of the element.
+    HierarchyRequestError
+}
+
+impl ElementBuilder {
+    pub(crate) fn new( document: Document, tag: &str ) -> Self {
+        ElementBuilder {
+            document,
+            tag: tag.to_owned(),
+            attributes: Vec::new(),
+            children: Vec::new()
+        }
+    }
+
+    /// Sets the attribute called `name` to `value`.
+    pub fn attr( mut self, name: &str, value: &str ) -> Self {
+        self.attributes.push( (name.to_owned(), value.to_owned()) );
+        self
+    }
+
+    /// Appends a text node with the given contents.
+    pub fn text( mut self, text: &str ) -> Self {
+        self.children.push( text.into() );
+        self
+    }
+
+    /// Appends the given node as a child.
+    pub fn child< T: INode >( mut self, child: &T ) -> Self {
+        self.children.push( child.as_ref().clone().into() );
+        self
+    }
+
+    /// Creates the element.
+    ///
+    /// Fails if the tag or one of the attribute names is not a valid name,
+    /// or if one of the children can't be inserted into the element.
+    pub fn build( self ) -> Result< Element, BuildElementError > {
+        let names: Vec< String > = self.attributes.iter().map( |&(ref name, _)| name.clone() ).collect();
+        let values: Vec< String > = self.attributes.into_iter().map( |(_, value)| value ).collect();
+        js_try!(
+            var document = @{self.document};
+            var element = document.createElement( @{self.tag} );
+            var names = @{names};
+            var values = @{values};
+            for( var i = 0; i < names.length; ++i ) {
+                element.setAttribute( names[ i ], values[ i ] );
+            }
+
+            var children = @{self.children};
+            for( var i = 0; i < children.length; ++i ) {
+                var child = children[ i ];
+                if( typeof child === "string" ) {
+                    child = document.createTextNode( child );
+                }
+                element.appendChild( child );
+            }
+
+            return element;
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::element::IElement;
+    use webapi::node::INode;
+
+    #[test]
+    fn test_build_element() {
+        let child = document().create_element( "span" ).unwrap();
+        let element = document().build_element( "div" )
+            .attr( "class", "row" )
+            .attr( "id", "first" )
+            .text( "hi" )
+            .child( &child )
+            .build()
+            .unwrap();
+
+        assert_eq!( element.get_attribute( "class" ), Some( "row".to_owned() ) );
+        assert_eq!( element.get_attribute( "id" ), Some( "first".to_owned() ) );
+        assert_eq!( element.text_content(), Some( "hi".to_owned() ) );
+        assert_eq!( element.child_nodes().len(), 2 );
+        assert_eq!( child.parent_node().map( |node| node.as_ref().clone() ), Some( element.as_ref().clone() ) );
+    }
+
+    #[test]
+    fn test_build_element_with_invalid_attribute() {
+        match document().build_element( "div" ).attr( "!!", "" ).build() {
+            Err( BuildElementError::InvalidCharacterError( _ ) ) => {},
+            result => panic!( "Unexpected result: {:?}", result )
+        }
+    }
+
+    #[test]
+    fn test_build_element_with_invalid_child() {
+        match document().build_element( "span" ).child( &document() ).build() {
+            Err( BuildElementError::HierarchyRequestError( _ ) ) => {},
+            result => panic!( "Unexpected result: {:?}", result )
+        }
+    }
+}
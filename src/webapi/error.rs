@@ -54,6 +54,36 @@ impl IError for Error {}
 
 error_boilerplate! { Error }
 
+/// Used to indicate an unsuccessful operation when none of the other NativeError objects are an appropriate indication of the failure cause.
+///
+/// This is a native JavaScript error, not a `DOMException`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
+// https://tc39.github.io/ecma262/#sec-native-error-types-used-in-this-standard-typeerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TypeError")]
+#[reference(subclass_of(Error))]
+pub struct TypeError( Reference );
+
+impl IError for TypeError {}
+
+error_boilerplate! { TypeError }
+
+/// Used to indicate that a value is not in the set or range of allowable values.
+///
+/// This is a native JavaScript error, not a `DOMException`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
+// https://tc39.github.io/ecma262/#sec-native-error-types-used-in-this-standard-rangeerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RangeError")]
+#[reference(subclass_of(Error))]
+pub struct RangeError( Reference );
+
+impl IError for RangeError {}
+
+error_boilerplate! { RangeError }
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -74,4 +104,30 @@ mod test {
         assert_eq!(&text, "Error: foo");
         assert_eq!(::std::error::Error::description(&error), "Error");
     }
+
+    #[test]
+    fn test_type_error() {
+        let error: TypeError = js!(
+            return new TypeError("foo");
+        ).try_into().unwrap();
+
+        assert_eq!(error.name(), "TypeError");
+        assert_eq!(error.message(), "foo");
+
+        let error: Result< TypeError, _ > = js!( return new Error("foo"); ).try_into();
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn test_range_error() {
+        let error: RangeError = js!(
+            return new RangeError("foo");
+        ).try_into().unwrap();
+
+        assert_eq!(error.name(), "RangeError");
+        assert_eq!(error.message(), "foo");
+
+        let error: Result< RangeError, _ > = js!( return new TypeError("foo"); ).try_into();
+        assert!(error.is_err());
+    }
 }
@@ -1,12 +1,32 @@
 use std::fmt;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::time::Duration;
+use discard::Discard;
 
 use webcore::value::Reference;
 use webcore::try_from::TryInto;
 use webcore::reference_type::ReferenceType;
+use webcore::once::Once;
 use webapi::event::{ConcreteEvent, IEvent};
+use webapi::global::set_timeout;
 use private::TODO;
 
+#[cfg(feature = "futures-support")]
+use futures_core::{Future, Poll};
+#[cfg(feature = "futures-support")]
+use futures_core::task::Context;
+#[cfg(feature = "futures-support")]
+use futures_channel::oneshot;
+#[cfg(feature = "futures-support")]
+use webapi::error::Error;
+
 /// A handle to a particular event listener.
+///
+/// The listener stays registered until [remove](#method.remove) is called, even
+/// if the handle is dropped. Wrap the handle in a [DiscardOnDrop](struct.DiscardOnDrop.html)
+/// to have both the JavaScript listener and the Rust closure cleaned up automatically
+/// once the wrapper goes out of scope.
 pub struct EventListenerHandle {
     event_type: &'static str,
     reference: Reference,
@@ -34,9 +54,121 @@ impl EventListenerHandle {
     }
 }
 
+impl Discard for EventListenerHandle {
+    #[inline]
+    fn discard( self ) {
+        self.remove();
+    }
+}
+
+/// The [`Future`](https://docs.rs/futures/0.2.*/futures/future/trait.Future.html) which is returned by
+/// [`IEventTarget::next_event`](trait.IEventTarget.html#method.next_event).
+///
+/// If it's dropped before the event fires the listener is removed.
+#[cfg(feature = "futures-support")]
+#[derive( Debug )]
+pub struct NextEvent< T > {
+    receiver: oneshot::Receiver< T >,
+    handle: Option< EventListenerHandle >
+}
+
+#[cfg(feature = "futures-support")]
+impl< T > Future for NextEvent< T > {
+    type Item = T;
+    // TODO use Void instead
+    type Error = Error;
+
+    #[inline]
+    fn poll( &mut self, cx: &mut Context ) -> Poll< Self::Item, Self::Error > {
+        self.receiver.poll( cx ).map_err( |_| unreachable!() )
+    }
+}
+
+#[cfg(feature = "futures-support")]
+impl< T > Drop for NextEvent< T > {
+    #[inline]
+    fn drop( &mut self ) {
+        if let Some( handle ) = self.handle.take() {
+            handle.remove();
+        }
+    }
+}
+
+struct Throttle< T, F > {
+    listener: RefCell< F >,
+    min_interval: f64,
+    last_fired: RefCell< Option< f64 > >,
+    pending: RefCell< Option< T > >
+}
+
+fn now() -> f64 {
+    js!( return performance.now(); ).try_into().unwrap()
+}
+
+impl< T: 'static, F: FnMut( T ) + 'static > Throttle< T, F > {
+    fn on_event( this: &Rc< Self >, event: T ) {
+        let now = now();
+        let last_fired = *this.last_fired.borrow();
+        match last_fired {
+            Some( last_fired ) if now - last_fired < this.min_interval => {
+                let delay = ( last_fired + this.min_interval - now ).ceil() as u32;
+                Throttle::queue( this, event, delay );
+            },
+            _ => Throttle::fire( this, now, event )
+        }
+    }
+
+    fn queue( this: &Rc< Self >, event: T, delay: u32 ) {
+        let already_scheduled = this.pending.borrow_mut().replace( event ).is_some();
+        if !already_scheduled {
+            // The timeout only holds a weak reference so that nothing
+            // fires anymore once the listener itself is removed.
+            let weak = Rc::downgrade( this );
+            set_timeout( move || {
+                if let Some( this ) = weak.upgrade() {
+                    Throttle::flush( &this );
+                }
+            }, delay );
+        }
+    }
+
+    fn flush( this: &Rc< Self > ) {
+        let event = this.pending.borrow_mut().take();
+        if let Some( event ) = event {
+            Throttle::fire( this, now(), event );
+        }
+    }
+
+    fn fire( this: &Rc< Self >, now: f64, event: T ) {
+        let mut listener = match this.listener.try_borrow_mut() {
+            Ok( listener ) => listener,
+            Err( _ ) => {
+                // The listener has dispatched an event to its own target; deliver
+                // it once the listener has returned instead of re-entering it.
+                Throttle::queue( this, event, 0 );
+                return;
+            }
+        };
+
+        *this.last_fired.borrow_mut() = Some( now );
+        ( &mut *listener )( event );
+    }
+}
+
 /// `IEventTarget` is an interface implemented by objects that
 /// can receive events and may have listeners for them.
 ///
+/// It's implemented by every type whose JavaScript counterpart is an `EventTarget`,
+/// so code which only deals with events can be generic over it:
+///
+/// ```rust
+/// fn on_click< T: IEventTarget >( target: &T ) -> EventListenerHandle {
+///     target.add_event_listener( |_: ClickEvent| {
+///         console!( log, "Clicked!" );
+///     })
+/// }
+/// ```
+///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget)
 // https://dom.spec.whatwg.org/#eventtarget
 pub trait IEventTarget: ReferenceType {
@@ -63,6 +195,75 @@ pub trait IEventTarget: ReferenceType {
         }
     }
 
+    /// Adds given event handler to the list of event listeners for the specified
+    /// `EventTarget`, which is automatically removed after it's called for the
+    /// first time.
+    ///
+    /// The closure is dropped as soon as it has been called. Calling
+    /// [remove](struct.EventListenerHandle.html#method.remove) on the returned
+    /// handle before that unregisters it without calling it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#Parameters)
+    // https://dom.spec.whatwg.org/#dom-addeventlisteneroptions-once
+    fn add_event_listener_once< T, F >( &self, listener: F ) -> EventListenerHandle
+        where T: ConcreteEvent, F: FnOnce( T ) + 'static
+    {
+        let reference = self.as_ref();
+
+        let listener_reference = js! {
+            var listener = @{Once( listener )};
+            @{reference}.addEventListener( @{T::EVENT_TYPE}, listener, { once: true } );
+            return listener;
+        }.try_into().unwrap();
+
+        EventListenerHandle {
+            event_type: T::EVENT_TYPE,
+            reference: reference.clone(),
+            listener_reference: listener_reference
+        }
+    }
+
+    /// Returns a [`Future`](https://docs.rs/futures/0.2.*/futures/future/trait.Future.html)
+    /// which resolves with the next event of type `T` fired at this `EventTarget`.
+    ///
+    /// The listener is removed once the event fires, or when the future is dropped.
+    #[cfg(feature = "futures-support")]
+    fn next_event< T >( &self ) -> NextEvent< T >
+        where T: ConcreteEvent + 'static
+    {
+        let ( sender, receiver ) = oneshot::channel();
+        let handle = self.add_event_listener_once( move |event: T| {
+            // The receiver may already have been dropped, in which case nobody cares about the event.
+            let _ = sender.send( event );
+        });
+
+        NextEvent {
+            receiver,
+            handle: Some( handle )
+        }
+    }
+
+    /// Adds given event handler to the list of event listeners for the specified
+    /// `EventTarget`, calling it at most once per `min_interval`.
+    ///
+    /// This is meant for high-frequency events like [ScrollEvent](event/struct.ScrollEvent.html)
+    /// or [ResizeEvent](event/struct.ResizeEvent.html). The first event is delivered
+    /// immediately; any events fired during the following `min_interval` are coalesced,
+    /// and only the most recent of them is delivered once the interval has elapsed.
+    fn add_throttled_event_listener< T, F >( &self, min_interval: Duration, listener: F ) -> EventListenerHandle
+        where T: ConcreteEvent + 'static, F: FnMut( T ) + 'static
+    {
+        let min_interval = min_interval.as_secs() as f64 * 1000.0 + min_interval.subsec_nanos() as f64 / 1_000_000.0;
+        let throttle = Rc::new( Throttle {
+            listener: RefCell::new( listener ),
+            min_interval,
+            last_fired: RefCell::new( None ),
+            pending: RefCell::new( None )
+        });
+
+        self.add_event_listener( move |event: T| Throttle::on_event( &throttle, event ) )
+    }
+
     /// Dispatches an `Event` at this `EventTarget`, invoking the affected event listeners in the
     /// appropriate order.
     ///
@@ -84,3 +285,156 @@ pub trait IEventTarget: ReferenceType {
 pub struct EventTarget( Reference );
 
 impl IEventTarget for EventTarget {}
+
+impl EventTarget {
+    /// Creates a new standalone `EventTarget`, which can be used to dispatch
+    /// events between different parts of an application.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/EventTarget)
+    // https://dom.spec.whatwg.org/#dom-eventtarget-eventtarget
+    pub fn new() -> Self {
+        js!(
+            return new EventTarget();
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::events::dom::ScrollEvent;
+
+    fn scroll_event() -> ScrollEvent {
+        js!(
+            return new Event( @{ScrollEvent::EVENT_TYPE} );
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_add_throttled_event_listener() {
+        let target: EventTarget = js!( return document.createElement( "div" ); ).try_into().unwrap();
+        let count = Rc::new( RefCell::new( 0 ) );
+        let handle = target.add_throttled_event_listener( Duration::from_secs( 60 ), {
+            let count = count.clone();
+            move |_: ScrollEvent| *count.borrow_mut() += 1
+        });
+
+        target.dispatch_event( &scroll_event() ).unwrap();
+        assert_eq!( *count.borrow(), 1 );
+
+        target.dispatch_event( &scroll_event() ).unwrap();
+        target.dispatch_event( &scroll_event() ).unwrap();
+        assert_eq!( *count.borrow(), 1 );
+
+        handle.remove();
+    }
+
+    #[test]
+    fn test_throttled_event_listener_delivers_the_last_pending_event() {
+        let target: EventTarget = js!( return document.createElement( "div" ); ).try_into().unwrap();
+        let events: Rc< RefCell< Vec< String > > > = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = target.add_throttled_event_listener( Duration::from_millis( 10 ), {
+            let events = events.clone();
+            move |event: ScrollEvent| {
+                let tag: String = js!( return @{event}.tag; ).try_into().unwrap();
+                events.borrow_mut().push( tag );
+            }
+        });
+
+        let dispatch = |tag: &str| {
+            let event = scroll_event();
+            js! { @(no_return) @{&event}.tag = @{tag}; }
+            target.dispatch_event( &event ).unwrap();
+        };
+
+        dispatch( "first" );
+        dispatch( "second" );
+        dispatch( "third" );
+        assert_eq!( *events.borrow(), vec![ "first".to_owned() ] );
+
+        set_timeout( move || {
+            assert_eq!( *events.borrow(), vec![ "first".to_owned(), "third".to_owned() ] );
+            handle.remove();
+        }, 50 );
+    }
+
+    #[test]
+    fn test_throttled_event_listener_reentry() {
+        let target: EventTarget = js!( return document.createElement( "div" ); ).try_into().unwrap();
+        let count = Rc::new( RefCell::new( 0 ) );
+        let handle = target.add_throttled_event_listener( Duration::from_millis( 0 ), {
+            let target = target.clone();
+            let count = count.clone();
+            move |_: ScrollEvent| {
+                *count.borrow_mut() += 1;
+                if *count.borrow() == 1 {
+                    target.dispatch_event( &scroll_event() ).unwrap();
+                }
+            }
+        });
+
+        target.dispatch_event( &scroll_event() ).unwrap();
+        assert_eq!( *count.borrow(), 1 );
+
+        set_timeout( move || {
+            assert_eq!( *count.borrow(), 2 );
+            handle.remove();
+        }, 20 );
+    }
+
+    #[test]
+    fn test_add_event_listener_once() {
+        let target: EventTarget = js!( return document.createElement( "div" ); ).try_into().unwrap();
+        let count = Rc::new( RefCell::new( 0 ) );
+        let handle = target.add_event_listener_once( {
+            let count = count.clone();
+            move |_: ScrollEvent| *count.borrow_mut() += 1
+        });
+
+        target.dispatch_event( &scroll_event() ).unwrap();
+        target.dispatch_event( &scroll_event() ).unwrap();
+        assert_eq!( *count.borrow(), 1 );
+
+        // Removing an already fired listener is harmless.
+        handle.remove();
+    }
+
+    fn count_scroll_events< T: IEventTarget >( target: &T ) -> Rc< RefCell< u32 > > {
+        let count = Rc::new( RefCell::new( 0 ) );
+        target.add_event_listener( {
+            let count = count.clone();
+            move |_: ScrollEvent| *count.borrow_mut() += 1
+        });
+
+        target.dispatch_event( &scroll_event() ).unwrap();
+        count
+    }
+
+    #[test]
+    fn test_generic_event_targets() {
+        use webapi::document::document;
+
+        assert_eq!( *count_scroll_events( &EventTarget::new() ).borrow(), 1 );
+        assert_eq!( *count_scroll_events( &document().create_element( "div" ).unwrap() ).borrow(), 1 );
+        assert_eq!( *count_scroll_events( &document().create_text_node( "" ) ).borrow(), 1 );
+    }
+
+    #[test]
+    fn test_discard_on_drop_removes_listener() {
+        use webcore::discard::DiscardOnDrop;
+
+        let target: EventTarget = js!( return document.createElement( "div" ); ).try_into().unwrap();
+        let count = Rc::new( RefCell::new( 0 ) );
+        let handle = DiscardOnDrop::new( target.add_event_listener( {
+            let count = count.clone();
+            move |_: ScrollEvent| *count.borrow_mut() += 1
+        }));
+
+        target.dispatch_event( &scroll_event() ).unwrap();
+        assert_eq!( *count.borrow(), 1 );
+
+        drop( handle );
+        target.dispatch_event( &scroll_event() ).unwrap();
+        assert_eq!( *count.borrow(), 1 );
+    }
+}
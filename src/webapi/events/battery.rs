@@ -0,0 +1,69 @@
+use webcore::value::Reference;
+use webapi::event::{IEvent, Event};
+
+/// The `ChargingChangeEvent` is fired on a [BatteryManager](../struct.BatteryManager.html)
+/// when the battery starts or stops being charged.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/onchargingchange)
+// https://w3c.github.io/battery/#the-chargingchange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "chargingchange")]
+#[reference(subclass_of(Event))]
+pub struct ChargingChangeEvent( Reference );
+
+impl IEvent for ChargingChangeEvent {}
+
+/// The `LevelChangeEvent` is fired on a [BatteryManager](../struct.BatteryManager.html)
+/// when the battery's charge level changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/onlevelchange)
+// https://w3c.github.io/battery/#the-levelchange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "levelchange")]
+#[reference(subclass_of(Event))]
+pub struct LevelChangeEvent( Reference );
+
+impl IEvent for LevelChangeEvent {}
+
+/// The `ChargingTimeChangeEvent` is fired on a [BatteryManager](../struct.BatteryManager.html)
+/// when the remaining charging time changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/onchargingtimechange)
+// https://w3c.github.io/battery/#the-chargingtimechange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "chargingtimechange")]
+#[reference(subclass_of(Event))]
+pub struct ChargingTimeChangeEvent( Reference );
+
+impl IEvent for ChargingTimeChangeEvent {}
+
+/// The `DischargingTimeChangeEvent` is fired on a [BatteryManager](../struct.BatteryManager.html)
+/// when the remaining discharging time changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BatteryManager/ondischargingtimechange)
+// https://w3c.github.io/battery/#the-dischargingtimechange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "dischargingtimechange")]
+#[reference(subclass_of(Event))]
+pub struct DischargingTimeChangeEvent( Reference );
+
+impl IEvent for DischargingTimeChangeEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_level_change_event() {
+        let event: LevelChangeEvent = js!(
+            return new Event( @{LevelChangeEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), LevelChangeEvent::EVENT_TYPE );
+    }
+}
@@ -0,0 +1,247 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event};
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::permissions::PermissionState;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+
+/// The `DeviceOrientationEvent` is fired on the `window` when fresh data
+/// is available about the physical orientation of the device.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent)
+// https://w3c.github.io/deviceorientation/#deviceorientation
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DeviceOrientationEvent")]
+#[reference(event = "deviceorientation")]
+#[reference(subclass_of(Event))]
+pub struct DeviceOrientationEvent( Reference );
+
+impl IEvent for DeviceOrientationEvent {}
+
+impl DeviceOrientationEvent {
+    /// Returns the rotation of the device around its z axis, in degrees from 0 to 360.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/alpha)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-alpha
+    #[inline]
+    pub fn alpha( &self ) -> Option< f64 > {
+        js!(
+            return @{self.as_ref()}.alpha;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the rotation of the device around its x axis, in degrees from -180 to 180.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/beta)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-beta
+    #[inline]
+    pub fn beta( &self ) -> Option< f64 > {
+        js!(
+            return @{self.as_ref()}.beta;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the rotation of the device around its y axis, in degrees from -90 to 90.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/gamma)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-gamma
+    #[inline]
+    pub fn gamma( &self ) -> Option< f64 > {
+        js!(
+            return @{self.as_ref()}.gamma;
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the orientation is relative to the Earth's coordinate frame,
+    /// rather than to an arbitrary frame.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/absolute)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-absolute
+    #[inline]
+    pub fn absolute( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.absolute;
+        ).try_into().unwrap()
+    }
+
+    /// Asks the user for permission to receive orientation events.
+    ///
+    /// This is required on iOS, and has to be called from within a user gesture handler.
+    /// On browsers which don't require a permission this always resolves to
+    /// `PermissionState::Granted`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent#Browser_compatibility)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-requestpermission
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn request_permission() -> PromiseFuture< PermissionState, Error > {
+        js!(
+            if( typeof DeviceOrientationEvent.requestPermission === "function" ) {
+                return DeviceOrientationEvent.requestPermission();
+            }
+            return Promise.resolve( "granted" );
+        ).try_into().unwrap()
+    }
+}
+
+/// The acceleration of a device, in meters per second squared.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEventAcceleration)
+// https://w3c.github.io/deviceorientation/#devicemotioneventacceleration
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceAcceleration {
+    /// The acceleration along the x axis.
+    pub x: Option< f64 >,
+    /// The acceleration along the y axis.
+    pub y: Option< f64 >,
+    /// The acceleration along the z axis.
+    pub z: Option< f64 >
+}
+
+/// The rate of rotation of a device, in degrees per second.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEventRotationRate)
+// https://w3c.github.io/deviceorientation/#devicemotioneventrotationrate
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviceRotationRate {
+    /// The rate of rotation around the z axis.
+    pub alpha: Option< f64 >,
+    /// The rate of rotation around the x axis.
+    pub beta: Option< f64 >,
+    /// The rate of rotation around the y axis.
+    pub gamma: Option< f64 >
+}
+
+/// The `DeviceMotionEvent` is fired on the `window` at a regular interval,
+/// indicating the acceleration and rotation rate of the device.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent)
+// https://w3c.github.io/deviceorientation/#devicemotion
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DeviceMotionEvent")]
+#[reference(event = "devicemotion")]
+#[reference(subclass_of(Event))]
+pub struct DeviceMotionEvent( Reference );
+
+impl IEvent for DeviceMotionEvent {}
+
+impl DeviceMotionEvent {
+    fn get_acceleration( &self, property: &str ) -> Option< DeviceAcceleration > {
+        let values: Option< Vec< Option< f64 > > > = js!(
+            var acceleration = @{self.as_ref()}[ @{property} ];
+            if( !acceleration ) {
+                return null;
+            }
+            return [ acceleration.x, acceleration.y, acceleration.z ];
+        ).try_into().unwrap();
+
+        values.map( |values| DeviceAcceleration {
+            x: values[ 0 ],
+            y: values[ 1 ],
+            z: values[ 2 ]
+        })
+    }
+
+    /// Returns the acceleration of the device, excluding the effect of gravity.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/acceleration)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-acceleration
+    #[inline]
+    pub fn acceleration( &self ) -> Option< DeviceAcceleration > {
+        self.get_acceleration( "acceleration" )
+    }
+
+    /// Returns the acceleration of the device, including the effect of gravity.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/accelerationIncludingGravity)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-accelerationincludinggravity
+    #[inline]
+    pub fn acceleration_including_gravity( &self ) -> Option< DeviceAcceleration > {
+        self.get_acceleration( "accelerationIncludingGravity" )
+    }
+
+    /// Returns the rate of rotation of the device.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/rotationRate)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-rotationrate
+    pub fn rotation_rate( &self ) -> Option< DeviceRotationRate > {
+        let values: Option< Vec< Option< f64 > > > = js!(
+            var rate = @{self.as_ref()}.rotationRate;
+            if( !rate ) {
+                return null;
+            }
+            return [ rate.alpha, rate.beta, rate.gamma ];
+        ).try_into().unwrap();
+
+        values.map( |values| DeviceRotationRate {
+            alpha: values[ 0 ],
+            beta: values[ 1 ],
+            gamma: values[ 2 ]
+        })
+    }
+
+    /// Returns the interval at which the data is obtained from the device, in milliseconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/interval)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-interval
+    #[inline]
+    pub fn interval( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.interval;
+        ).try_into().unwrap()
+    }
+
+    /// Asks the user for permission to receive motion events.
+    ///
+    /// This is required on iOS, and has to be called from within a user gesture handler.
+    /// On browsers which don't require a permission this always resolves to
+    /// `PermissionState::Granted`.
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-requestpermission
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn request_permission() -> PromiseFuture< PermissionState, Error > {
+        js!(
+            if( typeof DeviceMotionEvent.requestPermission === "function" ) {
+                return DeviceMotionEvent.requestPermission();
+            }
+            return Promise.resolve( "granted" );
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_device_orientation_event() {
+        let event: DeviceOrientationEvent = js!(
+            return new DeviceOrientationEvent( @{DeviceOrientationEvent::EVENT_TYPE}, { alpha: 10, beta: 20, absolute: true } );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), DeviceOrientationEvent::EVENT_TYPE );
+        assert_eq!( event.alpha(), Some( 10.0 ) );
+        assert_eq!( event.beta(), Some( 20.0 ) );
+        assert_eq!( event.gamma(), None );
+        assert!( event.absolute() );
+    }
+
+    #[test]
+    fn test_device_motion_event() {
+        let event: DeviceMotionEvent = js!(
+            return new DeviceMotionEvent( @{DeviceMotionEvent::EVENT_TYPE}, {
+                accelerationIncludingGravity: { x: 1, y: 2, z: 9.5 },
+                interval: 16
+            } );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), DeviceMotionEvent::EVENT_TYPE );
+        assert_eq!( event.acceleration_including_gravity(), Some( DeviceAcceleration {
+            x: Some( 1.0 ),
+            y: Some( 2.0 ),
+            z: Some( 9.5 )
+        }));
+        assert_eq!( event.interval(), 16.0 );
+    }
+}
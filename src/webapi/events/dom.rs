@@ -1,4 +1,5 @@
 use webcore::value::Reference;
+use webcore::try_from::TryInto;
 use webapi::event::{IEvent, IUiEvent, UiEvent, Event};
 
 /// The `ChangeEvent` is fired for input, select, and textarea
@@ -100,6 +101,19 @@ pub struct ScrollEvent( Reference );
 
 impl IEvent for ScrollEvent {}
 
+/// The scrollend event is fired when the document view or an element has
+/// completed scrolling.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollend_event)
+// https://drafts.csswg.org/cssom-view/#eventdef-document-scrollend
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "scrollend")]
+#[reference(subclass_of(Event))]
+pub struct ScrollEndEvent( Reference );
+
+impl IEvent for ScrollEndEvent {}
+
 /// The readystatechange event is fired when the readyState attribute of a document has changed.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/readystatechange)
@@ -137,6 +151,86 @@ pub struct SelectionChangeEvent( Reference );
 
 impl IEvent for SelectionChangeEvent {}
 
+/// The `BeforeUnloadEvent` is fired when the window, the document and its resources
+/// are about to be unloaded.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/beforeunload)
+// https://html.spec.whatwg.org/#beforeunloadevent
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "BeforeUnloadEvent")]
+#[reference(event = "beforeunload")]
+#[reference(subclass_of(Event))]
+pub struct BeforeUnloadEvent( Reference );
+
+impl IEvent for BeforeUnloadEvent {}
+
+impl BeforeUnloadEvent {
+    /// Returns the message which was set to be displayed in the confirmation dialog.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BeforeUnloadEvent)
+    // https://html.spec.whatwg.org/#dom-beforeunloadevent-returnvalue
+    #[inline]
+    pub fn return_value( &self ) -> String {
+        js!(
+            return @{self}.returnValue;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the return value of this event; setting it to a non-empty string
+    /// will make the browser ask the user whenever they really want to leave
+    /// the page.
+    ///
+    /// Most browsers will ignore the actual message and display a generic one instead.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BeforeUnloadEvent)
+    // https://html.spec.whatwg.org/#dom-beforeunloadevent-returnvalue
+    #[inline]
+    pub fn set_return_value( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.returnValue = @{value};
+        }
+    }
+}
+
+/// The `VisibilityChangeEvent` is fired at the document when the contents
+/// of its tab have become visible or have been hidden.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/visibilitychange)
+// https://w3c.github.io/page-visibility/#sec-visibilitychange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "visibilitychange")]
+#[reference(subclass_of(Event))]
+pub struct VisibilityChangeEvent( Reference );
+
+impl IEvent for VisibilityChangeEvent {}
+
+/// The `OnlineEvent` is fired on the window when the browser has gained
+/// access to the network.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/online)
+// https://html.spec.whatwg.org/#event-online
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "online")]
+#[reference(subclass_of(Event))]
+pub struct OnlineEvent( Reference );
+
+impl IEvent for OnlineEvent {}
+
+/// The `OfflineEvent` is fired on the window when the browser has lost
+/// access to the network.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/offline)
+// https://html.spec.whatwg.org/#event-offline
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "offline")]
+#[reference(subclass_of(Event))]
+pub struct OfflineEvent( Reference );
+
+impl IEvent for OfflineEvent {}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -183,6 +277,14 @@ mod tests {
         assert_eq!( event.event_type(), ScrollEvent::EVENT_TYPE );
     }
 
+    #[test]
+    fn test_scroll_end_event() {
+        let event: ScrollEndEvent = js!(
+            return new Event( @{ScrollEndEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), ScrollEndEvent::EVENT_TYPE );
+    }
+
     #[test]
     fn test_ready_state_change_event() {
         let event: ReadyStateChangeEvent = js!(
@@ -206,4 +308,40 @@ mod tests {
         ).try_into().unwrap();
         assert_eq!( event.event_type(), SelectionChangeEvent::EVENT_TYPE);
     }
+
+    #[test]
+    fn test_before_unload_event() {
+        let event: BeforeUnloadEvent = js!(
+            var event = document.createEvent( "BeforeUnloadEvent" );
+            event.initEvent( @{BeforeUnloadEvent::EVENT_TYPE}, false, true );
+            return event;
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), BeforeUnloadEvent::EVENT_TYPE );
+        event.set_return_value( "Are you sure?" );
+        assert_eq!( event.return_value(), "Are you sure?" );
+    }
+
+    #[test]
+    fn test_visibility_change_event() {
+        let event: VisibilityChangeEvent = js!(
+            return new Event( @{VisibilityChangeEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), VisibilityChangeEvent::EVENT_TYPE );
+    }
+
+    #[test]
+    fn test_online_event() {
+        let event: OnlineEvent = js!(
+            return new Event( @{OnlineEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), OnlineEvent::EVENT_TYPE );
+    }
+
+    #[test]
+    fn test_offline_event() {
+        let event: OfflineEvent = js!(
+            return new Event( @{OfflineEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), OfflineEvent::EVENT_TYPE );
+    }
 }
@@ -0,0 +1,174 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::promise::Promise;
+use webapi::event::{IEvent, Event};
+
+/// The `ErrorEvent` is fired on the `window` when a script throws
+/// an exception which isn't caught.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent)
+// https://html.spec.whatwg.org/#the-errorevent-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ErrorEvent")]
+#[reference(event = "error")]
+#[reference(subclass_of(Event))]
+pub struct ErrorEvent( Reference );
+
+impl IEvent for ErrorEvent {}
+
+impl ErrorEvent {
+    /// Returns a human-readable message describing the error.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/message)
+    // https://html.spec.whatwg.org/#dom-errorevent-message
+    #[inline]
+    pub fn message( &self ) -> String {
+        js!(
+            return @{self}.message;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the name of the script file in which the error occurred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/filename)
+    // https://html.spec.whatwg.org/#dom-errorevent-filename
+    #[inline]
+    pub fn filename( &self ) -> String {
+        js!(
+            return @{self}.filename;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the line number of the script file on which the error occurred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/lineno)
+    // https://html.spec.whatwg.org/#dom-errorevent-lineno
+    #[inline]
+    pub fn lineno( &self ) -> u32 {
+        js!(
+            return @{self}.lineno;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the column number of the script file on which the error occurred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/colno)
+    // https://html.spec.whatwg.org/#dom-errorevent-colno
+    #[inline]
+    pub fn colno( &self ) -> u32 {
+        js!(
+            return @{self}.colno;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the value which was thrown, usually an `Error` object.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/error)
+    // https://html.spec.whatwg.org/#dom-errorevent-error
+    #[inline]
+    pub fn error( &self ) -> Value {
+        js!(
+            return @{self}.error;
+        )
+    }
+}
+
+/// The `IPromiseRejectionEvent` interface represents events which are fired
+/// when a JavaScript promise is rejected.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent)
+// https://html.spec.whatwg.org/#promiserejectionevent
+pub trait IPromiseRejectionEvent: IEvent {
+    /// Returns the promise which was rejected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent/promise)
+    // https://html.spec.whatwg.org/#dom-promiserejectionevent-promise
+    #[inline]
+    fn promise( &self ) -> Promise {
+        js!(
+            return @{self.as_ref()}.promise;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the value with which the promise was rejected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent/reason)
+    // https://html.spec.whatwg.org/#dom-promiserejectionevent-reason
+    #[inline]
+    fn reason( &self ) -> Value {
+        js!(
+            return @{self.as_ref()}.reason;
+        )
+    }
+}
+
+/// The `UnhandledRejectionEvent` is fired on the `window` when a promise
+/// is rejected and has no rejection handler.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/unhandledrejection)
+// https://html.spec.whatwg.org/#event-unhandledrejection
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PromiseRejectionEvent")]
+#[reference(event = "unhandledrejection")]
+#[reference(subclass_of(Event))]
+pub struct UnhandledRejectionEvent( Reference );
+
+impl IEvent for UnhandledRejectionEvent {}
+impl IPromiseRejectionEvent for UnhandledRejectionEvent {}
+
+/// The `RejectionHandledEvent` is fired on the `window` when a rejection handler
+/// is attached to a promise after an [UnhandledRejectionEvent](struct.UnhandledRejectionEvent.html)
+/// was already fired for it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/rejectionhandled)
+// https://html.spec.whatwg.org/#event-rejectionhandled
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PromiseRejectionEvent")]
+#[reference(event = "rejectionhandled")]
+#[reference(subclass_of(Event))]
+pub struct RejectionHandledEvent( Reference );
+
+impl IEvent for RejectionHandledEvent {}
+impl IPromiseRejectionEvent for RejectionHandledEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_error_event() {
+        let event: ErrorEvent = js!(
+            return new ErrorEvent( @{ErrorEvent::EVENT_TYPE}, {
+                message: "Oops",
+                filename: "main.js",
+                lineno: 12,
+                colno: 34,
+                error: 56
+            });
+        ).try_into().unwrap();
+
+        assert_eq!( event.event_type(), ErrorEvent::EVENT_TYPE );
+        assert_eq!( event.message(), "Oops" );
+        assert_eq!( event.filename(), "main.js" );
+        assert_eq!( event.lineno(), 12 );
+        assert_eq!( event.colno(), 34 );
+        assert_eq!( event.error(), 56 );
+    }
+
+    #[test]
+    fn test_unhandled_rejection_event() {
+        let event: UnhandledRejectionEvent = js!(
+            var promise = Promise.reject( "Oops" );
+            promise.catch( function() {} );
+            return new PromiseRejectionEvent( @{UnhandledRejectionEvent::EVENT_TYPE}, {
+                promise: promise,
+                reason: "Oops"
+            });
+        ).try_into().unwrap();
+
+        assert_eq!( event.event_type(), UnhandledRejectionEvent::EVENT_TYPE );
+        assert_eq!( event.reason(), "Oops" );
+        let _: Promise = event.promise();
+    }
+}
@@ -1,5 +1,9 @@
+pub mod battery;
+pub mod clipboard;
+pub mod device;
 pub mod dom;
 pub mod drag;
+pub mod error;
 pub mod focus;
 pub mod gamepad;
 pub mod history;
@@ -7,4 +11,6 @@ pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
 pub mod progress;
+pub mod rtc;
 pub mod socket;
+pub mod transition;
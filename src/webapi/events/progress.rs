@@ -68,6 +68,23 @@ pub struct ProgressEvent( Reference );
 impl IEvent for ProgressEvent {}
 impl IProgressEvent for ProgressEvent {}
 
+impl ProgressEvent {
+    /// Creates a new `progress` event, which can then be dispatched with
+    /// [`dispatch_event`](trait.IEventTarget.html#method.dispatch_event).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ProgressEvent/ProgressEvent)
+    // https://xhr.spec.whatwg.org/#dom-progressevent-progressevent
+    pub fn new( length_computable: bool, loaded: u64, total: u64 ) -> Self {
+        js!(
+            return new ProgressEvent( "progress", {
+                lengthComputable: @{length_computable},
+                loaded: @{loaded as f64},
+                total: @{total as f64}
+            } );
+        ).try_into().unwrap()
+    }
+}
+
 /// The `ProgressLoadEvent` is fired when progress has successful finished.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/load_(ProgressEvent))
@@ -158,6 +175,27 @@ mod tests {
         assert_eq!( event.total(), 100 );
     }
 
+    #[test]
+    fn test_new_progress_event() {
+        use std::rc::Rc;
+        use std::cell::Cell;
+        use webapi::document::document;
+        use webapi::event_target::IEventTarget;
+
+        let element = document().create_element( "div" ).unwrap();
+        let loaded = Rc::new( Cell::new( 0 ) );
+        element.add_event_listener( {
+            let loaded = loaded.clone();
+            move |event: ProgressEvent| loaded.set( event.loaded() )
+        });
+
+        let event = ProgressEvent::new( true, 25, 50 );
+        assert!( event.length_computable() );
+        assert_eq!( event.total(), 50 );
+        element.dispatch_event( &event ).unwrap();
+        assert_eq!( loaded.get(), 25 );
+    }
+
     #[test]
     fn test_load_start_event() {
         let event: LoadStartEvent = js!(
@@ -0,0 +1,76 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event};
+use webapi::rtc::{RtcIceCandidate, RtcDataChannel};
+
+/// The `RtcIceCandidateEvent` is fired on an [RtcPeerConnection](../struct.RtcPeerConnection.html)
+/// whenever a new local ICE candidate has been gathered. The candidate should be
+/// sent to the remote peer, which passes it to
+/// [add_ice_candidate](../struct.RtcPeerConnection.html#method.add_ice_candidate).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/icecandidate_event)
+// https://w3c.github.io/webrtc-pc/#event-icecandidate
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RTCPeerConnectionIceEvent")]
+#[reference(event = "icecandidate")]
+#[reference(subclass_of(Event))]
+pub struct RtcIceCandidateEvent( Reference );
+
+impl IEvent for RtcIceCandidateEvent {}
+
+impl RtcIceCandidateEvent {
+    /// Returns the gathered candidate, or `None` if candidate gathering has finished.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnectionIceEvent/candidate)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcpeerconnectioniceevent-candidate
+    #[inline]
+    pub fn candidate( &self ) -> Option< RtcIceCandidate > {
+        js!(
+            return @{self.as_ref()}.candidate;
+        ).try_into().unwrap()
+    }
+}
+
+/// The `RtcDataChannelEvent` is fired on an [RtcPeerConnection](../struct.RtcPeerConnection.html)
+/// when the remote peer has created a data channel.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/datachannel_event)
+// https://w3c.github.io/webrtc-pc/#event-datachannel
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RTCDataChannelEvent")]
+#[reference(event = "datachannel")]
+#[reference(subclass_of(Event))]
+pub struct RtcDataChannelEvent( Reference );
+
+impl IEvent for RtcDataChannelEvent {}
+
+impl RtcDataChannelEvent {
+    /// Returns the data channel created by the remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannelEvent/channel)
+    // https://w3c.github.io/webrtc-pc/#dom-datachannelevent-channel
+    #[inline]
+    pub fn channel( &self ) -> RtcDataChannel {
+        js!(
+            var channel = @{self.as_ref()}.channel;
+            // Keep binary messages consistent with channels created locally.
+            channel.binaryType = "arraybuffer";
+            return channel;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_ice_candidate_event() {
+        let event: RtcIceCandidateEvent = js!(
+            return new RTCPeerConnectionIceEvent( @{RtcIceCandidateEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), RtcIceCandidateEvent::EVENT_TYPE );
+        assert!( event.candidate().is_none() );
+    }
+}
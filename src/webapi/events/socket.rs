@@ -6,6 +6,8 @@ use webapi::blob::Blob;
 use webapi::array_buffer::ArrayBuffer;
 use webapi::web_socket::SocketCloseCode;
 use webapi::event::{IEvent, Event};
+use webapi::window_proxy::WindowProxy;
+use webapi::message_port::MessagePort;
 
 /// A SocketCloseEvent is sent to clients using WebSockets when the connection is closed.
 ///
@@ -184,6 +186,20 @@ pub trait IMessageEvent: IEvent where <Self::Data as TryFrom<Value>>::Error: Deb
         ).try_into().ok()
     }
 
+    /// The window which sent the message, if it was sent with `postMessage` on a window.
+    ///
+    /// Together with [origin](#method.origin) this can be used to check who a
+    /// message came from.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageEvent/source)
+    // https://html.spec.whatwg.org/multipage/comms.html#the-messageevent-interface:dom-messageevent-source
+    #[inline]
+    fn source_window( &self ) -> Option<WindowProxy> {
+        js!(
+            return @{self.as_ref()}.source;
+        ).try_into().ok()
+    }
+
     /// An array of MessagePort objects representing the ports associated with the channel the
     /// message is being sent through (where appropriate, e.g. in channel messaging or when sending
     /// a message to a shared worker).
@@ -191,9 +207,9 @@ pub trait IMessageEvent: IEvent where <Self::Data as TryFrom<Value>>::Error: Deb
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageEvent/ports)
     // https://html.spec.whatwg.org/multipage/comms.html#the-messageevent-interface:dom-messageevent-ports
     #[inline]
-    fn ports( &self ) -> Vec<Reference> {
+    fn ports( &self ) -> Vec<MessagePort> {
         js!(
-            return @{self.as_ref()}.ports;
+            return Array.prototype.slice.call( @{self.as_ref()}.ports );
         ).try_into().unwrap()
     }
 }
@@ -215,6 +231,26 @@ impl IMessageEvent for SocketMessageEvent {
 
 impl IEvent for SocketMessageEvent {}
 
+/// A message event which is delivered by `postMessage` to windows, workers
+/// and message ports, as well as by `BroadcastChannel` and `EventSource`.
+///
+/// Always check the [origin](trait.IMessageEvent.html#method.origin) of messages
+/// which were sent to a window before trusting their data.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageEvent)
+// https://html.spec.whatwg.org/#messageevent
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessageEvent")]
+#[reference(event = "message")]
+#[reference(subclass_of(Event))]
+pub struct MessageEvent( Reference );
+
+impl IMessageEvent for MessageEvent {
+    type Data = Value;
+}
+
+impl IEvent for MessageEvent {}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -237,4 +273,25 @@ mod tests {
         assert_eq!( event.reason(), "WebSocket was closed normally" );
         assert!( event.was_clean() );
     }
+
+    #[test]
+    fn test_message_event() {
+        let event: MessageEvent = js!(
+            return new MessageEvent(
+                @{MessageEvent::EVENT_TYPE},
+                {
+                    data: "Hello",
+                    origin: "https://example.com",
+                    lastEventId: "42",
+                    source: window
+                }
+            );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), MessageEvent::EVENT_TYPE );
+        assert_eq!( event.data(), Value::String( "Hello".to_owned() ) );
+        assert_eq!( event.origin(), "https://example.com" );
+        assert_eq!( event.last_event_id(), "42" );
+        assert!( event.source_window().is_some() );
+        assert!( event.ports().is_empty() );
+    }
 }
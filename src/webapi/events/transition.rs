@@ -0,0 +1,254 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event};
+
+/// The `ITransitionEvent` interface represents events providing information
+/// related to CSS transitions.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransitionEvent)
+// https://drafts.csswg.org/css-transitions/#interface-transitionevent
+pub trait ITransitionEvent: IEvent {
+    /// Returns the name of the CSS property associated with the transition.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransitionEvent/propertyName)
+    // https://drafts.csswg.org/css-transitions/#dom-transitionevent-propertyname
+    #[inline]
+    fn property_name( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.propertyName;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of seconds the transition had been running when
+    /// the event fired, not counting its delay.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransitionEvent/elapsedTime)
+    // https://drafts.csswg.org/css-transitions/#dom-transitionevent-elapsedtime
+    #[inline]
+    fn elapsed_time( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.elapsedTime;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the name of the pseudo-element the transition runs on, starting
+    /// with `::`, or an empty string if it runs on the element itself.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransitionEvent/pseudoElement)
+    // https://drafts.csswg.org/css-transitions/#dom-transitionevent-pseudoelement
+    #[inline]
+    fn pseudo_element( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.pseudoElement;
+        ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the [ITransitionEvent](trait.ITransitionEvent.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransitionEvent)
+// https://drafts.csswg.org/css-transitions/#interface-transitionevent
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TransitionEvent")]
+#[reference(subclass_of(Event))]
+pub struct TransitionEvent( Reference );
+
+impl IEvent for TransitionEvent {}
+impl ITransitionEvent for TransitionEvent {}
+
+/// The `TransitionRunEvent` is fired when a CSS transition is created, before its delay.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/transitionrun)
+// https://drafts.csswg.org/css-transitions/#transitionrun
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TransitionEvent")]
+#[reference(event = "transitionrun")]
+#[reference(subclass_of(Event, TransitionEvent))]
+pub struct TransitionRunEvent( Reference );
+
+impl IEvent for TransitionRunEvent {}
+impl ITransitionEvent for TransitionRunEvent {}
+
+/// The `TransitionStartEvent` is fired when a CSS transition has actually started,
+/// after its delay.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/transitionstart)
+// https://drafts.csswg.org/css-transitions/#transitionstart
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TransitionEvent")]
+#[reference(event = "transitionstart")]
+#[reference(subclass_of(Event, TransitionEvent))]
+pub struct TransitionStartEvent( Reference );
+
+impl IEvent for TransitionStartEvent {}
+impl ITransitionEvent for TransitionStartEvent {}
+
+/// The `TransitionEndEvent` is fired when a CSS transition has completed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/transitionend)
+// https://drafts.csswg.org/css-transitions/#transitionend
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TransitionEvent")]
+#[reference(event = "transitionend")]
+#[reference(subclass_of(Event, TransitionEvent))]
+pub struct TransitionEndEvent( Reference );
+
+impl IEvent for TransitionEndEvent {}
+impl ITransitionEvent for TransitionEndEvent {}
+
+/// The `TransitionCancelEvent` is fired when a CSS transition is cancelled,
+/// for example because the transitioned property was changed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/transitioncancel)
+// https://drafts.csswg.org/css-transitions/#transitioncancel
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TransitionEvent")]
+#[reference(event = "transitioncancel")]
+#[reference(subclass_of(Event, TransitionEvent))]
+pub struct TransitionCancelEvent( Reference );
+
+impl IEvent for TransitionCancelEvent {}
+impl ITransitionEvent for TransitionCancelEvent {}
+
+/// The `IAnimationEvent` interface represents events providing information
+/// related to CSS animations.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AnimationEvent)
+// https://drafts.csswg.org/css-animations/#interface-animationevent
+pub trait IAnimationEvent: IEvent {
+    /// Returns the value of the `animation-name` CSS property associated with the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AnimationEvent/animationName)
+    // https://drafts.csswg.org/css-animations/#dom-animationevent-animationname
+    #[inline]
+    fn animation_name( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.animationName;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of seconds the animation had been running when
+    /// the event fired, not counting the time it was paused.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AnimationEvent/elapsedTime)
+    // https://drafts.csswg.org/css-animations/#dom-animationevent-elapsedtime
+    #[inline]
+    fn elapsed_time( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.elapsedTime;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the name of the pseudo-element the animation runs on, starting
+    /// with `::`, or an empty string if it runs on the element itself.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AnimationEvent/pseudoElement)
+    // https://drafts.csswg.org/css-animations/#dom-animationevent-pseudoelement
+    #[inline]
+    fn pseudo_element( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.pseudoElement;
+        ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IAnimationEvent](trait.IAnimationEvent.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AnimationEvent)
+// https://drafts.csswg.org/css-animations/#interface-animationevent
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AnimationEvent")]
+#[reference(subclass_of(Event))]
+pub struct AnimationEvent( Reference );
+
+impl IEvent for AnimationEvent {}
+impl IAnimationEvent for AnimationEvent {}
+
+/// The `AnimationStartEvent` is fired when a CSS animation has started.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/animationstart)
+// https://drafts.csswg.org/css-animations/#eventdef-globaleventhandlers-animationstart
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AnimationEvent")]
+#[reference(event = "animationstart")]
+#[reference(subclass_of(Event, AnimationEvent))]
+pub struct AnimationStartEvent( Reference );
+
+impl IEvent for AnimationStartEvent {}
+impl IAnimationEvent for AnimationStartEvent {}
+
+/// The `AnimationEndEvent` is fired when a CSS animation has completed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/animationend)
+// https://drafts.csswg.org/css-animations/#eventdef-globaleventhandlers-animationend
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AnimationEvent")]
+#[reference(event = "animationend")]
+#[reference(subclass_of(Event, AnimationEvent))]
+pub struct AnimationEndEvent( Reference );
+
+impl IEvent for AnimationEndEvent {}
+impl IAnimationEvent for AnimationEndEvent {}
+
+/// The `AnimationIterationEvent` is fired when an iteration of a CSS animation
+/// ends and the next one begins.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/animationiteration)
+// https://drafts.csswg.org/css-animations/#eventdef-globaleventhandlers-animationiteration
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AnimationEvent")]
+#[reference(event = "animationiteration")]
+#[reference(subclass_of(Event, AnimationEvent))]
+pub struct AnimationIterationEvent( Reference );
+
+impl IEvent for AnimationIterationEvent {}
+impl IAnimationEvent for AnimationIterationEvent {}
+
+/// The `AnimationCancelEvent` is fired when a CSS animation unexpectedly aborts,
+/// for example because its `animation-name` was changed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/animationcancel)
+// https://drafts.csswg.org/css-animations/#eventdef-globaleventhandlers-animationcancel
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AnimationEvent")]
+#[reference(event = "animationcancel")]
+#[reference(subclass_of(Event, AnimationEvent))]
+pub struct AnimationCancelEvent( Reference );
+
+impl IEvent for AnimationCancelEvent {}
+impl IAnimationEvent for AnimationCancelEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_transition_end_event() {
+        let event: TransitionEndEvent = js!(
+            return new TransitionEvent(
+                @{TransitionEndEvent::EVENT_TYPE},
+                { propertyName: "opacity", elapsedTime: 0.5 }
+            );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), TransitionEndEvent::EVENT_TYPE );
+        assert_eq!( event.property_name(), "opacity" );
+        assert_eq!( event.elapsed_time(), 0.5 );
+        assert_eq!( event.pseudo_element(), "" );
+    }
+
+    #[test]
+    fn test_animation_iteration_event() {
+        let event: AnimationIterationEvent = js!(
+            return new AnimationEvent(
+                @{AnimationIterationEvent::EVENT_TYPE},
+                { animationName: "spin", elapsedTime: 2.0 }
+            );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), AnimationIterationEvent::EVENT_TYPE );
+        assert_eq!( event.animation_name(), "spin" );
+        assert_eq!( event.elapsed_time(), 2.0 );
+    }
+}
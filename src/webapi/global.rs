@@ -1,5 +1,10 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
 use webapi::window::window;
 use webapi::window_or_worker::IWindowOrWorker;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::blob::Blob;
+use webapi::url::Url;
 
 /// An alias for [window.set_timeout](struct.Window.html#method.set_timeout).
 pub fn set_timeout< F: FnOnce() + 'static >( callback: F, timeout: u32 ) {
@@ -10,3 +15,73 @@ pub fn set_timeout< F: FnOnce() + 'static >( callback: F, timeout: u32 ) {
 pub fn alert( message: &str ) {
     window().alert( message );
 }
+
+/// Returns `true` if the given JavaScript `object` has a property with the given `name`,
+/// either directly or through its prototype chain.
+///
+/// This is useful to detect whether an API is available before using it, for example
+/// `has_property( &window(), "IntersectionObserver" )`.
+pub fn has_property< T: AsRef< Reference > >( object: &T, name: &str ) -> bool {
+    js!(
+        return @{name} in @{object.as_ref()};
+    ).try_into().unwrap()
+}
+
+/// Makes the browser download `data` as a file named `filename`, with the given `mime` type.
+///
+/// This creates a temporary `<a download>` element pointing to an object URL of the
+/// data and clicks it. The object URL is revoked 40 seconds afterwards.
+pub fn download_bytes( filename: &str, mime: &str, data: &[u8] ) {
+    let blob: Blob = js!(
+        return new Blob( [ @{UnsafeTypedArray( data )} ], { type: @{mime} } );
+    ).try_into().unwrap();
+
+    let url = Url::create_object_url( &blob );
+    js! { @(no_return)
+        var anchor = document.createElement( "a" );
+        anchor.href = @{&*url};
+        anchor.download = @{filename};
+        anchor.style.display = "none";
+        var parent = document.body || document.documentElement;
+        parent.appendChild( anchor );
+        anchor.click();
+        parent.removeChild( anchor );
+    }
+
+    // Browsers only start fetching the URL after the click event has been
+    // handled, and a slow download can take a while to get going, so this
+    // uses the same generous delay as FileSaver.js.
+    set_timeout( move || url.revoke(), 40_000 );
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_bytes() {
+        let clicked = js!(
+            var clicked = [];
+            var listener = function( event ) {
+                if( event.target.download !== undefined ) {
+                    clicked.push( [ event.target.download, event.target.href ] );
+                    event.preventDefault();
+                }
+            };
+            document.addEventListener( "click", listener );
+            clicked.remove = function() {
+                document.removeEventListener( "click", listener );
+            };
+            return clicked;
+        );
+
+        download_bytes( "hello.txt", "text/plain", b"Hello!" );
+        js! { @(no_return) @{&clicked}.remove(); }
+
+        let filename: String = js!( return @{&clicked}[ 0 ][ 0 ]; ).try_into().unwrap();
+        let href: String = js!( return @{&clicked}[ 0 ][ 1 ]; ).try_into().unwrap();
+        assert_eq!( filename, "hello.txt" );
+        assert!( href.starts_with( "blob:" ) );
+        assert_eq!( js!( return document.querySelectorAll( "a[download]" ).length; ), 0 );
+    }
+}
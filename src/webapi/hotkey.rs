@@ -0,0 +1,420 @@
+use std::fmt;
+use std::rc::{Rc, Weak};
+use std::cell::{Cell, RefCell};
+use std::str::FromStr;
+
+use webapi::event::IEvent;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::keyboard::{IKeyboardEvent, KeyDownEvent};
+use webapi::navigator::navigator;
+
+/// Indicates that a key combination passed to [Hotkey::parse](struct.Hotkey.html#method.parse)
+/// or [HotkeyRegistry::register](struct.HotkeyRegistry.html#method.register) couldn't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HotkeyParseError( String );
+
+impl fmt::Display for HotkeyParseError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "Invalid key combination '{}'", self.0 )
+    }
+}
+
+impl ::std::error::Error for HotkeyParseError {
+    fn description( &self ) -> &str {
+        "Invalid key combination"
+    }
+}
+
+fn is_mac() -> bool {
+    let platform = navigator().platform();
+    platform.starts_with( "Mac" ) || platform.starts_with( "iP" )
+}
+
+fn normalize_key( key: &str ) -> String {
+    let key = key.to_lowercase();
+    let key = match key.as_str() {
+        "esc" => "escape",
+        "space" | "spacebar" => " ",
+        "return" => "enter",
+        "del" => "delete",
+        "ins" => "insert",
+        "plus" => "+",
+        "up" => "arrowup",
+        "down" => "arrowdown",
+        "left" => "arrowleft",
+        "right" => "arrowright",
+        key => key
+    };
+
+    key.to_owned()
+}
+
+/// A key combination, such as `Ctrl+Shift+P`.
+///
+/// The modifiers can be given in any order and the names are case insensitive.
+/// `Mod` (or `CmdOrCtrl`) stands for the platform's primary modifier, which is
+/// `Meta` (the command key) on Apple platforms and `Ctrl` everywhere else.
+///
+/// The key is matched against the [key](trait.IKeyboardEvent.html#method.key) of the
+/// event. Letters and digits are also matched against the physical key, so that
+/// `Shift+1` or `Alt+P` still match when the modifier changes the character typed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    key: String
+}
+
+impl Hotkey {
+    /// Parses a key combination like `"Ctrl+Shift+P"`, `"Mod+S"` or `"Escape"`.
+    pub fn parse( combination: &str ) -> Result< Self, HotkeyParseError > {
+        let error = || HotkeyParseError( combination.to_owned() );
+
+        // A trailing `++` means that the key itself is `+`.
+        let (modifiers, key) = if combination.ends_with( "++" ) {
+            (&combination[ ..combination.len() - 2 ], "+")
+        } else if combination == "+" {
+            ("", "+")
+        } else {
+            match combination.rfind( '+' ) {
+                Some( index ) => (&combination[ ..index ], &combination[ index + 1.. ]),
+                None => ("", combination)
+            }
+        };
+
+        let key = key.trim();
+        if key.is_empty() {
+            return Err( error() );
+        }
+
+        let mut hotkey = Hotkey {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: normalize_key( key )
+        };
+
+        if modifiers.is_empty() {
+            return Ok( hotkey );
+        }
+
+        for modifier in modifiers.split( '+' ) {
+            let flag = match modifier.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => &mut hotkey.ctrl,
+                "alt" | "option" => &mut hotkey.alt,
+                "shift" => &mut hotkey.shift,
+                "meta" | "cmd" | "command" | "super" | "win" => &mut hotkey.meta,
+                "mod" | "cmdorctrl" => if is_mac() { &mut hotkey.meta } else { &mut hotkey.ctrl },
+                _ => return Err( error() )
+            };
+
+            if *flag {
+                return Err( error() );
+            }
+
+            *flag = true;
+        }
+
+        Ok( hotkey )
+    }
+
+    /// Returns whether the given keyboard event is this key combination being pressed.
+    pub fn matches< T: IKeyboardEvent >( &self, event: &T ) -> bool {
+        if event.ctrl_key() != self.ctrl ||
+           event.alt_key() != self.alt ||
+           event.shift_key() != self.shift ||
+           event.meta_key() != self.meta {
+            return false;
+        }
+
+        if normalize_key( &event.key() ) == self.key {
+            return true;
+        }
+
+        let mut chars = self.key.chars();
+        match (chars.next(), chars.next()) {
+            (Some( c ), None) if c.is_ascii_lowercase() => event.code() == format!( "Key{}", c.to_ascii_uppercase() ),
+            (Some( c ), None) if c.is_ascii_digit() => event.code() == format!( "Digit{}", c ),
+            _ => false
+        }
+    }
+}
+
+impl FromStr for Hotkey {
+    type Err = HotkeyParseError;
+
+    #[inline]
+    fn from_str( combination: &str ) -> Result< Self, Self::Err > {
+        Hotkey::parse( combination )
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        let modifiers = [
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.meta, "Meta")
+        ];
+
+        for &(enabled, name) in &modifiers {
+            if enabled {
+                write!( formatter, "{}+", name )?;
+            }
+        }
+
+        let mut chars = self.key.chars();
+        match chars.next() {
+            Some( ' ' ) if self.key.len() == 1 => write!( formatter, "Space" ),
+            Some( first ) => write!( formatter, "{}{}", first.to_uppercase(), chars.as_str() ),
+            None => Ok(())
+        }
+    }
+}
+
+struct Entry {
+    id: u64,
+    hotkey: Hotkey,
+    callback: Rc< RefCell< dyn FnMut( KeyDownEvent ) > >
+}
+
+struct RegistryState {
+    entries: RefCell< Vec< Entry > >,
+    next_id: Cell< u64 >
+}
+
+impl RegistryState {
+    fn on_key_down( &self, event: KeyDownEvent ) {
+        if event.is_composing() {
+            return;
+        }
+
+        // The callback is called after the borrow ends, since it
+        // might register or unregister other hotkeys.
+        let callback = self.entries.borrow().iter().rev()
+            .find( |entry| entry.hotkey.matches( &event ) )
+            .map( |entry| entry.callback.clone() );
+
+        if let Some( callback ) = callback {
+            event.prevent_default();
+
+            // If the callback itself dispatched this event, it's still running,
+            // and calling it again would alias its mutable state.
+            if let Ok( mut callback ) = callback.try_borrow_mut() {
+                ( &mut *callback )( event );
+            }
+        }
+    }
+}
+
+/// Dispatches [KeyDownEvent](struct.KeyDownEvent.html)s to the callbacks
+/// registered for their [Hotkey](struct.Hotkey.html).
+///
+/// When a key combination matches several hotkeys, only the most recently
+/// registered callback is called. The default action of matched events is prevented.
+/// Events which a callback dispatches to the registry's target while it's running
+/// aren't delivered to that same callback.
+///
+/// The registry stops listening for events when it's dropped.
+pub struct HotkeyRegistry {
+    state: Rc< RegistryState >,
+    listener: Option< EventListenerHandle >
+}
+
+impl fmt::Debug for HotkeyRegistry {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        let hotkeys: Vec< String > = self.state.entries.borrow().iter()
+            .map( |entry| entry.hotkey.to_string() )
+            .collect();
+
+        formatter.debug_struct( "HotkeyRegistry" )
+            .field( "hotkeys", &hotkeys )
+            .finish()
+    }
+}
+
+impl HotkeyRegistry {
+    /// Creates a new registry listening for key presses on `target`,
+    /// for example the [window](fn.window.html) for global shortcuts.
+    pub fn new< T: IEventTarget >( target: &T ) -> Self {
+        let state = Rc::new( RegistryState {
+            entries: RefCell::new( Vec::new() ),
+            next_id: Cell::new( 0 )
+        });
+
+        let weak = Rc::downgrade( &state );
+        let listener = target.add_event_listener( move |event: KeyDownEvent| {
+            if let Some( state ) = weak.upgrade() {
+                state.on_key_down( event );
+            }
+        });
+
+        HotkeyRegistry {
+            state,
+            listener: Some( listener )
+        }
+    }
+
+    /// Registers `callback` to be called when the key combination is pressed.
+    ///
+    /// The combination is parsed with [Hotkey::parse](struct.Hotkey.html#method.parse).
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn register< F >( &self, combination: &str, callback: F ) -> Result< HotkeyHandle, HotkeyParseError >
+        where F: FnMut( KeyDownEvent ) + 'static
+    {
+        let hotkey = Hotkey::parse( combination )?;
+        Ok( self.register_hotkey( hotkey, callback ) )
+    }
+
+    /// Registers `callback` to be called when the given `hotkey` is pressed.
+    ///
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn register_hotkey< F >( &self, hotkey: Hotkey, callback: F ) -> HotkeyHandle
+        where F: FnMut( KeyDownEvent ) + 'static
+    {
+        let id = self.state.next_id.get();
+        self.state.next_id.set( id + 1 );
+        self.state.entries.borrow_mut().push( Entry {
+            id,
+            hotkey,
+            callback: Rc::new( RefCell::new( callback ) )
+        });
+
+        HotkeyHandle {
+            state: Rc::downgrade( &self.state ),
+            id
+        }
+    }
+}
+
+impl Drop for HotkeyRegistry {
+    fn drop( &mut self ) {
+        if let Some( listener ) = self.listener.take() {
+            listener.remove();
+        }
+    }
+}
+
+/// A handle to a callback registered in a [HotkeyRegistry](struct.HotkeyRegistry.html).
+///
+/// The callback is unregistered when the handle is dropped.
+pub struct HotkeyHandle {
+    state: Weak< RegistryState >,
+    id: u64
+}
+
+impl fmt::Debug for HotkeyHandle {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "HotkeyHandle" )
+    }
+}
+
+impl Drop for HotkeyHandle {
+    fn drop( &mut self ) {
+        if let Some( state ) = self.state.upgrade() {
+            let id = self.id;
+            state.entries.borrow_mut().retain( |entry| entry.id != id );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+    use webapi::event_target::EventTarget;
+
+    fn key_down( key: &str, code: &str, ctrl: bool, shift: bool ) -> KeyDownEvent {
+        js!(
+            return new KeyboardEvent( "keydown", {
+                key: @{key},
+                code: @{code},
+                ctrlKey: @{ctrl},
+                shiftKey: @{shift},
+                cancelable: true
+            });
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!( Hotkey::parse( "Ctrl+Shift+P" ).unwrap(), Hotkey::parse( "shift+ctrl+p" ).unwrap() );
+        assert_eq!( Hotkey::parse( "control + P" ).unwrap().to_string(), "Ctrl+P" );
+        assert_eq!( Hotkey::parse( "Esc" ).unwrap().to_string(), "Escape" );
+        assert_eq!( Hotkey::parse( "Alt+Space" ).unwrap().to_string(), "Alt+Space" );
+        assert_eq!( Hotkey::parse( "Ctrl++" ).unwrap().to_string(), "Ctrl++" );
+        assert_eq!( Hotkey::parse( "Cmd+K" ).unwrap(), Hotkey::parse( "Meta+k" ).unwrap() );
+
+        let primary = if is_mac() { "Meta+S" } else { "Ctrl+S" };
+        assert_eq!( Hotkey::parse( "Mod+S" ).unwrap().to_string(), primary );
+
+        assert!( Hotkey::parse( "" ).is_err() );
+        assert!( Hotkey::parse( "Ctrl+" ).is_err() );
+        assert!( Hotkey::parse( "Ctrl+Ctrl+P" ).is_err() );
+        assert!( Hotkey::parse( "Hyper+P" ).is_err() );
+    }
+
+    #[test]
+    fn test_matches() {
+        let hotkey = Hotkey::parse( "Ctrl+Shift+P" ).unwrap();
+        assert!( hotkey.matches( &key_down( "P", "KeyP", true, true ) ) );
+        assert!( !hotkey.matches( &key_down( "P", "KeyP", true, false ) ) );
+        assert!( !hotkey.matches( &key_down( "O", "KeyO", true, true ) ) );
+
+        let hotkey = Hotkey::parse( "Shift+1" ).unwrap();
+        assert!( hotkey.matches( &key_down( "!", "Digit1", false, true ) ) );
+    }
+
+    #[test]
+    fn test_registry() {
+        let target = EventTarget::new();
+        let registry = HotkeyRegistry::new( &target );
+        let count = Rc::new( Cell::new( 0 ) );
+
+        let handle = registry.register( "Ctrl+Shift+P", {
+            let count = count.clone();
+            move |_| count.set( count.get() + 1 )
+        }).unwrap();
+
+        let event = key_down( "P", "KeyP", true, true );
+        target.dispatch_event( &event ).unwrap();
+        assert_eq!( count.get(), 1 );
+        assert!( event.default_prevented() );
+
+        let event = key_down( "P", "KeyP", true, false );
+        target.dispatch_event( &event ).unwrap();
+        assert_eq!( count.get(), 1 );
+        assert!( !event.default_prevented() );
+
+        drop( handle );
+        target.dispatch_event( &key_down( "P", "KeyP", true, true ) ).unwrap();
+        assert_eq!( count.get(), 1 );
+
+        assert!( registry.register( "Ctrl+Nope+P", |_| {} ).is_err() );
+    }
+
+    #[test]
+    fn test_reentrant_dispatch() {
+        let target = EventTarget::new();
+        let registry = HotkeyRegistry::new( &target );
+        let count = Rc::new( Cell::new( 0 ) );
+
+        let _handle = registry.register( "Ctrl+Shift+P", {
+            let target = target.clone();
+            let count = count.clone();
+            move |_| {
+                count.set( count.get() + 1 );
+                let event = key_down( "P", "KeyP", true, true );
+                target.dispatch_event( &event ).unwrap();
+                assert!( event.default_prevented() );
+            }
+        }).unwrap();
+
+        target.dispatch_event( &key_down( "P", "KeyP", true, true ) ).unwrap();
+        assert_eq!( count.get(), 1 );
+    }
+}
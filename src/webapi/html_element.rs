@@ -1,92 +1,133 @@
-use webcore::value::Reference;
-use webcore::try_from::TryInto;
+use webcore::value::{Reference, Value, ConversionError};
+use webcore::try_from::{TryFrom, TryInto};
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
 use webapi::element::{IElement, Element};
 use webapi::string_map::StringMap;
+use webapi::dom_exception::SyntaxError;
 
-/// Represents a rectangle.
+/// Represents a rectangle, as returned by APIs such as
+/// [get_bounding_client_rect](trait.IHtmlElement.html#method.get_bounding_client_rect).
+///
+/// The values are read eagerly from the underlying `DOMRect`, so a `Rect`
+/// is a plain value which doesn't change when the layout changes.
+///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRect)
 // https://drafts.fxtf.org/geometry-1/#domrect
-#[derive(Clone, Debug, ReferenceType)]
-#[reference(instance_of = "DOMRect")]
-pub struct Rect (Reference);
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64
+}
 
 impl Rect {
+    /// Creates a new rectangle with the given origin and size.
+    #[inline]
+    pub fn new( x: f64, y: f64, width: f64, height: f64 ) -> Self {
+        Rect { x, y, width, height }
+    }
 
     /// Represents the x coordinate of the DOMRect's origin
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/x)
     // https://drafts.fxtf.org/geometry-1/#dom-domrect-x
+    #[inline]
     pub fn get_x( &self ) -> f64 {
-        js! (
-            return @{&self.0}.x;
-        ).try_into().unwrap()
+        self.x
     }
 
     /// Represents the y coordinate of the DOMRect's origin.
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/y)
     // https://drafts.fxtf.org/geometry-1/#dom-domrect-y
+    #[inline]
     pub fn get_y( &self ) -> f64 {
-        js! (
-            return @{&self.0}.y;
-        ).try_into().unwrap()
+        self.y
     }
 
     /// Represents the width of the DOMRect.
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/width)
     // https://drafts.fxtf.org/geometry-1/#dom-domrect-width
+    #[inline]
     pub fn get_width( &self ) -> f64 {
-        js! (
-            return @{&self.0}.width;
-        ).try_into().unwrap()
+        self.width
     }
 
     /// Represents the height of the DOMRect.
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/height)
     // https://drafts.fxtf.org/geometry-1/#dom-domrect-height
+    #[inline]
     pub fn get_height( &self ) -> f64 {
-        js! (
-            return @{&self.0}.height;
-        ).try_into().unwrap()
+        self.height
     }
 
     /// Returns the top coordinate value of the DOMRect. (Has the same value as y, or y + height if height is negative.)
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/top)
     // https://drafts.fxtf.org/geometry-1/#dom-domrectreadonly-top
+    #[inline]
     pub fn get_top( &self ) -> f64 {
-        js! (
-            return @{&self.0}.top;
-        ).try_into().unwrap()
+        self.y.min( self.y + self.height )
     }
 
     /// Returns the right coordinate value of the DOMRect. (Has the same value as x + width, or x if width is negative.)
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/right)
     // https://drafts.fxtf.org/geometry-1/#dom-domrectreadonly-right
+    #[inline]
     pub fn get_right( &self ) -> f64 {
-        js! (
-            return @{&self.0}.right;
-        ).try_into().unwrap()
+        self.x.max( self.x + self.width )
     }
 
     /// Returns the bottom coordinate value of the DOMRect. (Has the same value as y + height, or y if height is negative.)
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/bottom)
     // https://drafts.fxtf.org/geometry-1/#dom-domrectreadonly-bottom
+    #[inline]
     pub fn get_bottom( &self ) -> f64 {
-        js! (
-            return @{&self.0}.bottom;
-        ).try_into().unwrap()
+        self.y.max( self.y + self.height )
     }
 
     /// Returns the left coordinate value of the DOMRect. (Has the same value as x, or x + width if width is negative.)
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/left)
     // https://drafts.fxtf.org/geometry-1/#dom-domrectreadonly-left
+    #[inline]
     pub fn get_left( &self ) -> f64 {
-        js! (
-            return @{&self.0}.left;
-        ).try_into().unwrap()
+        self.x.min( self.x + self.width )
     }
 }
 
+impl TryFrom< Value > for Rect {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let reference = match value {
+            Value::Reference( reference ) => reference,
+            value => return Err( ConversionError::type_mismatch( &value ) )
+        };
+
+        let values: Vec< f64 > = js!(
+            var rect = @{reference};
+            return [ rect.x, rect.y, rect.width, rect.height ];
+        ).try_into()?;
+
+        Ok( Rect::new( values[ 0 ], values[ 1 ], values[ 2 ], values[ 3 ] ) )
+    }
+}
+
+/// The editability of an element, as reflected by its `contentEditable` property.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/contentEditable)
+// https://html.spec.whatwg.org/#attr-contenteditable
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ContentEditable {
+    /// The element is editable.
+    True,
+    /// The element is not editable.
+    False,
+    /// The element inherits its editable status from its parent.
+    Inherit,
+    /// The element's raw text is editable, but rich text formatting is disabled.
+    PlaintextOnly
+}
+
 /// The `IHtmlElement` interface represents any HTML element.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement)
@@ -134,6 +175,34 @@ pub trait IHtmlElement: IElement {
         ).try_into().unwrap()
     }
 
+    /// Returns a rectangle for each CSS border box of the element.
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getClientRects)
+    // https://drafts.csswg.org/cssom-view/#dom-element-getclientrects
+    fn get_client_rects( &self ) -> Vec< Rect > {
+        js! (
+            return Array.prototype.slice.call( @{self.as_ref()}.getClientRects() );
+        ).try_into().unwrap()
+    }
+
+    /// Sets several inline style properties of the element at once.
+    ///
+    /// The property names are CSS property names, for example `"background-color"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/setProperty)
+    // https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-setproperty
+    fn set_styles( &self, styles: &[(&str, &str)] ) {
+        let names: Vec< &str > = styles.iter().map( |&(name, _)| name ).collect();
+        let values: Vec< &str > = styles.iter().map( |&(_, value)| value ).collect();
+        js! { @(no_return)
+            var style = @{self.as_ref()}.style;
+            var names = @{names};
+            var values = @{values};
+            for( var i = 0; i < names.length; ++i ) {
+                style.setProperty( names[ i ], values[ i ] );
+            }
+        }
+    }
+
     /// Returns the layout width of an element. Typically, an element's offsetWidth is a
     /// measurement which includes the element borders, the element horizontal padding, the
     /// element vertical scrollbar (if present, if rendered) and the element CSS width.
@@ -167,6 +236,53 @@ pub trait IHtmlElement: IElement {
             return @{self.as_ref()}.innerText;
         ).try_into().unwrap()
     }
+
+    /// Returns whether the element is editable.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/contentEditable)
+    // https://html.spec.whatwg.org/#dom-contenteditable
+    fn content_editable( &self ) -> ContentEditable {
+        let value: String = js!(
+            return @{self.as_ref()}.contentEditable;
+        ).try_into().unwrap();
+
+        match value.as_ref() {
+            "true" => ContentEditable::True,
+            "false" => ContentEditable::False,
+            "inherit" => ContentEditable::Inherit,
+            "plaintext-only" => ContentEditable::PlaintextOnly,
+            other => panic!( "Unexpected contentEditable value: {:?}", other )
+        }
+    }
+
+    /// Sets whether the element is editable.
+    ///
+    /// Browsers which don't support `ContentEditable::PlaintextOnly` will
+    /// return a `SyntaxError` when it is set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/contentEditable)
+    // https://html.spec.whatwg.org/#dom-contenteditable
+    fn set_content_editable( &self, value: ContentEditable ) -> Result< (), SyntaxError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.contentEditable = @{match value {
+                ContentEditable::True => "true",
+                ContentEditable::False => "false",
+                ContentEditable::Inherit => "inherit",
+                ContentEditable::PlaintextOnly => "plaintext-only"
+            }};
+        ).unwrap()
+    }
+
+    /// Returns whether the element is currently editable, taking into account
+    /// the `contentEditable` state inherited from its ancestors.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/isContentEditable)
+    // https://html.spec.whatwg.org/#dom-iscontenteditable
+    fn is_content_editable( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.isContentEditable;
+        ).try_into().unwrap()
+    }
 }
 
 /// A reference to a JavaScript object which implements the [IHtmlElement](trait.IHtmlElement.html)
@@ -199,6 +315,30 @@ mod tests {
         ).try_into().unwrap()
     }
 
+    #[test]
+    fn test_rect() {
+        let rect: Rect = js!( return new DOMRect( 10, 20, -5, 40 ); ).try_into().unwrap();
+        assert_eq!( rect, Rect::new( 10.0, 20.0, -5.0, 40.0 ) );
+        assert_eq!( rect.get_left(), 5.0 );
+        assert_eq!( rect.get_right(), 10.0 );
+        assert_eq!( rect.get_top(), 20.0 );
+        assert_eq!( rect.get_bottom(), 60.0 );
+    }
+
+    #[test]
+    fn test_set_styles() {
+        let element: HtmlElement = div().try_into().unwrap();
+        element.set_styles( &[("color", "red"), ("margin-left", "2px")] );
+        assert_eq!( js!( return @{&element}.style.color; ), "red" );
+        assert_eq!( js!( return @{&element}.style.marginLeft; ), "2px" );
+    }
+
+    #[test]
+    fn test_get_client_rects() {
+        let element: HtmlElement = div().try_into().unwrap();
+        assert!( element.get_client_rects().is_empty() );
+    }
+
     #[test]
     fn test_inner_text() {
         let element: HtmlElement = div().try_into().unwrap();
@@ -208,4 +348,19 @@ mod tests {
         element.append_child(&text("foo"));
         assert_eq!(element.inner_text(), "foo foo");
     }
+
+    #[test]
+    fn test_content_editable() {
+        let element: HtmlElement = div().try_into().unwrap();
+        assert_eq!(element.content_editable(), ContentEditable::Inherit);
+
+        for &value in &[ContentEditable::True, ContentEditable::False, ContentEditable::Inherit] {
+            element.set_content_editable(value).unwrap();
+            assert_eq!(element.content_editable(), value);
+        }
+
+        if element.set_content_editable(ContentEditable::PlaintextOnly).is_ok() {
+            assert_eq!(element.content_editable(), ContentEditable::PlaintextOnly);
+        }
+    }
 }
@@ -0,0 +1,147 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::url_components::IUrlComponents;
+
+/// The HTML `<a>` element represents a hyperlink.
+///
+/// Its URL components can be accessed through [IUrlComponents](trait.IUrlComponents.html),
+/// which also makes it usable to take a URL apart.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement)
+// https://html.spec.whatwg.org/#htmlanchorelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLAnchorElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct AnchorElement( Reference );
+
+impl IEventTarget for AnchorElement {}
+impl INode for AnchorElement {}
+impl IElement for AnchorElement {}
+impl IHtmlElement for AnchorElement {}
+impl IUrlComponents for AnchorElement {}
+
+impl AnchorElement {
+    /// Sets the whole URL of the link.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/href)
+    // https://html.spec.whatwg.org/#dom-hyperlink-href
+    #[inline]
+    pub fn set_href( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.href = @{value};
+        }
+    }
+
+    /// Returns the file name which is suggested when the link's target is downloaded, or an empty string if the link isn't a download link.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/download)
+    // https://html.spec.whatwg.org/#attr-hyperlink-download
+    #[inline]
+    pub fn download( &self ) -> String {
+        js!(
+            return @{self}.download;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the suggested file name of the download, which turns the link into a download link.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/download)
+    // https://html.spec.whatwg.org/#attr-hyperlink-download
+    #[inline]
+    pub fn set_download( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.download = @{value};
+        }
+    }
+
+    /// Returns the name of the browsing context in which the link is opened, such as `_blank`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/target)
+    // https://html.spec.whatwg.org/#attr-hyperlink-target
+    #[inline]
+    pub fn target( &self ) -> String {
+        js!(
+            return @{self}.target;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the name of the browsing context in which the link is opened.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/target)
+    // https://html.spec.whatwg.org/#attr-hyperlink-target
+    #[inline]
+    pub fn set_target( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.target = @{value};
+        }
+    }
+
+    /// Returns the relationship of the linked resource to the current document, such as `noopener`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/rel)
+    // https://html.spec.whatwg.org/#attr-hyperlink-rel
+    #[inline]
+    pub fn rel( &self ) -> String {
+        js!(
+            return @{self}.rel;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the relationship of the linked resource to the current document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/rel)
+    // https://html.spec.whatwg.org/#attr-hyperlink-rel
+    #[inline]
+    pub fn set_rel( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.rel = @{value};
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn anchor( href: &str ) -> AnchorElement {
+        let anchor: AnchorElement = js!( return document.createElement( "a" ); ).try_into().unwrap();
+        anchor.set_href( href );
+        anchor
+    }
+
+    #[test]
+    fn test_url_components() {
+        let anchor = anchor( "https://example.com:8080/foo/bar?baz=1#qux" );
+        assert_eq!( anchor.href(), "https://example.com:8080/foo/bar?baz=1#qux" );
+        assert_eq!( anchor.origin(), "https://example.com:8080" );
+        assert_eq!( anchor.protocol(), "https:" );
+        assert_eq!( anchor.host(), "example.com:8080" );
+        assert_eq!( anchor.hostname(), "example.com" );
+        assert_eq!( anchor.port(), "8080" );
+        assert_eq!( anchor.pathname(), "/foo/bar" );
+        assert_eq!( anchor.search(), "?baz=1" );
+        assert_eq!( anchor.hash(), "#qux" );
+
+        anchor.set_pathname( "/other" );
+        anchor.set_hash( "" );
+        assert_eq!( anchor.href(), "https://example.com:8080/other?baz=1" );
+    }
+
+    #[test]
+    fn test_download_and_target() {
+        let anchor = anchor( "https://example.com/" );
+        assert_eq!( anchor.download(), "" );
+        anchor.set_download( "file.txt" );
+        assert_eq!( anchor.download(), "file.txt" );
+        assert_eq!( anchor.get_attribute( "download" ), Some( "file.txt".to_owned() ) );
+
+        anchor.set_target( "_blank" );
+        anchor.set_rel( "noopener" );
+        assert_eq!( anchor.target(), "_blank" );
+        assert_eq!( anchor.rel(), "noopener" );
+    }
+}
@@ -7,6 +7,11 @@ use webapi::element::{IElement, Element};
 use webapi::html_element::{IHtmlElement, HtmlElement};
 use webapi::blob::Blob;
 use webapi::rendering_context::RenderingContext;
+use webapi::window::window;
+use webapi::offscreen_canvas::OffscreenCanvas;
+use webapi::dom_exception::InvalidStateError;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
 use private::TODO;
 
 /// The HTML `<canvas>` element provides an empty graphic zone on which specific JavaScript APIs
@@ -104,4 +109,72 @@ impl CanvasElement {
 
         Ok(())
     }
+
+    /// Returns a future which resolves to a Blob object representing the image contained in the canvas.
+    ///
+    /// This is the asynchronous version of [`to_blob`](#method.to_blob). Prefer it over
+    /// [`to_data_url`](#method.to_data_url) for large images, since it avoids creating
+    /// a huge base64-encoded string.
+    ///
+    /// The future fails if the canvas is tainted by cross-origin data or if the image couldn't be created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLCanvasElement/toBlob)
+    // https://html.spec.whatwg.org/#the-canvas-element:dom-canvas-toblob
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn to_blob_future( &self, mime_type: Option< &str >, quality: Option< f64 > ) -> PromiseFuture< Blob > {
+        js!(
+            var canvas = @{self};
+            var mime_type = @{mime_type};
+            var quality = @{quality};
+            return new Promise( function( resolve, reject ) {
+                canvas.toBlob( function( blob ) {
+                    if( blob === null ) {
+                        reject( new Error( "The canvas image couldn't be created" ) );
+                    } else {
+                        resolve( blob );
+                    }
+                }, mime_type, quality );
+            } );
+        ).try_into().unwrap()
+    }
+
+    /// Transfers control of this canvas to a new [`OffscreenCanvas`](struct.OffscreenCanvas.html),
+    /// which can then be sent to a worker and rendered to from there.
+    ///
+    /// Everything drawn on the returned `OffscreenCanvas` is displayed by this element.
+    /// Afterwards this element can no longer be drawn to directly: calling
+    /// [`get_context`](#method.get_context) on it will fail.
+    ///
+    /// Returns an `InvalidStateError` if a rendering context was already obtained
+    /// from this canvas, or if control was already transferred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLCanvasElement/transferControlToOffscreen)
+    // https://html.spec.whatwg.org/#dom-canvas-transfercontroltooffscreen
+    pub fn transfer_control_to_offscreen( &self ) -> Result< OffscreenCanvas, InvalidStateError > {
+        js_try!(
+            return @{self}.transferControlToOffscreen();
+        ).unwrap()
+    }
+
+    /// Resizes the canvas for the current display's [device pixel ratio](struct.Window.html#method.device_pixel_ratio).
+    ///
+    /// The backing store is set to `css_width * ratio` by `css_height * ratio` pixels
+    /// while the CSS size of the element is set to `css_width` by `css_height`, so that
+    /// drawing stays crisp on high-DPI displays.
+    ///
+    /// Returns the device pixel ratio which was used; you'll usually want to pass it to
+    /// the rendering context's `scale` so that you can keep drawing in CSS pixels.
+    pub fn resize_for_dpi( &self, css_width: f64, css_height: f64 ) -> f64 {
+        let ratio = window().device_pixel_ratio();
+        self.set_width( ( css_width * ratio ).round() as u32 );
+        self.set_height( ( css_height * ratio ).round() as u32 );
+
+        js! { @(no_return)
+            var style = @{self}.style;
+            style.width = @{css_width} + "px";
+            style.height = @{css_height} + "px";
+        }
+
+        ratio
+    }
 }
@@ -0,0 +1,158 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::token_list::TokenList;
+use webapi::window_proxy::WindowProxy;
+use webapi::document::Document;
+
+/// The HTML `<iframe>` element represents a nested browsing context,
+/// embedding another page into the current one.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement)
+// https://html.spec.whatwg.org/#htmliframeelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLIFrameElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct IFrameElement( Reference );
+
+impl IEventTarget for IFrameElement {}
+impl INode for IFrameElement {}
+impl IElement for IFrameElement {}
+impl IHtmlElement for IFrameElement {}
+
+impl IFrameElement {
+    /// Returns the URL of the embedded page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/src)
+    // https://html.spec.whatwg.org/#dom-iframe-src
+    pub fn src( &self ) -> String {
+        js!(
+            return @{self}.src;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the URL of the embedded page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/src)
+    // https://html.spec.whatwg.org/#dom-iframe-src
+    pub fn set_src( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.src = @{value};
+        }
+    }
+
+    /// Returns the name of the embedded browsing context, which can be used as a link target.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/name)
+    // https://html.spec.whatwg.org/#dom-iframe-name
+    pub fn name( &self ) -> String {
+        js!(
+            return @{self}.name;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the name of the embedded browsing context.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/name)
+    // https://html.spec.whatwg.org/#dom-iframe-name
+    pub fn set_name( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.name = @{value};
+        }
+    }
+
+    /// Returns a live [TokenList](struct.TokenList.html) of the restrictions applied
+    /// to the embedded page by the `sandbox` attribute, like `allow-scripts`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/sandbox)
+    // https://html.spec.whatwg.org/#dom-iframe-sandbox
+    pub fn sandbox( &self ) -> TokenList {
+        js!(
+            return @{self}.sandbox;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the permissions policy of the embedded page, from the `allow` attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/iframe#attr-allow)
+    // https://html.spec.whatwg.org/#dom-iframe-allow
+    pub fn allow( &self ) -> String {
+        js!(
+            return @{self}.allow;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the permissions policy of the embedded page, for example `"fullscreen; camera"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/iframe#attr-allow)
+    // https://html.spec.whatwg.org/#dom-iframe-allow
+    pub fn set_allow( &self, value: &str ) {
+        js! { @(no_return)
+            @{self}.allow = @{value};
+        }
+    }
+
+    /// Returns the window of the embedded page, which can be used to
+    /// [post messages](struct.WindowProxy.html#method.post_message) to it.
+    ///
+    /// Returns `None` if the iframe isn't in a document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/contentWindow)
+    // https://html.spec.whatwg.org/#dom-iframe-contentwindow
+    pub fn content_window( &self ) -> Option< WindowProxy > {
+        js!(
+            return @{self}.contentWindow;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the document of the embedded page.
+    ///
+    /// Returns `None` if the iframe isn't in a document, or if the embedded
+    /// page is from another origin.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLIFrameElement/contentDocument)
+    // https://html.spec.whatwg.org/#dom-iframe-contentdocument
+    pub fn content_document( &self ) -> Option< Document > {
+        js!(
+            try {
+                return @{self}.contentDocument;
+            } catch( error ) {
+                return null;
+            }
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    fn iframe() -> IFrameElement {
+        js!( return document.createElement( "iframe" ); ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_sandbox() {
+        let iframe = iframe();
+        iframe.sandbox().add( "allow-scripts" ).unwrap();
+        assert_eq!( iframe.get_attribute( "sandbox" ).unwrap(), "allow-scripts" );
+        assert!( iframe.sandbox().contains( "allow-scripts" ) );
+    }
+
+    #[test]
+    fn test_content_window() {
+        let iframe = iframe();
+        assert!( iframe.content_window().is_none() );
+        assert!( iframe.content_document().is_none() );
+
+        let body = document().body().unwrap();
+        body.append_child( &iframe );
+        assert!( iframe.content_window().is_some() );
+        assert!( iframe.content_document().is_some() );
+        body.remove_child( &iframe ).unwrap();
+    }
+}
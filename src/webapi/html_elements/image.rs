@@ -5,6 +5,9 @@ use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
 use webapi::element::{IElement, Element};
 use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::error::IError;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
 
 /// The HTML image element is used to manipulate the layout and presentation of
 /// `<img>` elements.
@@ -21,6 +24,15 @@ impl INode for ImageElement {}
 impl IElement for ImageElement {}
 impl IHtmlElement for ImageElement {}
 
+/// The error returned when an [`ImageElement`](struct.ImageElement.html) fails to load or decode.
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Error")]
+pub struct ImageError( Reference );
+
+impl IError for ImageError {}
+
+error_boilerplate! { ImageError }
+
 impl ImageElement {
     /// Constructs a new ImageElement.
     ///
@@ -42,6 +54,43 @@ impl ImageElement {
         ).try_into().unwrap()
     }
 
+    /// Constructs a new ImageElement, sets its `src` to `url` and returns a
+    /// future which resolves to the image once it has finished loading.
+    ///
+    /// The future fails with an [`ImageError`](struct.ImageError.html) if the image can't be loaded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/Image)
+    // https://html.spec.whatwg.org/#the-img-element:dom-image
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn load( url: &str ) -> PromiseFuture< ImageElement, ImageError > {
+        js!(
+            var image = new Image();
+            return new Promise( function( resolve, reject ) {
+                image.onload = function() {
+                    resolve( image );
+                };
+                image.onerror = function() {
+                    reject( new Error( "Failed to load image: " + image.src ) );
+                };
+                image.src = @{url};
+            } );
+        ).try_into().unwrap()
+    }
+
+    /// Starts decoding the image and returns a future which resolves once
+    /// the image is ready to be appended to the DOM or drawn.
+    ///
+    /// The future fails with an [`ImageError`](struct.ImageError.html) if the image can't be decoded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/decode)
+    // https://html.spec.whatwg.org/#dom-img-decode
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn decode( &self ) -> PromiseFuture< (), ImageError > {
+        js!(
+            return @{self}.decode();
+        ).try_into().unwrap()
+    }
+
     /// Returns the HTML `alt` attribute, representing the fallback context for the image.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/alt)
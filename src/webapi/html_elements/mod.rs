@@ -1,15 +1,21 @@
+mod anchor;
 mod canvas;
 mod image;
 mod input;
 mod textarea;
 mod select;
 mod option;
+mod table;
+mod iframe;
 
+pub use self::anchor::AnchorElement;
 pub use self::canvas::CanvasElement;
-pub use self::image::ImageElement;
+pub use self::image::{ImageElement, ImageError};
 pub use self::input::InputElement;
 pub use self::textarea::TextAreaElement;
 pub use self::select::SelectElement;
 pub use self::option::OptionElement;
+pub use self::table::{TableElement, TableRowElement, TableCellElement};
+pub use self::iframe::IFrameElement;
 
 pub use self::select::UnknownValueError;
\ No newline at end of file
@@ -0,0 +1,236 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::dom_exception::IndexSizeError;
+
+/// The HTML `<table>` element represents tabular data.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableElement)
+// https://html.spec.whatwg.org/#htmltableelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLTableElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct TableElement( Reference );
+
+impl IEventTarget for TableElement {}
+impl INode for TableElement {}
+impl IElement for TableElement {}
+impl IHtmlElement for TableElement {}
+
+impl TableElement {
+    /// Returns all the rows of the table, including the ones
+    /// in its `<thead>` and `<tfoot>`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableElement/rows)
+    // https://html.spec.whatwg.org/#dom-table-rows
+    pub fn rows( &self ) -> Vec< TableRowElement > {
+        js!(
+            return Array.prototype.slice.call( @{self}.rows );
+        ).try_into().unwrap()
+    }
+
+    /// Inserts a new row at `index` and returns it. An `index` of `-1`
+    /// appends the row at the end of the table.
+    ///
+    /// Fails if `index` is smaller than `-1` or larger than the number of rows.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableElement/insertRow)
+    // https://html.spec.whatwg.org/#dom-table-insertrow
+    pub fn insert_row( &self, index: i32 ) -> Result< TableRowElement, IndexSizeError > {
+        js_try!(
+            return @{self}.insertRow( @{index} );
+        ).unwrap()
+    }
+
+    /// Removes the row at `index`. An `index` of `-1` removes the last row.
+    ///
+    /// Fails if `index` is smaller than `-1`, or not smaller than the number of rows.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableElement/deleteRow)
+    // https://html.spec.whatwg.org/#dom-table-deleterow
+    pub fn delete_row( &self, index: i32 ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self}.deleteRow( @{index} );
+        ).unwrap()
+    }
+}
+
+/// The HTML `<tr>` element represents a row of cells in a table.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableRowElement)
+// https://html.spec.whatwg.org/#htmltablerowelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLTableRowElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct TableRowElement( Reference );
+
+impl IEventTarget for TableRowElement {}
+impl INode for TableRowElement {}
+impl IElement for TableRowElement {}
+impl IHtmlElement for TableRowElement {}
+
+impl TableRowElement {
+    /// Returns the position of the row in its table, or `-1` if it isn't in a table.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableRowElement/rowIndex)
+    // https://html.spec.whatwg.org/#dom-tr-rowindex
+    pub fn row_index( &self ) -> i32 {
+        js!(
+            return @{self}.rowIndex;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the cells of the row, both `<td>` and `<th>`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableRowElement/cells)
+    // https://html.spec.whatwg.org/#dom-tr-cells
+    pub fn cells( &self ) -> Vec< TableCellElement > {
+        js!(
+            return Array.prototype.slice.call( @{self}.cells );
+        ).try_into().unwrap()
+    }
+
+    /// Inserts a new `<td>` cell at `index` and returns it. An `index` of `-1`
+    /// appends the cell at the end of the row.
+    ///
+    /// Fails if `index` is smaller than `-1` or larger than the number of cells.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableRowElement/insertCell)
+    // https://html.spec.whatwg.org/#dom-tr-insertcell
+    pub fn insert_cell( &self, index: i32 ) -> Result< TableCellElement, IndexSizeError > {
+        js_try!(
+            return @{self}.insertCell( @{index} );
+        ).unwrap()
+    }
+
+    /// Removes the cell at `index`. An `index` of `-1` removes the last cell.
+    ///
+    /// Fails if `index` is smaller than `-1`, or not smaller than the number of cells.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableRowElement/deleteCell)
+    // https://html.spec.whatwg.org/#dom-tr-deletecell
+    pub fn delete_cell( &self, index: i32 ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self}.deleteCell( @{index} );
+        ).unwrap()
+    }
+}
+
+/// The HTML `<td>` and `<th>` elements represent a cell of a table.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableCellElement)
+// https://html.spec.whatwg.org/#htmltablecellelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLTableCellElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct TableCellElement( Reference );
+
+impl IEventTarget for TableCellElement {}
+impl INode for TableCellElement {}
+impl IElement for TableCellElement {}
+impl IHtmlElement for TableCellElement {}
+
+impl TableCellElement {
+    /// Returns the position of the cell in its row, or `-1` if it isn't in a row.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableCellElement/cellIndex)
+    // https://html.spec.whatwg.org/#dom-tdth-cellindex
+    pub fn cell_index( &self ) -> i32 {
+        js!(
+            return @{self}.cellIndex;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of columns this cell spans.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableCellElement/colSpan)
+    // https://html.spec.whatwg.org/#dom-tdth-colspan
+    pub fn col_span( &self ) -> u32 {
+        js!(
+            return @{self}.colSpan;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the number of columns this cell spans.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableCellElement/colSpan)
+    // https://html.spec.whatwg.org/#dom-tdth-colspan
+    pub fn set_col_span( &self, value: u32 ) {
+        js! { @(no_return)
+            @{self}.colSpan = @{value};
+        }
+    }
+
+    /// Returns the number of rows this cell spans.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableCellElement/rowSpan)
+    // https://html.spec.whatwg.org/#dom-tdth-rowspan
+    pub fn row_span( &self ) -> u32 {
+        js!(
+            return @{self}.rowSpan;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the number of rows this cell spans.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTableCellElement/rowSpan)
+    // https://html.spec.whatwg.org/#dom-tdth-rowspan
+    pub fn set_row_span( &self, value: u32 ) {
+        js! { @(no_return)
+            @{self}.rowSpan = @{value};
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn table() -> TableElement {
+        js!( return document.createElement( "table" ); ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_insert_and_delete_rows() {
+        let table = table();
+        let first = table.insert_row( -1 ).unwrap();
+        let last = table.insert_row( -1 ).unwrap();
+        let middle = table.insert_row( 1 ).unwrap();
+
+        let rows = table.rows();
+        assert_eq!( rows.len(), 3 );
+        assert_eq!( rows[ 0 ], first );
+        assert_eq!( rows[ 1 ], middle );
+        assert_eq!( rows[ 2 ], last );
+        assert_eq!( last.row_index(), 2 );
+
+        table.delete_row( -1 ).unwrap();
+        table.delete_row( 0 ).unwrap();
+        assert_eq!( table.rows(), vec![ middle ] );
+
+        assert!( table.insert_row( 5 ).is_err() );
+        assert!( table.delete_row( 5 ).is_err() );
+    }
+
+    #[test]
+    fn test_insert_and_delete_cells() {
+        let row = table().insert_row( -1 ).unwrap();
+        let first = row.insert_cell( -1 ).unwrap();
+        let second = row.insert_cell( -1 ).unwrap();
+        assert_eq!( row.cells(), vec![ first.clone(), second.clone() ] );
+        assert_eq!( second.cell_index(), 1 );
+        assert_eq!( first.node_name(), "TD" );
+
+        first.set_col_span( 2 );
+        assert_eq!( first.col_span(), 2 );
+        assert_eq!( second.row_span(), 1 );
+
+        row.delete_cell( 0 ).unwrap();
+        assert_eq!( row.cells(), vec![ second ] );
+        assert!( row.insert_cell( -2 ).is_err() );
+        assert!( row.delete_cell( 3 ).is_err() );
+    }
+}
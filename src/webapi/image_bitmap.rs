@@ -0,0 +1,115 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::reference_type::ReferenceType;
+use webapi::blob::Blob;
+use webapi::html_elements::{ImageElement, CanvasElement};
+use webapi::rendering_context::ImageData;
+use webapi::offscreen_canvas::OffscreenCanvas;
+use webapi::dom_exception::{IndexSizeError, InvalidStateError};
+use webapi::error::TypeError;
+
+/// A bitmap image which can be drawn to a `<canvas>` without undue latency.
+///
+/// You can create one with [`Window::create_image_bitmap`](struct.Window.html#method.create_image_bitmap).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageBitmap)
+// https://html.spec.whatwg.org/#imagebitmap
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ImageBitmap")]
+pub struct ImageBitmap( Reference );
+
+impl ImageBitmap {
+    /// Returns the width of the bitmap in CSS pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageBitmap/width)
+    // https://html.spec.whatwg.org/#dom-imagebitmap-width
+    pub fn width( &self ) -> u32 {
+        js!(
+            return @{self}.width;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the height of the bitmap in CSS pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageBitmap/height)
+    // https://html.spec.whatwg.org/#dom-imagebitmap-height
+    pub fn height( &self ) -> u32 {
+        js!(
+            return @{self}.height;
+        ).try_into().unwrap()
+    }
+
+    /// Disposes of all graphical resources associated with the bitmap.
+    ///
+    /// After this is called the bitmap can no longer be drawn, and its
+    /// width and height will be `0`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageBitmap/close)
+    // https://html.spec.whatwg.org/#dom-imagebitmap-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+/// A type which can be passed to [`Window::create_image_bitmap`](struct.Window.html#method.create_image_bitmap).
+// https://html.spec.whatwg.org/#imagebitmapsource
+pub trait ImageBitmapSource: ReferenceType {}
+
+impl ImageBitmapSource for Blob {}
+impl ImageBitmapSource for ImageElement {}
+impl ImageBitmapSource for CanvasElement {}
+impl ImageBitmapSource for ImageData {}
+impl ImageBitmapSource for ImageBitmap {}
+impl ImageBitmapSource for OffscreenCanvas {}
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that `createImageBitmap()` may reject with.
+    // https://html.spec.whatwg.org/#dom-createimagebitmap
+    CreateImageBitmapError,
+    /// An IndexSizeError if the source's width or height is 0
+    IndexSizeError,
+    /// An InvalidStateError if the source has no image data or couldn't be decoded
+    InvalidStateError,
+    /// A TypeError if the source isn't supported
+    TypeError
+}
+
+#[cfg(all(test, feature = "web_test", feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use webapi::window::window;
+    use webcore::promise_future::PromiseFuture;
+
+    #[test]
+    fn test_create_image_bitmap_from_canvas() {
+        let canvas: CanvasElement = js!( return document.createElement( "canvas" ); ).try_into().unwrap();
+        canvas.set_width( 30 );
+        canvas.set_height( 20 );
+
+        PromiseFuture::spawn_local(
+            window().create_image_bitmap( &canvas )
+                .map( |bitmap| {
+                    assert_eq!( bitmap.width(), 30 );
+                    assert_eq!( bitmap.height(), 20 );
+                    bitmap.close();
+                    assert_eq!( bitmap.width(), 0 );
+                })
+                .recover( |error| panic!( "create_image_bitmap failed: {:?}", error ) )
+        );
+    }
+
+    #[test]
+    fn test_native_type_error() {
+        use webapi::error::IError;
+
+        // `createImageBitmap` rejects with a plain `TypeError`, not a `DOMException`.
+        let error: CreateImageBitmapError = js!( return new TypeError( "unsupported source" ); ).try_into().unwrap();
+        match error {
+            CreateImageBitmapError::TypeError( error ) => assert_eq!( error.message(), "unsupported source" ),
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::object::Object;
+use webapi::date::Date;
+use webapi::error::Error;
+
+/// A language sensitive number formatter, for example for currencies and percentages.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
+// https://www.ecma-international.org/ecma-402/1.0/#sec-11
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Intl.NumberFormat")]
+pub struct NumberFormat( Reference );
+
+impl NumberFormat {
+    /// Creates a new number formatter.
+    ///
+    /// `locale` is a BCP 47 language tag (for example `"de-DE"`); if it's `None` the browser's default locale is used.
+    /// `options` is an object with the formatting options, for example `{ style: "currency", currency: "EUR" }`
+    /// or `{ style: "percent" }`.
+    ///
+    /// Returns an error if the `locale` or the `options` are invalid.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-11.1.3.1
+    pub fn new( locale: Option< &str >, options: Option< &Object > ) -> Result< Self, Error > {
+        js_try!(
+            var locale = @{locale};
+            var options = @{options};
+            return new Intl.NumberFormat( locale === null ? undefined : locale, options === null ? undefined : options );
+        ).unwrap()
+    }
+
+    /// Formats the given number according to the locale and options of this formatter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/format)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-11.3.2
+    pub fn format( &self, number: f64 ) -> String {
+        js!(
+            return @{self}.format( @{number} );
+        ).try_into().unwrap()
+    }
+}
+
+/// A language sensitive date and time formatter.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
+// https://www.ecma-international.org/ecma-402/1.0/#sec-12
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Intl.DateTimeFormat")]
+pub struct DateTimeFormat( Reference );
+
+impl DateTimeFormat {
+    /// Creates a new date and time formatter.
+    ///
+    /// `locale` is a BCP 47 language tag (for example `"en-GB"`); if it's `None` the browser's default locale is used.
+    /// `options` is an object with the formatting options, for example `{ dateStyle: "long", timeZone: "UTC" }`.
+    ///
+    /// Returns an error if the `locale` or the `options` are invalid.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-12.1.3.1
+    pub fn new( locale: Option< &str >, options: Option< &Object > ) -> Result< Self, Error > {
+        js_try!(
+            var locale = @{locale};
+            var options = @{options};
+            return new Intl.DateTimeFormat( locale === null ? undefined : locale, options === null ? undefined : options );
+        ).unwrap()
+    }
+
+    /// Formats the given date according to the locale and options of this formatter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/format)
+    // https://www.ecma-international.org/ecma-402/1.0/#sec-12.3.2
+    pub fn format( &self, date: &Date ) -> String {
+        js!(
+            return @{self}.format( @{date} );
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_format() {
+        let options: Object = js!( return { style: "percent" }; ).try_into().unwrap();
+        let format = NumberFormat::new( Some( "en-US" ), Some( &options ) ).unwrap();
+        assert_eq!( format.format( 0.25 ), "25%" );
+
+        let format = NumberFormat::new( Some( "en-US" ), None ).unwrap();
+        assert_eq!( format.format( 1234.5 ), "1,234.5" );
+    }
+
+    #[test]
+    fn test_number_format_invalid_options() {
+        let options: Object = js!( return { style: "currency" }; ).try_into().unwrap();
+        assert!( NumberFormat::new( Some( "en-US" ), Some( &options ) ).is_err() );
+    }
+
+    #[test]
+    fn test_date_time_format() {
+        let options: Object = js!( return { timeZone: "UTC" }; ).try_into().unwrap();
+        let format = DateTimeFormat::new( Some( "en-US" ), Some( &options ) ).unwrap();
+        let date = Date::from_time( Date::utc( 96, 1, 2, 3, 4, 5, 0 ) );
+        assert_eq!( format.format( &date ), "2/2/1996" );
+    }
+}
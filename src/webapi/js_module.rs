@@ -0,0 +1,122 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::instance_of::InstanceOf;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// The namespace object of an ES module loaded with [import_module](fn.import_module.html),
+/// giving access to the module's exports.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import#Dynamic_Imports)
+// https://tc39.github.io/ecma262/#sec-module-namespace-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct JsModule( Reference );
+
+// This is implemented by hand since there is no `Module` constructor
+// that could be used with `instanceof`.
+impl InstanceOf for JsModule {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        __js_raw_asm!(
+            "return (Object.prototype.toString.call( Module.STDWEB_PRIVATE.acquire_js_reference( $0 ) ) === \"[object Module]\") | 0;",
+            reference.as_raw()
+        ) == 1
+    }
+}
+
+impl JsModule {
+    /// Returns the export with the given `name`, or `Value::Undefined`
+    /// if the module has no such export.
+    pub fn get( &self, name: &str ) -> Value {
+        js!(
+            return @{self}[ @{name} ];
+        )
+    }
+
+    /// Returns whether the module has an export with the given `name`.
+    pub fn has( &self, name: &str ) -> bool {
+        js!(
+            return @{name} in @{self};
+        ).try_into().unwrap()
+    }
+
+    /// Returns the default export of the module, or `Value::Undefined`
+    /// if it has none.
+    pub fn default_export( &self ) -> Value {
+        self.get( "default" )
+    }
+
+    /// Returns the names of all the exports of the module.
+    pub fn export_names( &self ) -> Vec< String > {
+        js!(
+            return Object.keys( @{self} );
+        ).try_into().unwrap()
+    }
+}
+
+/// Loads the ES module at `url` at runtime with a dynamic `import()`, and
+/// returns a future which resolves to its namespace object.
+///
+/// This allows calling into JavaScript libraries without a bundling step:
+///
+/// ```rust
+/// import_module( "https://example.com/library.js" ).map( |module| {
+///     let do_thing = module.get( "doThing" );
+///     js! { @{do_thing}( 42 ); }
+/// })
+/// ```
+///
+/// Relative URLs are resolved against the URL of the script calling `import()`,
+/// so prefer absolute URLs.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import#Dynamic_Imports)
+// https://tc39.github.io/ecma262/#sec-import-calls
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+pub fn import_module( url: &str ) -> PromiseFuture< JsModule, Error > {
+    js!(
+        return import( @{url} );
+    ).try_into().unwrap()
+}
+
+#[cfg(all(test, feature = "web_test", feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn test_import_module() {
+        let url = "data:text/javascript,export const answer = 42; export function double( x ) { return x * 2; } export default \"hello\";";
+        PromiseFuture::spawn_local(
+            import_module( url )
+                .map( |module| {
+                    let names = module.export_names();
+                    assert_eq!( names.len(), 3 );
+                    for name in &[ "answer", "default", "double" ] {
+                        assert!( names.iter().any( |export| export == name ) );
+                    }
+
+                    assert!( module.has( "answer" ) );
+                    assert!( !module.has( "missing" ) );
+                    assert_eq!( module.get( "answer" ), 42 );
+                    assert_eq!( module.get( "missing" ), Value::Undefined );
+                    assert_eq!( module.default_export(), "hello" );
+                    assert_eq!( js!( return @{module.get( "double" )}( 21 ); ), 42 );
+
+                    let reference = module.as_ref().clone();
+                    assert!( JsModule::instance_of( &reference ) );
+                })
+                .recover( |error| panic!( "import_module failed: {:?}", error ) )
+        );
+    }
+
+    #[test]
+    fn test_instance_of() {
+        let object: Reference = js!( return {}; ).try_into().unwrap();
+        assert!( !JsModule::instance_of( &object ) );
+
+        let module: Result< JsModule, _ > = js!( return {}; ).try_into();
+        assert!( module.is_err() );
+    }
+}
@@ -0,0 +1,102 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+/// Stores information on a media query applied to a document, and handles sending notifications to
+/// listeners when the media query state changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList)
+// https://drafts.csswg.org/cssom-view/#mediaquerylist
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaQueryList")]
+#[reference(subclass_of(EventTarget))]
+pub struct MediaQueryList( Reference );
+
+impl IEventTarget for MediaQueryList {}
+
+impl MediaQueryList {
+    /// Returns `true` if the document currently matches the media query list.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList/matches)
+    // https://drafts.csswg.org/cssom-view/#dom-mediaquerylist-matches
+    pub fn matches( &self ) -> bool {
+        js!(
+            return @{self}.matches;
+        ).try_into().unwrap()
+    }
+
+    /// Returns a serialized version of the media query list.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList/media)
+    // https://drafts.csswg.org/cssom-view/#dom-mediaquerylist-media
+    pub fn media( &self ) -> String {
+        js!(
+            return @{self}.media;
+        ).try_into().unwrap()
+    }
+
+    /// Adds a listener which will be called with the new value of
+    /// [`matches`](#method.matches) whenever the media query status changes.
+    ///
+    /// The listener is removed when the returned [`MediaQueryListener`](struct.MediaQueryListener.html)
+    /// is dropped.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList/addListener)
+    // https://drafts.csswg.org/cssom-view/#dom-mediaquerylist-addlistener
+    pub fn add_listener< F >( &self, callback: F ) -> MediaQueryListener
+        where F: FnMut( bool ) + 'static {
+        let callback_reference: Reference = js!( return @{callback}; ).try_into().unwrap();
+
+        let listener_reference: Reference = js!(
+            var callback = @{&callback_reference};
+            var listener = function( event ) {
+                callback( event.matches );
+            };
+            @{self}.addListener( listener );
+            return listener;
+        ).try_into().unwrap();
+
+        MediaQueryListener {
+            media_query_list: self.clone(),
+            listener_reference,
+            callback_reference
+        }
+    }
+}
+
+/// A handle to a listener added with [`MediaQueryList::add_listener`](struct.MediaQueryList.html#method.add_listener).
+///
+/// When the `MediaQueryListener` is dropped the listener is removed
+/// from the `MediaQueryList` and its callback is freed.
+#[ derive( Debug ) ]
+pub struct MediaQueryListener {
+    media_query_list: MediaQueryList,
+    listener_reference: Reference,
+    callback_reference: Reference
+}
+
+impl Drop for MediaQueryListener {
+    #[inline]
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            @{&self.media_query_list}.removeListener( @{&self.listener_reference} );
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::window::window;
+
+    #[test]
+    fn test_match_media() {
+        let list: MediaQueryList = window().match_media( "(min-width: 0px)" );
+        assert_eq!( list.media(), "(min-width: 0px)" );
+        assert!( list.matches() );
+
+        let listener = list.add_listener( |_| {} );
+        drop( listener );
+    }
+}
@@ -0,0 +1,132 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::DataCloneError;
+
+/// A two-way channel through which two contexts, such as a page and a worker,
+/// can communicate using its two [MessagePort](struct.MessagePort.html)s.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel)
+// https://html.spec.whatwg.org/#messagechannel
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessageChannel")]
+pub struct MessageChannel( Reference );
+
+impl MessageChannel {
+    /// Creates a new channel and returns its two entangled ports. Messages posted
+    /// to one of them are received by the other one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel/MessageChannel)
+    // https://html.spec.whatwg.org/#dom-messagechannel
+    pub fn new() -> ( MessagePort, MessagePort ) {
+        let channel: MessageChannel = js!(
+            return new MessageChannel();
+        ).try_into().unwrap();
+
+        ( channel.port1(), channel.port2() )
+    }
+
+    /// Returns the first port of the channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel/port1)
+    // https://html.spec.whatwg.org/#dom-messagechannel-port1
+    pub fn port1( &self ) -> MessagePort {
+        js!(
+            return @{self}.port1;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the second port of the channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel/port2)
+    // https://html.spec.whatwg.org/#dom-messagechannel-port2
+    pub fn port2( &self ) -> MessagePort {
+        js!(
+            return @{self}.port2;
+        ).try_into().unwrap()
+    }
+}
+
+/// One of the two ports of a [MessageChannel](struct.MessageChannel.html), through
+/// which messages can be sent to the other port.
+///
+/// Incoming messages are delivered as [MessageEvent](../event/struct.MessageEvent.html)s.
+/// When listening with `add_event_listener` the port has to be [started](#method.start)
+/// before any messages are delivered.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort)
+// https://html.spec.whatwg.org/#messageport
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessagePort")]
+#[reference(subclass_of(EventTarget))]
+pub struct MessagePort( Reference );
+
+impl IEventTarget for MessagePort {}
+
+impl MessagePort {
+    /// Sends a `message` to the other port of the channel.
+    ///
+    /// Fails with a `DataCloneError` if the `message` can't be cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/postMessage)
+    // https://html.spec.whatwg.org/#dom-messageport-postmessage
+    pub fn post_message( &self, message: &Value ) -> Result< (), DataCloneError > {
+        js_try!( @(no_return)
+            @{self}.postMessage( @{message} );
+        ).unwrap()
+    }
+
+    /// Sends a `message` to the other port of the channel, and transfers the
+    /// ownership of the objects in `transfer`, such as other `MessagePort`s or
+    /// [ArrayBuffer](struct.ArrayBuffer.html)s, along with it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/postMessage)
+    // https://html.spec.whatwg.org/#dom-messageport-postmessage
+    pub fn post_message_with_transfer( &self, message: &Value, transfer: &[Reference] ) -> Result< (), DataCloneError > {
+        js_try!( @(no_return)
+            @{self}.postMessage( @{message}, @{transfer} );
+        ).unwrap()
+    }
+
+    /// Starts delivering the messages which are queued on the port.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/start)
+    // https://html.spec.whatwg.org/#dom-messageport-start
+    pub fn start( &self ) {
+        js! { @(no_return)
+            @{self}.start();
+        }
+    }
+
+    /// Disconnects the port, so that it's no longer active.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/close)
+    // https://html.spec.whatwg.org/#dom-messageport-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_channel() {
+        let ( port1, port2 ) = MessageChannel::new();
+        assert_ne!( port1, port2 );
+
+        port1.post_message( &"Hello".into() ).unwrap();
+        let ( other_port, _ ) = MessageChannel::new();
+        port1.post_message_with_transfer( &Value::Null, &[ other_port.as_ref().clone() ] ).unwrap();
+        assert!( port1.post_message_with_transfer( &Value::Null, &[ port1.as_ref().clone() ] ).is_err() );
+
+        let error: Value = js!( return function() {}; );
+        assert!( port1.post_message( &error ).is_err() );
+
+        port1.close();
+        port2.close();
+    }
+}
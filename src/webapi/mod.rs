@@ -1,34 +1,68 @@
 pub mod global;
+pub mod css;
+pub mod css_style_sheet;
 pub mod cross_origin_setting;
 pub mod date;
+pub mod intl;
 pub mod document;
+pub mod cookies;
 pub mod window;
+pub mod window_proxy;
+pub mod message_port;
 pub mod event;
 pub mod event_target;
 pub mod node;
+pub mod range;
+pub mod selection;
 pub mod element;
+pub mod element_builder;
+pub mod animation;
 pub mod html_element;
 pub mod html_elements;
 pub mod window_or_worker;
 pub mod token_list;
 pub mod document_fragment;
 pub mod text_node;
+pub mod character_data;
+pub mod comment;
 pub mod node_list;
 pub mod string_map;
 pub mod location;
+pub mod navigator;
+pub mod clipboard;
+pub mod permissions;
+pub mod service_worker;
+pub mod cache_storage;
+pub mod battery;
+pub mod screen;
+pub mod render_loop;
+pub mod hotkey;
+pub mod performance;
+pub mod media_query_list;
 pub mod storage;
 pub mod blob;
+pub mod url;
+pub mod url_components;
+pub mod js_module;
 pub mod file;
 pub mod file_list;
 pub mod file_reader;
 pub mod array_buffer;
 pub mod typed_array;
+pub mod data_view;
 /// A module containing XMLHttpRequest and its ReadyState
 pub mod xml_http_request;
+pub mod readable_stream;
+pub mod response;
 pub mod history;
 pub mod web_socket;
+pub mod rtc;
 pub mod rendering_context;
+pub mod image_bitmap;
+pub mod offscreen_canvas;
+pub mod webgl_rendering_context;
 pub mod mutation_observer;
+pub mod tree_walker;
 pub mod error;
 pub mod dom_exception;
 pub mod events;
@@ -0,0 +1,267 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::blob::Blob;
+use webapi::array_buffer::ArrayBuffer;
+use webapi::clipboard::Clipboard;
+use webapi::gamepad::Gamepad;
+use webapi::permissions::Permissions;
+use webapi::service_worker::ServiceWorkerContainer;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::battery::BatteryManager;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// The body of a beacon sent with [`Navigator::send_beacon`](struct.Navigator.html#method.send_beacon).
+#[derive(Clone, Copy, Debug)]
+pub enum BeaconData< 'a > {
+    /// A string, sent as `text/plain`.
+    String( &'a str ),
+    /// A `Blob`, sent with the blob's type.
+    Blob( &'a Blob ),
+    /// An `ArrayBuffer`, sent as binary data.
+    ArrayBuffer( &'a ArrayBuffer ),
+    /// A byte slice, sent as binary data.
+    Bytes( &'a [u8] )
+}
+
+/// The `Navigator` interface represents the state and the identity of the user agent.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator)
+// https://html.spec.whatwg.org/#navigator
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Navigator")]
+pub struct Navigator( Reference );
+
+/// A global instance of [Navigator](struct.Navigator.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/navigator)
+pub fn navigator() -> Navigator {
+    unsafe { js!( return navigator; ).into_reference_unchecked() }.unwrap()
+}
+
+impl Navigator {
+    /// Returns the user agent string of the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorID/userAgent)
+    // https://html.spec.whatwg.org/#dom-navigator-useragent
+    pub fn user_agent( &self ) -> String {
+        js!(
+            return @{self}.userAgent;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the name of the platform the browser runs on, such as `"Win32"` or `"Linux x86_64"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorID/platform)
+    // https://html.spec.whatwg.org/#dom-navigator-platform
+    pub fn platform( &self ) -> String {
+        js!(
+            return @{self}.platform;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the preferred language of the user as a BCP 47 language tag, such as `"en-US"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorLanguage/language)
+    // https://html.spec.whatwg.org/#dom-navigator-language
+    pub fn language( &self ) -> String {
+        js!(
+            return @{self}.language;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the languages preferred by the user as BCP 47 language tags, most preferred first.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorLanguage/languages)
+    // https://html.spec.whatwg.org/#dom-navigator-languages
+    pub fn languages( &self ) -> Vec< String > {
+        js!(
+            return Array.from( @{self}.languages );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of logical processors which are available to run threads,
+    /// such as web workers, at the same time.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorConcurrentHardware/hardwareConcurrency)
+    // https://html.spec.whatwg.org/#dom-navigator-hardwareconcurrency
+    pub fn hardware_concurrency( &self ) -> u32 {
+        js!(
+            return @{self}.hardwareConcurrency;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the maximum number of simultaneous touch points supported by the device.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/maxTouchPoints)
+    // https://w3c.github.io/pointerevents/#dom-navigator-maxtouchpoints
+    pub fn max_touch_points( &self ) -> u32 {
+        js!(
+            return @{self}.maxTouchPoints;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the online status of the browser.
+    ///
+    /// When this returns `false` the browser is definitely offline; a `true`
+    /// value doesn't necessarily mean that the browser can actually access
+    /// the Internet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorOnLine/onLine)
+    // https://html.spec.whatwg.org/#dom-navigator-online
+    pub fn on_line( &self ) -> bool {
+        js!(
+            return @{self}.onLine;
+        ).try_into().unwrap()
+    }
+
+    /// Asynchronously sends a small `POST` request with the given body to the given `url`.
+    ///
+    /// Unlike a regular request a beacon is guaranteed to be sent even if the page
+    /// is being unloaded, which makes it useful for analytics.
+    ///
+    /// Returns `true` if the browser successfully queued the data for transfer, and `false`
+    /// if it didn't, for example because the data is larger than the browser allows.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/sendBeacon)
+    // https://w3c.github.io/beacon/#sendbeacon-method
+    pub fn send_beacon( &self, url: &str, data: Option< BeaconData > ) -> bool {
+        let queued = match data {
+            None => js!( return @{self}.sendBeacon( @{url} ); ),
+            Some( BeaconData::String( data ) ) => js!( return @{self}.sendBeacon( @{url}, @{data} ); ),
+            Some( BeaconData::Blob( data ) ) => js!( return @{self}.sendBeacon( @{url}, @{data} ); ),
+            Some( BeaconData::ArrayBuffer( data ) ) => js!( return @{self}.sendBeacon( @{url}, @{data} ); ),
+            Some( BeaconData::Bytes( data ) ) => js!( return @{self}.sendBeacon( @{url}, @{UnsafeTypedArray( data )} ); )
+        };
+
+        queued.try_into().unwrap()
+    }
+
+    /// Returns the [Clipboard](struct.Clipboard.html) object, which gives access to the system clipboard.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/clipboard)
+    // https://w3c.github.io/clipboard-apis/#dom-navigator-clipboard
+    pub fn clipboard( &self ) -> Clipboard {
+        js!(
+            return @{self}.clipboard;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the [Permissions](struct.Permissions.html) object, which can be used to
+    /// query the status of permissions without prompting the user.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/permissions)
+    // https://w3c.github.io/permissions/#dom-navigator-permissions
+    pub fn permissions( &self ) -> Permissions {
+        js!(
+            return @{self}.permissions;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the [ServiceWorkerContainer](struct.ServiceWorkerContainer.html), which
+    /// can be used to register service workers and communicate with them.
+    ///
+    /// Returns `None` when service workers aren't available, for example
+    /// in insecure contexts.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/serviceWorker)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker
+    pub fn service_worker( &self ) -> Option< ServiceWorkerContainer > {
+        js!(
+            return @{self}.serviceWorker;
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the [BatteryManager](struct.BatteryManager.html)
+    /// of the system.
+    ///
+    /// Only some browsers support this API; in the others the future fails with an error.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getBattery)
+    // https://w3c.github.io/battery/#dom-navigator-getbattery
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn get_battery( &self ) -> PromiseFuture< BatteryManager, Error > {
+        js!(
+            var navigator = @{self};
+            if( typeof navigator.getBattery !== "function" ) {
+                return Promise.reject( new Error( "The Battery Status API is not supported" ) );
+            }
+            return navigator.getBattery();
+        ).try_into().unwrap()
+    }
+
+    /// Vibrates the device with the given pattern of alternating vibration and
+    /// pause durations, in milliseconds. A single duration vibrates once.
+    ///
+    /// An empty `pattern` cancels any ongoing vibration. Returns `false` if the
+    /// pattern is invalid or vibration isn't supported.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/vibrate)
+    // https://w3c.github.io/vibration/#vibrate-method
+    pub fn vibrate( &self, pattern: &[u32] ) -> bool {
+        js!(
+            var navigator = @{self};
+            if( typeof navigator.vibrate !== "function" ) {
+                return false;
+            }
+            return navigator.vibrate( @{pattern} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a snapshot of all gamepads, in an array indexed by each gamepad's
+    /// [index](struct.Gamepad.html#method.index); slots of disconnected gamepads are `None`.
+    ///
+    /// Gamepad state isn't updated live in every browser, so this should be called
+    /// again on every frame. This is the same as [Gamepad::get_all](struct.Gamepad.html#method.get_all).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getGamepads)
+    // https://w3c.github.io/gamepad/#dom-navigator-getgamepads
+    pub fn get_gamepads( &self ) -> Vec< Option< Gamepad > > {
+        js!(
+            return Array.from( @{self}.getGamepads() );
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_worker() {
+        let available: bool = js!( return "serviceWorker" in navigator; ).try_into().unwrap();
+        assert_eq!( navigator().service_worker().is_some(), available );
+    }
+
+    #[test]
+    fn test_environment() {
+        let navigator = navigator();
+        assert!( !navigator.user_agent().is_empty() );
+        assert!( navigator.hardware_concurrency() >= 1 );
+
+        let languages = navigator.languages();
+        if !languages.is_empty() {
+            assert_eq!( languages[ 0 ], navigator.language() );
+        }
+    }
+
+    #[test]
+    fn test_send_beacon() {
+        let navigator = navigator();
+        let blob: Blob = js!( return new Blob( [ "blob" ] ); ).try_into().unwrap();
+        let buffer: ArrayBuffer = js!( return new ArrayBuffer( 4 ); ).try_into().unwrap();
+
+        assert!( navigator.send_beacon( "/beacon", None ) );
+        assert!( navigator.send_beacon( "/beacon", Some( BeaconData::String( "string" ) ) ) );
+        assert!( navigator.send_beacon( "/beacon", Some( BeaconData::Blob( &blob ) ) ) );
+        assert!( navigator.send_beacon( "/beacon", Some( BeaconData::ArrayBuffer( &buffer ) ) ) );
+        assert!( navigator.send_beacon( "/beacon", Some( BeaconData::Bytes( &[ 1, 2, 3 ] ) ) ) );
+
+        // Browsers limit the amount of data queued by beacons to 64 KiB.
+        let too_large = vec![ 0; 1024 * 1024 ];
+        assert!( !navigator.send_beacon( "/beacon", Some( BeaconData::Bytes( &too_large ) ) ) );
+    }
+}
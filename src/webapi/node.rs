@@ -84,6 +84,18 @@ pub trait INode: IEventTarget {
         ).try_into().unwrap()
     }
 
+    /// Compares the position of the given node against this node in any document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Node/compareDocumentPosition)
+    // https://dom.spec.whatwg.org/#ref-for-dom-node-comparedocumentposition
+    fn compare_document_position< T: INode >( &self, other: &T ) -> DocumentPosition {
+        let bits: u16 = js!(
+            return @{self.as_ref()}.compareDocumentPosition( @{other.as_ref()} );
+        ).try_into().unwrap();
+
+        DocumentPosition( bits )
+    }
+
     /// Inserts the specified node before the reference node as a child of the current node.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Node/insertBefore)
@@ -94,6 +106,37 @@ pub trait INode: IEventTarget {
         ).unwrap()
     }
 
+    /// Inserts the specified node before the reference node as a child of the current node,
+    /// or appends it to the current node's children if `reference_node` is `None`.
+    ///
+    /// Use [as_node](#method.as_node) to pass something other than a [Node](struct.Node.html)
+    /// as the reference node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Node/insertBefore)
+    // https://dom.spec.whatwg.org/#ref-for-dom-node-insertbefore
+    fn insert_before_or_append< T: INode >( &self, new_node: &T, reference_node: Option< &Node > ) -> Result< Node, InsertNodeError > {
+        js_try! (
+            return @{self.as_ref()}.insertBefore( @{new_node.as_ref()}, @{reference_node} );
+        ).unwrap()
+    }
+
+    /// Moves this node to be a child of `new_parent`, before the `before` node
+    /// or at the end if `before` is `None`.
+    ///
+    /// Unlike calling [insert_before_or_append](#method.insert_before_or_append) directly,
+    /// this first checks that `new_parent` isn't this node or one of its descendants,
+    /// and returns [ReparentError::IntoOwnSubtree](enum.ReparentError.html#variant.IntoOwnSubtree)
+    /// instead of having the DOM throw a `HierarchyRequestError`.
+    fn reparent< P: INode >( &self, new_parent: &P, before: Option< &Node > ) -> Result< (), ReparentError > {
+        if self.contains( new_parent ) {
+            return Err( ReparentError::IntoOwnSubtree );
+        }
+
+        new_parent.insert_before_or_append( self, before )
+            .map( |_| () )
+            .map_err( ReparentError::Insert )
+    }
+
     /// Replaces one hild node of the specified node with another.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Node/replaceChild)
@@ -343,10 +386,37 @@ pub trait INode: IEventTarget {
     }
 }
 
-/// Errors thrown by `Node` insertion methods.
 error_enum_boilerplate! {
+    /// Errors thrown by `Node` insertion methods.
     InsertNodeError,
-    NotFoundError, HierarchyRequestError
+    /// The reference node or the node to be replaced is not a child of this node.
+    NotFoundError,
+    /// The node can't be inserted at this position, for example into its own subtree.
+    HierarchyRequestError
+}
+
+/// Errors returned by [INode::reparent](trait.INode.html#method.reparent).
+#[derive(Clone, Debug)]
+pub enum ReparentError {
+    /// The new parent is the node being moved or one of its descendants.
+    IntoOwnSubtree,
+    /// The DOM refused to insert the node.
+    Insert( InsertNodeError )
+}
+
+impl ::std::fmt::Display for ReparentError {
+    fn fmt( &self, formatter: &mut ::std::fmt::Formatter ) -> ::std::fmt::Result {
+        match *self {
+            ReparentError::IntoOwnSubtree => write!( formatter, "a node can't be moved into its own subtree" ),
+            ReparentError::Insert( ref error ) => error.fmt( formatter )
+        }
+    }
+}
+
+impl ::std::error::Error for ReparentError {
+    fn description( &self ) -> &str {
+        "ReparentError"
+    }
 }
 
 /// A reference to a JavaScript object which implements the [INode](trait.INode.html)
@@ -438,6 +508,51 @@ pub enum NodeType {
     XmlNotation,
 }
 
+/// The position of a node relative to another node, as returned by
+/// [INode::compare_document_position](trait.INode.html#method.compare_document_position).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Node/compareDocumentPosition)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DocumentPosition( u16 );
+
+impl DocumentPosition {
+    /// Returns whether the nodes are not in the same tree.
+    pub fn is_disconnected( &self ) -> bool {
+        self.0 & 0x01 != 0
+    }
+
+    /// Returns whether the other node precedes this node.
+    pub fn is_preceding( &self ) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    /// Returns whether the other node follows this node.
+    pub fn is_following( &self ) -> bool {
+        self.0 & 0x04 != 0
+    }
+
+    /// Returns whether the other node is an ancestor of this node.
+    pub fn contains( &self ) -> bool {
+        self.0 & 0x08 != 0
+    }
+
+    /// Returns whether the other node is a descendant of this node.
+    pub fn is_contained_by( &self ) -> bool {
+        self.0 & 0x10 != 0
+    }
+
+    /// Returns whether the ordering of the nodes is implementation specific,
+    /// which is only the case for disconnected nodes.
+    pub fn is_implementation_specific( &self ) -> bool {
+        self.0 & 0x20 != 0
+    }
+
+    /// Returns whether both nodes are the same node.
+    pub fn is_same_node( &self ) -> bool {
+        self.0 == 0
+    }
+}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -585,6 +700,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_insert_before_or_append() {
+        let node = div();
+        let child1 = div();
+        let child2 = div();
+        let child3 = div();
+        node.insert_before_or_append(&child1, None).unwrap();
+        node.insert_before_or_append(&child2, None).unwrap();
+        assert_eq!(node.first_child().unwrap().as_ref(), child1.as_ref());
+        assert_eq!(node.last_child().unwrap().as_ref(), child2.as_ref());
+
+        node.insert_before_or_append(&child3, Some(&child2)).unwrap();
+        assert_eq!(child1.next_sibling().unwrap().as_ref(), child3.as_ref());
+        assert_eq!(node.last_child().unwrap().as_ref(), child2.as_ref());
+
+        match node.insert_before_or_append(&div(), Some(&div())) {
+            Err(InsertNodeError::NotFoundError(_)) => (),
+            _ => panic!("Expected NotFoundError")
+        }
+    }
+
     #[test]
     fn test_replace_child() {
         let node = div();
@@ -606,6 +742,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reparent() {
+        let node = div();
+        let child1 = div();
+        let child2 = div();
+        let grandchild = div();
+        node.append_child(&child1);
+        node.append_child(&child2);
+        child1.append_child(&grandchild);
+
+        grandchild.reparent(&node, Some(&child2)).unwrap();
+        assert_eq!(child1.next_sibling().unwrap().as_ref(), grandchild.as_ref());
+        child1.reparent(&child2, None).unwrap();
+        assert_eq!(child1.parent_node().unwrap().as_ref(), child2.as_ref());
+
+        match node.reparent(&child1, None) {
+            Err(ReparentError::IntoOwnSubtree) => (),
+            _ => panic!("Expected IntoOwnSubtree")
+        }
+
+        match child2.reparent(&child2, None) {
+            Err(ReparentError::IntoOwnSubtree) => (),
+            _ => panic!("Expected IntoOwnSubtree")
+        }
+
+        match div().reparent(&node, Some(&div())) {
+            Err(ReparentError::Insert(InsertNodeError::NotFoundError(_))) => (),
+            _ => panic!("Expected NotFoundError")
+        }
+    }
+
     #[test]
     fn test_parent_node() {
         let node = div();
@@ -842,6 +1009,34 @@ mod tests {
         assert!( empty_opt_node.is_err() );
     }
 
+    #[test]
+    fn compare_document_position() {
+        let parent = div();
+        let first = div();
+        let second = div();
+        parent.append_child(&first);
+        parent.append_child(&second);
+
+        let position = first.compare_document_position(&second);
+        assert!(position.is_following());
+        assert!(!position.is_preceding());
+
+        let position = second.compare_document_position(&first);
+        assert!(position.is_preceding());
+
+        let position = first.compare_document_position(&parent);
+        assert!(position.contains());
+        assert!(position.is_preceding());
+
+        let position = parent.compare_document_position(&first);
+        assert!(position.is_contained_by());
+        assert!(position.is_following());
+        assert!(parent.contains(&first));
+
+        assert!(first.compare_document_position(&first).is_same_node());
+        assert!(first.compare_document_position(&div()).is_disconnected());
+    }
+
     #[test]
     fn from_html() {
         let node = Node::from_html("<div>Some text, horray!</div>").unwrap();
@@ -0,0 +1,141 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::image_bitmap::ImageBitmap;
+use webapi::dom_exception::InvalidStateError;
+
+/// Trait implemented by rendering contexts which can be obtained from an
+/// [`OffscreenCanvas`](struct.OffscreenCanvas.html).
+pub trait OffscreenRenderingContext {
+    /// Type of error which can occur whilst creating this context
+    type Error;
+    /// Obtains this kind of rendering context from the given offscreen canvas.
+    fn from_offscreen_canvas( canvas: &OffscreenCanvas ) -> Result< Self, Self::Error > where Self: Sized;
+}
+
+/// A canvas which can be rendered off screen, for example inside of a worker.
+///
+/// An `OffscreenCanvas` can either be created on its own with [`OffscreenCanvas::new`](#method.new),
+/// in which case its contents can be displayed with [`transfer_to_image_bitmap`](#method.transfer_to_image_bitmap),
+/// or it can be obtained from a `<canvas>` element with
+/// [`CanvasElement::transfer_control_to_offscreen`](struct.CanvasElement.html#method.transfer_control_to_offscreen),
+/// in which case everything drawn on it is automatically displayed by that element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas)
+// https://html.spec.whatwg.org/#the-offscreencanvas-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "OffscreenCanvas")]
+#[reference(subclass_of(EventTarget))]
+pub struct OffscreenCanvas( Reference );
+
+impl IEventTarget for OffscreenCanvas {}
+
+impl OffscreenCanvas {
+    /// Creates a new `OffscreenCanvas` with the given dimensions.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/OffscreenCanvas)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas
+    pub fn new( width: u32, height: u32 ) -> Self {
+        js!(
+            return new OffscreenCanvas( @{width}, @{height} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the height of the canvas in pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/height)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas-height
+    pub fn height( &self ) -> u32 {
+        js!(
+            return @{self}.height;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the height of the canvas in pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/height)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas-height
+    pub fn set_height( &self, value: u32 ) {
+        js! { @(no_return)
+            @{self}.height = @{value};
+        }
+    }
+
+    /// Returns the width of the canvas in pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/width)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas-width
+    pub fn width( &self ) -> u32 {
+        js!(
+            return @{self}.width;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the width of the canvas in pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/width)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas-width
+    pub fn set_width( &self, value: u32 ) {
+        js! { @(no_return)
+            @{self}.width = @{value};
+        }
+    }
+
+    /// Returns a drawing context on the canvas.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/getContext)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas-getcontext
+    pub fn get_context< T: OffscreenRenderingContext >( &self ) -> Result< T, T::Error > {
+        T::from_offscreen_canvas( self )
+    }
+
+    /// Creates an [`ImageBitmap`](struct.ImageBitmap.html) from the most recently rendered
+    /// image of the canvas. The canvas is then given a new blank image.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas/transferToImageBitmap)
+    // https://html.spec.whatwg.org/#dom-offscreencanvas-transfertoimagebitmap
+    pub fn transfer_to_image_bitmap( &self ) -> Result< ImageBitmap, InvalidStateError > {
+        js_try!(
+            return @{self}.transferToImageBitmap();
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::rendering_context::CanvasRenderingContext2d;
+    use webapi::webgl_rendering_context::WebGlRenderingContext;
+
+    #[test]
+    fn test_2d_context() {
+        let canvas = OffscreenCanvas::new( 30, 20 );
+        assert_eq!( canvas.width(), 30 );
+        assert_eq!( canvas.height(), 20 );
+
+        let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        context.fill_rect( 0.0, 0.0, 10.0, 10.0 );
+
+        let bitmap = canvas.transfer_to_image_bitmap().unwrap();
+        assert_eq!( bitmap.width(), 30 );
+        assert_eq!( bitmap.height(), 20 );
+    }
+
+    #[test]
+    fn test_webgl_context() {
+        let canvas = OffscreenCanvas::new( 16, 8 );
+        let context: WebGlRenderingContext = match canvas.get_context() {
+            Ok( context ) => context,
+            // WebGL isn't available everywhere, for example in headless browsers without a GPU.
+            Err( _ ) => return
+        };
+
+        assert_eq!( context.drawing_buffer_width(), 16 );
+        assert_eq!( context.drawing_buffer_height(), 8 );
+        context.clear_color( 1.0, 0.0, 0.0, 1.0 );
+        context.clear( WebGlRenderingContext::COLOR_BUFFER_BIT );
+
+        let bitmap = canvas.transfer_to_image_bitmap().unwrap();
+        assert_eq!( bitmap.width(), 16 );
+    }
+}
@@ -1,4 +1,5 @@
 use webcore::reference_type::ReferenceType;
+use webcore::try_from::TryInto;
 use webapi::node_list::NodeList;
 use webapi::element::Element;
 use private::TODO;
@@ -34,4 +35,74 @@ pub trait IParentNode: ReferenceType {
             Ok( js!( return @{self.as_ref()}.querySelectorAll( @{selector} ); ).into_reference_unchecked().unwrap() )
         }
     }
+
+    /// Returns the children of this node which are elements, skipping
+    /// text and comment nodes, unlike [child_nodes](trait.INode.html#method.child_nodes).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ParentNode/children)
+    // https://dom.spec.whatwg.org/#dom-parentnode-children
+    fn children( &self ) -> Vec< Element > {
+        js!(
+            return Array.prototype.slice.call( @{self.as_ref()}.children );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the first child of this node which is an element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ParentNode/firstElementChild)
+    // https://dom.spec.whatwg.org/#dom-parentnode-firstelementchild
+    fn first_element_child( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.firstElementChild;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the last child of this node which is an element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ParentNode/lastElementChild)
+    // https://dom.spec.whatwg.org/#dom-parentnode-lastelementchild
+    fn last_element_child( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.lastElementChild;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of children of this node which are elements.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ParentNode/childElementCount)
+    // https://dom.spec.whatwg.org/#dom-parentnode-childelementcount
+    fn child_element_count( &self ) -> u32 {
+        js!(
+            return @{self.as_ref()}.childElementCount;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::node::INode;
+
+    #[test]
+    fn test_element_children() {
+        let parent = document().create_element( "div" ).unwrap();
+        assert!( parent.children().is_empty() );
+        assert!( parent.first_element_child().is_none() );
+        assert!( parent.last_element_child().is_none() );
+
+        let first = document().create_element( "span" ).unwrap();
+        let last = document().create_element( "b" ).unwrap();
+        parent.append_child( &document().create_text_node( " " ) );
+        parent.append_child( &first );
+        parent.append_child( &document().create_text_node( " " ) );
+        parent.append_child( &last );
+        parent.append_child( &document().create_comment( "" ) );
+
+        assert_eq!( parent.child_nodes().len(), 5 );
+        assert_eq!( parent.children(), vec![ first.clone(), last.clone() ] );
+        assert_eq!( parent.first_element_child().unwrap(), first );
+        assert_eq!( parent.last_element_child().unwrap(), last );
+        assert_eq!( parent.child_element_count(), 2 );
+    }
 }
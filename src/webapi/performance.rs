@@ -0,0 +1,323 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::reference_type::ReferenceType;
+
+/// The `Performance` interface provides access to performance-related information
+/// about the current page.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance)
+// https://w3c.github.io/hr-time/#sec-performance
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Performance")]
+pub struct Performance( Reference );
+
+impl Performance {
+    /// Returns the number of milliseconds elapsed since the time origin of the page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/now)
+    // https://w3c.github.io/hr-time/#dom-performance-now
+    pub fn now( &self ) -> f64 {
+        js!(
+            return @{self}.now();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time at which the page started loading, in milliseconds
+    /// since the UNIX epoch.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/timeOrigin)
+    // https://w3c.github.io/hr-time/#dom-performance-timeorigin
+    pub fn time_origin( &self ) -> f64 {
+        js!(
+            return @{self}.timeOrigin;
+        ).try_into().unwrap()
+    }
+
+    /// Returns all the performance entries of the given type, such as `"resource"`,
+    /// `"navigation"`, `"mark"` or `"measure"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/getEntriesByType)
+    // https://w3c.github.io/performance-timeline/#dom-performance-getentriesbytype
+    pub fn get_entries_by_type( &self, entry_type: &str ) -> Vec< PerformanceEntry > {
+        js!(
+            return @{self}.getEntriesByType( @{entry_type} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the timing information of every resource loaded by the page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Resource_Timing_API)
+    // https://w3c.github.io/resource-timing/#sec-performanceresourcetiming
+    pub fn get_resource_entries( &self ) -> Vec< PerformanceResourceTiming > {
+        js!(
+            return @{self}.getEntriesByType( "resource" );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the timing information of the navigation to the current page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming)
+    // https://w3c.github.io/navigation-timing/#sec-PerformanceNavigationTiming
+    pub fn get_navigation_entries( &self ) -> Vec< PerformanceNavigationTiming > {
+        js!(
+            return @{self}.getEntriesByType( "navigation" );
+        ).try_into().unwrap()
+    }
+}
+
+/// The `IPerformanceEntry` interface represents a single performance metric.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry)
+// https://w3c.github.io/performance-timeline/#the-performanceentry-interface
+pub trait IPerformanceEntry: ReferenceType {
+    /// Returns the name of the entry; for resources this is the resource's URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/name)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-name
+    #[inline]
+    fn name( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.name;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the type of the entry, for example `"resource"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/entryType)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-entrytype
+    #[inline]
+    fn entry_type( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.entryType;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time at which the entry started, in milliseconds since the time origin.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/startTime)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-starttime
+    #[inline]
+    fn start_time( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.startTime;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the duration of the entry, in milliseconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/duration)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-duration
+    #[inline]
+    fn duration( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.duration;
+        ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the
+/// [IPerformanceEntry](trait.IPerformanceEntry.html) interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry)
+// https://w3c.github.io/performance-timeline/#the-performanceentry-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceEntry")]
+pub struct PerformanceEntry( Reference );
+
+impl IPerformanceEntry for PerformanceEntry {}
+
+/// The `IPerformanceResourceTiming` interface provides detailed network timing
+/// information about the loading of a resource.
+///
+/// All the timestamps are in milliseconds since the time origin, and are `0.0` when
+/// they're not available, for example for cross-origin resources.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming)
+// https://w3c.github.io/resource-timing/#sec-performanceresourcetiming
+pub trait IPerformanceResourceTiming: IPerformanceEntry {
+    /// Returns the type of the element or API which initiated the load, for example `"img"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/initiatorType)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-initiatortype
+    #[inline]
+    fn initiator_type( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.initiatorType;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started to fetch the resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/fetchStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-fetchstart
+    #[inline]
+    fn fetch_start( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.fetchStart;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started requesting the resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/requestStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-requeststart
+    #[inline]
+    fn request_start( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.requestStart;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately after the browser received the first byte of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/responseStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-responsestart
+    #[inline]
+    fn response_start( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.responseStart;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately after the browser received the last byte of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/responseEnd)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-responseend
+    #[inline]
+    fn response_end( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.responseEnd;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the size of the fetched resource in bytes, including the response headers.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/transferSize)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-transfersize
+    #[inline]
+    fn transfer_size( &self ) -> u64 {
+        js!(
+            return @{self.as_ref()}.transferSize;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the size of the payload body of the resource in bytes, before removing
+    /// any applied content encodings.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/encodedBodySize)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-encodedbodysize
+    #[inline]
+    fn encoded_body_size( &self ) -> u64 {
+        js!(
+            return @{self.as_ref()}.encodedBodySize;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the size of the payload body of the resource in bytes, after removing
+    /// any applied content encodings.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/decodedBodySize)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-decodedbodysize
+    #[inline]
+    fn decoded_body_size( &self ) -> u64 {
+        js!(
+            return @{self.as_ref()}.decodedBodySize;
+        ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the
+/// [IPerformanceResourceTiming](trait.IPerformanceResourceTiming.html) interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming)
+// https://w3c.github.io/resource-timing/#sec-performanceresourcetiming
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceResourceTiming")]
+#[reference(subclass_of(PerformanceEntry))]
+pub struct PerformanceResourceTiming( Reference );
+
+impl IPerformanceEntry for PerformanceResourceTiming {}
+impl IPerformanceResourceTiming for PerformanceResourceTiming {}
+
+/// The `PerformanceNavigationTiming` interface provides timing information
+/// about the navigation to the current document.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming)
+// https://w3c.github.io/navigation-timing/#sec-PerformanceNavigationTiming
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceNavigationTiming")]
+#[reference(subclass_of(PerformanceEntry, PerformanceResourceTiming))]
+pub struct PerformanceNavigationTiming( Reference );
+
+impl IPerformanceEntry for PerformanceNavigationTiming {}
+impl IPerformanceResourceTiming for PerformanceNavigationTiming {}
+
+impl PerformanceNavigationTiming {
+    /// Returns the time at which the document became interactive.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming/domInteractive)
+    // https://w3c.github.io/navigation-timing/#dom-performancenavigationtiming-dominteractive
+    pub fn dom_interactive( &self ) -> f64 {
+        js!(
+            return @{self}.domInteractive;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time right after the `DOMContentLoaded` event handlers finished running.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming/domContentLoadedEventEnd)
+    // https://w3c.github.io/navigation-timing/#dom-performancenavigationtiming-domcontentloadedeventend
+    pub fn dom_content_loaded_event_end( &self ) -> f64 {
+        js!(
+            return @{self}.domContentLoadedEventEnd;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time at which the document finished loading.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming/domComplete)
+    // https://w3c.github.io/navigation-timing/#dom-performancenavigationtiming-domcomplete
+    pub fn dom_complete( &self ) -> f64 {
+        js!(
+            return @{self}.domComplete;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time right after the `load` event handlers finished running.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming/loadEventEnd)
+    // https://w3c.github.io/navigation-timing/#dom-performancenavigationtiming-loadeventend
+    pub fn load_event_end( &self ) -> f64 {
+        js!(
+            return @{self}.loadEventEnd;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the type of the navigation, one of `"navigate"`, `"reload"`,
+    /// `"back_forward"` or `"prerender"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceNavigationTiming/type)
+    // https://w3c.github.io/navigation-timing/#dom-performancenavigationtiming-type
+    pub fn kind( &self ) -> String {
+        js!(
+            return @{self}.type;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::window::window;
+
+    #[test]
+    fn test_performance() {
+        let performance = window().performance();
+        assert!( performance.now() >= 0.0 );
+        assert!( performance.time_origin() > 0.0 );
+        for entry in performance.get_resource_entries() {
+            assert_eq!( entry.entry_type(), "resource" );
+            assert!( entry.response_end() >= entry.fetch_start() );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::value::{ConversionError, Reference, Value};
+use webapi::event_target::{IEventTarget, EventTarget};
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+
+/// The state of a permission.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PermissionStatus/state)
+// https://w3c.github.io/permissions/#dom-permissionstate
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PermissionState {
+    /// The permission has been granted.
+    Granted,
+    /// The permission has been denied.
+    Denied,
+    /// Using the feature will prompt the user for the permission.
+    Prompt
+}
+
+impl TryFrom< Value > for PermissionState {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::String( state ) => match state.as_ref() {
+                "granted" => Ok( PermissionState::Granted ),
+                "denied" => Ok( PermissionState::Denied ),
+                "prompt" => Ok( PermissionState::Prompt ),
+                state => Err( ConversionError::Custom( format!( "invalid permission state \"{}\"", state ) ) )
+            },
+            _ => Err( ConversionError::type_mismatch( &value ) )
+        }
+    }
+}
+
+/// The `Permissions` interface allows to query the status of the permissions
+/// covered by the Permissions API, without prompting the user.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Permissions)
+// https://w3c.github.io/permissions/#permissions-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Permissions")]
+pub struct Permissions( Reference );
+
+impl Permissions {
+    /// Returns the status of the permission with the given `name`, e.g. `"geolocation"`
+    /// or `"notifications"`.
+    ///
+    /// The future fails with a `TypeError` if the permission isn't supported by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Permissions/query)
+    // https://w3c.github.io/permissions/#dom-permissions-query
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn query( &self, name: &str ) -> PromiseFuture< PermissionStatus, Error > {
+        js!(
+            return @{self}.query( { name: @{name} } );
+        ).try_into().unwrap()
+    }
+}
+
+/// The state of a permission, as returned by [Permissions::query](struct.Permissions.html#method.query).
+///
+/// A [ChangeEvent](event/struct.ChangeEvent.html) is fired on it whenever the
+/// state changes, e.g. when the user revokes the permission in the browser's settings.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PermissionStatus)
+// https://w3c.github.io/permissions/#permissionstatus-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PermissionStatus")]
+#[reference(subclass_of(EventTarget))]
+pub struct PermissionStatus( Reference );
+
+impl IEventTarget for PermissionStatus {}
+
+impl PermissionStatus {
+    /// Returns the current state of the permission.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PermissionStatus/state)
+    // https://w3c.github.io/permissions/#dom-permissionstatus-state
+    pub fn state( &self ) -> PermissionState {
+        js!(
+            return @{self}.state;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PermissionState;
+    use webcore::try_from::TryInto;
+    use webcore::value::{ConversionError, Value};
+
+    #[test]
+    fn test_value_into_permission_state() {
+        let to_state = |value: Value| -> Result< PermissionState, ConversionError > {
+            value.try_into()
+        };
+
+        assert_eq!( to_state( "granted".into() ), Ok( PermissionState::Granted ) );
+        assert_eq!( to_state( "denied".into() ), Ok( PermissionState::Denied ) );
+        assert_eq!( to_state( "prompt".into() ), Ok( PermissionState::Prompt ) );
+        assert!( to_state( "default".into() ).is_err() );
+        assert!( to_state( Value::Null ).is_err() );
+    }
+}
@@ -0,0 +1,208 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::node::{INode, Node};
+use webapi::document_fragment::DocumentFragment;
+use webapi::html_element::Rect;
+use webapi::dom_exception::{IndexSizeError, InvalidNodeTypeError, HierarchyRequestError};
+
+/// A fragment of a document which can contain nodes and parts of text nodes.
+///
+/// You can create one with [`Document::create_range`](struct.Document.html#method.create_range)
+/// or get the ones of the current selection with [`Selection::get_range_at`](struct.Selection.html#method.get_range_at).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range)
+// https://dom.spec.whatwg.org/#range
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Range")]
+pub struct Range( Reference );
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that [`Range::set_start`](struct.Range.html#method.set_start)
+    /// and [`Range::set_end`](struct.Range.html#method.set_end) may throw.
+    SetRangeBoundaryError,
+    /// An IndexSizeError if the offset is larger than the length of the node
+    IndexSizeError,
+    /// An InvalidNodeTypeError if the node is a doctype
+    InvalidNodeTypeError
+}
+
+impl Range {
+    /// Returns the node within which the range starts.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/startContainer)
+    // https://dom.spec.whatwg.org/#dom-range-startcontainer
+    pub fn start_container( &self ) -> Node {
+        js!(
+            return @{self}.startContainer;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the offset within the [start container](#method.start_container) at which the range starts.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/startOffset)
+    // https://dom.spec.whatwg.org/#dom-range-startoffset
+    pub fn start_offset( &self ) -> u32 {
+        js!(
+            return @{self}.startOffset;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the node within which the range ends.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/endContainer)
+    // https://dom.spec.whatwg.org/#dom-range-endcontainer
+    pub fn end_container( &self ) -> Node {
+        js!(
+            return @{self}.endContainer;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the offset within the [end container](#method.end_container) at which the range ends.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/endOffset)
+    // https://dom.spec.whatwg.org/#dom-range-endoffset
+    pub fn end_offset( &self ) -> u32 {
+        js!(
+            return @{self}.endOffset;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the start and the end of the range are at the same position.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/collapsed)
+    // https://dom.spec.whatwg.org/#dom-range-collapsed
+    pub fn collapsed( &self ) -> bool {
+        js!(
+            return @{self}.collapsed;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the start of the range to the given `offset` within `node`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/setStart)
+    // https://dom.spec.whatwg.org/#dom-range-setstart
+    pub fn set_start< T: INode >( &self, node: &T, offset: u32 ) -> Result< (), SetRangeBoundaryError > {
+        js_try!( @(no_return)
+            @{self}.setStart( @{node.as_ref()}, @{offset} );
+        ).unwrap()
+    }
+
+    /// Sets the end of the range to the given `offset` within `node`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/setEnd)
+    // https://dom.spec.whatwg.org/#dom-range-setend
+    pub fn set_end< T: INode >( &self, node: &T, offset: u32 ) -> Result< (), SetRangeBoundaryError > {
+        js_try!( @(no_return)
+            @{self}.setEnd( @{node.as_ref()}, @{offset} );
+        ).unwrap()
+    }
+
+    /// Collapses the range to its start if `to_start` is `true`, or to its end otherwise.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/collapse)
+    // https://dom.spec.whatwg.org/#dom-range-collapse
+    pub fn collapse( &self, to_start: bool ) {
+        js! { @(no_return)
+            @{self}.collapse( @{to_start} );
+        }
+    }
+
+    /// Sets the range to contain the given node and its contents.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/selectNode)
+    // https://dom.spec.whatwg.org/#dom-range-selectnode
+    pub fn select_node< T: INode >( &self, node: &T ) -> Result< (), InvalidNodeTypeError > {
+        js_try!( @(no_return)
+            @{self}.selectNode( @{node.as_ref()} );
+        ).unwrap()
+    }
+
+    /// Sets the range to contain the contents of the given node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/selectNodeContents)
+    // https://dom.spec.whatwg.org/#dom-range-selectnodecontents
+    pub fn select_node_contents< T: INode >( &self, node: &T ) -> Result< (), InvalidNodeTypeError > {
+        js_try!( @(no_return)
+            @{self}.selectNodeContents( @{node.as_ref()} );
+        ).unwrap()
+    }
+
+    /// Removes the contents of the range from the document and returns them.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/extractContents)
+    // https://dom.spec.whatwg.org/#dom-range-extractcontents
+    pub fn extract_contents( &self ) -> Result< DocumentFragment, HierarchyRequestError > {
+        js_try!(
+            return @{self}.extractContents();
+        ).unwrap()
+    }
+
+    /// Returns a copy of the contents of the range.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/cloneContents)
+    // https://dom.spec.whatwg.org/#dom-range-clonecontents
+    pub fn clone_contents( &self ) -> Result< DocumentFragment, HierarchyRequestError > {
+        js_try!(
+            return @{self}.cloneContents();
+        ).unwrap()
+    }
+
+    /// Removes the contents of the range from the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/deleteContents)
+    // https://dom.spec.whatwg.org/#dom-range-deletecontents
+    pub fn delete_contents( &self ) {
+        js! { @(no_return)
+            @{self}.deleteContents();
+        }
+    }
+
+    /// Returns the smallest rectangle which contains the whole contents of the range.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/getBoundingClientRect)
+    // https://drafts.csswg.org/cssom-view/#dom-range-getboundingclientrect
+    pub fn get_bounding_client_rect( &self ) -> Rect {
+        js!(
+            return @{self}.getBoundingClientRect();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the text contained in the range.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Range/toString)
+    // https://dom.spec.whatwg.org/#dom-range-stringifier
+    pub fn to_string( &self ) -> String {
+        js!(
+            return @{self}.toString();
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_range() {
+        let parent = document().create_element( "div" ).unwrap();
+        let text = document().create_text_node( "Hello world" );
+        parent.append_child( &text );
+
+        let range = document().create_range();
+        range.set_start( &text, 0 ).unwrap();
+        range.set_end( &text, 5 ).unwrap();
+        assert!( !range.collapsed() );
+        assert_eq!( range.to_string(), "Hello" );
+        assert_eq!( range.start_offset(), 0 );
+        assert_eq!( range.end_offset(), 5 );
+        assert!( range.set_end( &text, 100 ).is_err() );
+
+        let fragment = range.extract_contents().unwrap();
+        assert_eq!( fragment.text_content().unwrap(), "Hello" );
+        assert_eq!( parent.text_content().unwrap(), " world" );
+
+        range.select_node_contents( &parent ).unwrap();
+        assert_eq!( range.to_string(), " world" );
+    }
+}
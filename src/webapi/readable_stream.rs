@@ -0,0 +1,109 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::error::Error;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::typed_array::TypedArray;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// A readable stream of data, for example the [body](struct.Response.html#method.body) of a [Response](struct.Response.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream)
+// https://streams.spec.whatwg.org/#rs-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ReadableStream")]
+pub struct ReadableStream( Reference );
+
+/// A reader which can be used to read chunks of data out of a [ReadableStream](struct.ReadableStream.html).
+///
+/// While a reader is active the stream is locked and no other reader can be acquired.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader)
+// https://streams.spec.whatwg.org/#default-reader-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ReadableStreamDefaultReader")]
+pub struct ReadableStreamReader( Reference );
+
+impl ReadableStream {
+    /// Returns whether the stream is locked to a reader.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/locked)
+    // https://streams.spec.whatwg.org/#rs-locked
+    pub fn locked( &self ) -> bool {
+        js!(
+            return @{self}.locked;
+        ).try_into().unwrap()
+    }
+
+    /// Creates a reader and locks the stream to it.
+    ///
+    /// Returns an error if the stream is already locked to another reader.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/getReader)
+    // https://streams.spec.whatwg.org/#rs-get-reader
+    pub fn get_reader( &self ) -> Result< ReadableStreamReader, Error > {
+        js_try!(
+            return @{self}.getReader();
+        ).unwrap()
+    }
+}
+
+impl ReadableStreamReader {
+    /// Returns a future which resolves to the next chunk of the stream,
+    /// or to `None` when the end of the stream has been reached.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/read)
+    // https://streams.spec.whatwg.org/#default-reader-read
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn read( &self ) -> PromiseFuture< Option< TypedArray< u8 > > > {
+        js!(
+            return @{self}.read().then( function( result ) {
+                return result.done ? null : result.value;
+            } );
+        ).try_into().unwrap()
+    }
+
+    /// Cancels the stream; any data which wasn't read yet is discarded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/cancel)
+    // https://streams.spec.whatwg.org/#default-reader-cancel
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn cancel( &self ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.cancel();
+        ).try_into().unwrap()
+    }
+
+    /// Releases the reader's lock on the stream, so that another reader can be acquired.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/releaseLock)
+    // https://streams.spec.whatwg.org/#default-reader-release-lock
+    pub fn release_lock( &self ) {
+        js! { @(no_return)
+            @{self}.releaseLock();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::response::Response;
+
+    #[test]
+    fn test_response_body() {
+        let response: Response = js!( return new Response( "Hello" ); ).try_into().unwrap();
+        assert!( response.ok() );
+        assert_eq!( response.status(), 200 );
+
+        let body = response.body().unwrap();
+        assert!( !body.locked() );
+
+        let reader = body.get_reader().unwrap();
+        assert!( body.locked() );
+        assert!( body.get_reader().is_err() );
+
+        reader.release_lock();
+        assert!( !body.locked() );
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+
+use webapi::window::{window, RequestAnimationFrameHandle};
+use webapi::document::document;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::dom::VisibilityChangeEvent;
+
+struct LoopState {
+    running: Cell< bool >,
+    last_timestamp: Cell< Option< f64 > >,
+    frame: RefCell< Option< RequestAnimationFrameHandle > >
+}
+
+impl LoopState {
+    fn schedule< F: FnMut( f64 ) + 'static >( this: &Rc< Self >, callback: Rc< RefCell< F > > ) {
+        let weak = Rc::downgrade( this );
+        let handle = window().request_animation_frame( move |timestamp| {
+            let this = match weak.upgrade() {
+                Some( this ) => this,
+                None => return
+            };
+
+            this.frame.borrow_mut().take();
+            if !this.running.get() {
+                return;
+            }
+
+            let delta = match this.last_timestamp.replace( Some( timestamp ) ) {
+                Some( last_timestamp ) => timestamp - last_timestamp,
+                None => 0.0
+            };
+
+            ( &mut *callback.borrow_mut() )( delta );
+
+            if this.running.get() && this.frame.borrow().is_none() && !document().hidden() {
+                LoopState::schedule( &this, callback );
+            }
+        });
+
+        *this.frame.borrow_mut() = Some( handle );
+    }
+
+    fn cancel_frame( &self ) {
+        let frame = self.frame.borrow_mut().take();
+        if let Some( frame ) = frame {
+            frame.cancel();
+        }
+    }
+}
+
+/// A loop which calls a callback on every animation frame while the page is visible.
+///
+/// The callback is passed the time in milliseconds which elapsed since the previous
+/// frame. When the document becomes hidden the loop is paused; once it's visible again
+/// it resumes, with the first frame after resuming receiving a delta of `0.0` instead
+/// of the whole time the page spent in the background.
+///
+/// The loop runs until the `RenderLoop` is dropped.
+pub struct RenderLoop {
+    state: Rc< LoopState >,
+    visibility_listener: Option< EventListenerHandle >
+}
+
+impl fmt::Debug for RenderLoop {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "RenderLoop {{ active: {} }}", self.is_active() )
+    }
+}
+
+impl RenderLoop {
+    /// Starts a new render loop driven by
+    /// [request_animation_frame](struct.Window.html#method.request_animation_frame).
+    pub fn start< F: FnMut( f64 ) + 'static >( callback: F ) -> Self {
+        let state = Rc::new( LoopState {
+            running: Cell::new( true ),
+            last_timestamp: Cell::new( None ),
+            frame: RefCell::new( None )
+        });
+
+        let callback = Rc::new( RefCell::new( callback ) );
+        let weak = Rc::downgrade( &state );
+        let visibility_callback = callback.clone();
+        let visibility_listener = document().add_event_listener( move |_: VisibilityChangeEvent| {
+            let state = match weak.upgrade() {
+                Some( state ) => state,
+                None => return
+            };
+
+            if document().hidden() {
+                state.cancel_frame();
+            } else if state.running.get() && state.frame.borrow().is_none() {
+                state.last_timestamp.set( None );
+                LoopState::schedule( &state, visibility_callback.clone() );
+            }
+        });
+
+        if !document().hidden() {
+            LoopState::schedule( &state, callback );
+        }
+
+        RenderLoop {
+            state,
+            visibility_listener: Some( visibility_listener )
+        }
+    }
+
+    /// Returns whether the loop is currently waiting for the next animation frame,
+    /// that is whether it isn't paused because the page is hidden.
+    pub fn is_active( &self ) -> bool {
+        self.state.frame.borrow().is_some()
+    }
+}
+
+impl Drop for RenderLoop {
+    fn drop( &mut self ) {
+        self.state.running.set( false );
+        self.state.cancel_frame();
+        if let Some( listener ) = self.visibility_listener.take() {
+            listener.remove();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_and_drop() {
+        let render_loop = RenderLoop::start( |_| {} );
+        assert_eq!( render_loop.is_active(), !document().hidden() );
+        drop( render_loop );
+    }
+}
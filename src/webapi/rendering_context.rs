@@ -3,7 +3,12 @@ use webcore::try_from::{TryFrom, TryInto};
 use webcore::value::{Undefined, Value};
 use webapi::html_elements::{CanvasElement, ImageElement};
 use webapi::html_element::IHtmlElement;
-use webapi::dom_exception::{SyntaxError, IndexSizeError, InvalidStateError, TypeError, SecurityError, NotSupportedError};
+use webapi::image_bitmap::ImageBitmap;
+use webcore::reference_type::ReferenceType;
+use webcore::instance_of::InstanceOf;
+use webapi::offscreen_canvas::{OffscreenCanvas, OffscreenRenderingContext};
+use webapi::dom_exception::{SyntaxError, IndexSizeError, InvalidStateError, SecurityError, NotSupportedError};
+use webapi::error::TypeError;
 
 /// Trait implemented by rendering contexts which can be obtained from a canvas.
 pub trait RenderingContext {
@@ -13,14 +18,38 @@ pub trait RenderingContext {
     fn from_canvas(canvas: &CanvasElement) -> Result<Self, Self::Error> where Self: Sized;
 }
 
+/// A type which can be drawn onto a canvas with
+/// [`CanvasRenderingContext2d::draw_image`](struct.CanvasRenderingContext2d.html#method.draw_image).
+// https://html.spec.whatwg.org/#canvasimagesource
+pub trait CanvasImageSource: ReferenceType {}
+
+impl CanvasImageSource for ImageElement {}
+impl CanvasImageSource for CanvasElement {}
+impl CanvasImageSource for ImageBitmap {}
+impl CanvasImageSource for OffscreenCanvas {}
+
 /// Used for drawing rectangles, text, images and other objects onto the canvas element.
 ///
+/// The same type is used for the 2D context of an [`OffscreenCanvas`](struct.OffscreenCanvas.html).
+///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D)
 // https://html.spec.whatwg.org/#canvasrenderingcontext2d
 #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
-#[reference(instance_of = "CanvasRenderingContext2D")]
 pub struct CanvasRenderingContext2d(Reference);
 
+// This is implemented by hand so that `OffscreenCanvasRenderingContext2D`, which
+// has (nearly) the same interface, is accepted too.
+impl InstanceOf for CanvasRenderingContext2d {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        __js_raw_asm!( "\
+            var o = Module.STDWEB_PRIVATE.acquire_js_reference( $0 );\
+            return (o instanceof CanvasRenderingContext2D ||\
+                (typeof OffscreenCanvasRenderingContext2D !== \"undefined\" && o instanceof OffscreenCanvasRenderingContext2D)) | 0;\
+        ", reference.as_raw() ) == 1
+    }
+}
+
 /// The CanvasGradient struct represents an opaque object describing a gradient. 
 /// It is returned by the methods CanvasRenderingContext2D.createLinearGradient() or 
 /// CanvasRenderingContext2D.createRadialGradient().
@@ -41,6 +70,16 @@ pub struct CanvasGradient(Reference);
 #[reference(instance_of = "CanvasPattern")]
 pub struct CanvasPattern(Reference);
 
+/// The Path2D struct is used to declare a path which can then be used on a
+/// [`CanvasRenderingContext2d`](struct.CanvasRenderingContext2d.html). Building a path once
+/// lets you fill, stroke or clip with it on every frame without rebuilding it on the context.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D)
+// https://html.spec.whatwg.org/#path2d
+#[derive(Clone, Debug, Eq, PartialEq, ReferenceType)]
+#[reference(instance_of = "Path2D")]
+pub struct Path2D(Reference);
+
 /// The ImageData struct represents the underlying pixel data of an area of a `<canvas>` element.
 /// You can create a new instance by calling [`CanvasRenderingContext2d::create_image_data`](struct.CanvasRenderingContext2d.html#method.create_image_data)
 /// or [`CanvasRenderingContext2d::create_image_data_size_of`](struct.CanvasRenderingContext2d.html#method.create_image_data_size_of).
@@ -291,6 +330,15 @@ impl RenderingContext for CanvasRenderingContext2d {
     }
 }
 
+impl OffscreenRenderingContext for CanvasRenderingContext2d {
+    type Error = ConversionError;
+    fn from_offscreen_canvas(canvas: &OffscreenCanvas) -> Result<Self, ConversionError> {
+        js!(
+            return @{canvas}.getContext("2d");
+        ).try_into()
+    }
+}
+
 impl CanvasGradient {
 
     /// Adds a new stop, defined by an offset and a color, to the gradient. If the offset is 
@@ -343,11 +391,99 @@ impl ImageData {
     }
 }
 
+impl Path2D {
+    /// Creates a new empty path.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D/Path2D)
+    // https://html.spec.whatwg.org/#dom-path2d
+    pub fn new() -> Path2D {
+        js! (
+            return new Path2D();
+        ).try_into().unwrap()
+    }
+
+    /// Creates a new path from the given SVG path data, for example `"M10 10 h 80 v 80 h -80 Z"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D/Path2D)
+    // https://html.spec.whatwg.org/#dom-path2d
+    pub fn new_from_svg(path: &str) -> Path2D {
+        js! (
+            return new Path2D(@{path});
+        ).try_into().unwrap()
+    }
+
+    /// Adds an arc to the path which is centered at (x, y) position with radius r starting 
+    /// at startAngle and ending at endAngle going in the given direction by anticlockwise.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/arc)
+    // https://html.spec.whatwg.org/#dom-context-2d-arc
+    pub fn arc(&self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64, anticlockwise: bool) {
+        js! { @(no_return)
+            @{&self.0}.arc(@{x}, @{y}, @{radius}, @{start_angle}, @{end_angle}, @{anticlockwise});
+        }
+    }
+
+    /// Adds a cubic Bézier curve to the path.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/bezierCurveTo)
+    // https://html.spec.whatwg.org/#dom-context-2d-beziercurveto
+    pub fn bezier_curve_to(&self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        js! { @(no_return)
+            @{&self.0}.bezierCurveTo(@{cp1x}, @{cp1y}, @{cp2x}, @{cp2y}, @{x}, @{y});
+        }
+    }
+
+    /// Causes the point of the pen to move back to the start of the current sub-path.
+    /// If the shape has already been closed or has only one point, this function does nothing.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/closePath)
+    // https://html.spec.whatwg.org/#dom-context-2d-closepath
+    pub fn close_path(&self) {
+        js! { @(no_return)
+            @{&self.0}.closePath();
+        }
+    }
+
+    /// Connects the last point in the sub-path to the x, y coordinates with a straight line.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/lineTo)
+    // https://html.spec.whatwg.org/#dom-context-2d-lineto
+    pub fn line_to(&self, x: f64, y: f64) {
+        js! { @(no_return)
+            @{&self.0}.lineTo(@{x}, @{y});
+        }
+    }
+
+    /// Moves the starting point of a new sub-path to the (x, y) coordinates.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/moveTo)
+    // https://html.spec.whatwg.org/#dom-context-2d-moveto
+    pub fn move_to(&self, x: f64, y: f64) {
+        js! { @(no_return)
+            @{&self.0}.moveTo(@{x}, @{y});
+        }
+    }
+
+    /// Adds a closed rectangle sub-path at position (x, y) with the given width and height.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/rect)
+    // https://html.spec.whatwg.org/#dom-context-2d-rect
+    pub fn rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        js! { @(no_return)
+            @{&self.0}.rect(@{x}, @{y}, @{width}, @{height});
+        }
+    }
+}
+
 impl CanvasRenderingContext2d {
     
     /// The CanvasRenderingContext2D.canvas property is a read-only reference to the HTMLCanvasElement 
     /// object that is associated with the context. It might be null if there is no association with an <canvas> element.
     /// 
+    /// # Panics
+    ///
+    /// This panics if the context belongs to an [`OffscreenCanvas`](struct.OffscreenCanvas.html).
+    ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/canvas)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-canvas
     pub fn get_canvas(&self) -> CanvasElement {
@@ -929,7 +1065,8 @@ impl CanvasRenderingContext2d {
     }
 
     /// Turns the path currently being built into the current clipping path.
-    /// ctx.clip(path, fillRule) is not supported because [(Path2D)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D) is still experimental
+    ///
+    /// Use [`clip_path`](#method.clip_path) to clip with a [`Path2D`](struct.Path2D.html) instead.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/clip)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-clip
@@ -940,6 +1077,17 @@ impl CanvasRenderingContext2d {
         }
     }
 
+    /// Turns the given path into the current clipping path.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/clip)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-clip
+    pub fn clip_path(&self, path: &Path2D, fill_rule: FillRule) {
+        let fill_rule_str = fill_rule_to_str(fill_rule);
+        js! { @(no_return)
+            @{&self.0}.clip(@{path}, @{fill_rule_str});
+        }
+    }
+
     /// Causes the point of the pen to move back to the start of the current sub-path. It tries 
     /// to add a straight line (but does not actually draw it) from the current point to the start. 
     /// If the shape has already been closed or has only one point, this function does nothing.
@@ -1034,42 +1182,48 @@ impl CanvasRenderingContext2d {
         }
     }
 
-    /// Provides different ways to draw an image onto the canvas.
+    /// Draws the whole `image` onto the canvas at (`dx`, `dy`), using the image's natural size.
+    ///
+    /// The `image` can be an [`ImageElement`](struct.ImageElement.html), a [`CanvasElement`](struct.CanvasElement.html)
+    /// or an [`ImageBitmap`](struct.ImageBitmap.html).
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/drawImage)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-drawimage
-    pub fn draw_image(&self, image: ImageElement, dx: f64, dy: f64) -> Result<(), DrawImageError> {
+    pub fn draw_image< T: CanvasImageSource >(&self, image: T, dx: f64, dy: f64) -> Result<(), DrawImageError> {
         js_try! (@(no_return)
-            @{&self.0}.drawImage(@{image}, @{dx}, @{dy});
+            @{&self.0}.drawImage(@{image.as_ref()}, @{dx}, @{dy});
         ).unwrap()
     }
 
-    /// Provides different ways to draw an image onto the canvas.
+    /// Draws the whole `image` onto the canvas at (`dx`, `dy`), scaled to `d_width` by `d_height`.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/drawImage)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-drawimage
-    pub fn draw_image_d(&self, image: ImageElement, dx: f64, dy: f64, d_width: f64, d_height: f64) -> Result<(), DrawImageError> {
+    pub fn draw_image_d< T: CanvasImageSource >(&self, image: T, dx: f64, dy: f64, d_width: f64, d_height: f64) -> Result<(), DrawImageError> {
         js_try! (@(no_return)
-            @{&self.0}.drawImage(@{image}, @{dx}, @{dy}, @{d_width}, @{d_height});
+            @{&self.0}.drawImage(@{image.as_ref()}, @{dx}, @{dy}, @{d_width}, @{d_height});
         ).unwrap()
     }
 
-    /// Provides different ways to draw an image onto the canvas.
+    /// Draws the `s_width` by `s_height` sub-rectangle of `image` starting at (`sx`, `sy`)
+    /// onto the canvas at (`dx`, `dy`), scaled to `d_width` by `d_height`.
+    ///
+    /// This is the form to use for blitting individual frames out of a sprite sheet.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/drawImage)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-drawimage
-    pub fn draw_image_s(&self, image: ImageElement, 
+    pub fn draw_image_s< T: CanvasImageSource >(&self, image: T, 
                         sx: f64, sy: f64, s_width: f64, s_height: f64, 
                         dx: f64, dy: f64, d_width: f64, d_height: f64
                     ) -> Result<(), DrawImageError> {
         js_try!(@(no_return)
-            @{&self.0}.drawImage(@{image}, @{sx}, @{sy}, @{s_width}, @{s_height}, @{dx}, @{dy}, @{d_width}, @{d_height});
+            @{&self.0}.drawImage(@{image.as_ref()}, @{sx}, @{sy}, @{s_width}, @{s_height}, @{dx}, @{dy}, @{d_width}, @{d_height});
         ).unwrap()
     }
 
     /// Fills the current or given path with the current fill style using the non-zero or even-odd winding rule.
     /// 
-    /// ctx.fill(path, fillRule) is not supported because [(Path2D)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D) is still experimental
+    /// Use [`fill_path`](#method.fill_path) to fill a [`Path2D`](struct.Path2D.html) instead.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/fill)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-fill
@@ -1080,6 +1234,17 @@ impl CanvasRenderingContext2d {
         }    
     }
 
+    /// Fills the given path with the current fill style using the non-zero or even-odd winding rule.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/fill)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-fill
+    pub fn fill_path(&self, path: &Path2D, fill_rule: FillRule) {
+        let fill_rule_str = fill_rule_to_str(fill_rule);
+        js! { @(no_return)
+            @{&self.0}.fill(@{path}, @{fill_rule_str});
+        }
+    }
+
     /// Draws a filled rectangle whose starting point is at the coordinates (x, y) with the
     /// specified width and height and whose style is determined by the fillStyle attribute.
     ///
@@ -1136,8 +1301,7 @@ impl CanvasRenderingContext2d {
 
     /// Reports whether or not the specified point is contained in the current path.
     /// 
-    /// ctx.isPointInPath(path, x, y) and ctx.isPointInPath(path, x, y, fillRule) 
-    /// are not supported because [(Path2D)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D) is still experimental 
+    /// Use [`is_point_in_path_with`](#method.is_point_in_path_with) to test against a [`Path2D`](struct.Path2D.html) instead.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/isPointInPath)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-ispointinpath
@@ -1148,9 +1312,20 @@ impl CanvasRenderingContext2d {
         ).try_into().unwrap()
     }
 
+    /// Reports whether or not the specified point is contained in the given path.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/isPointInPath)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-ispointinpath
+    pub fn is_point_in_path_with(&self, path: &Path2D, x: f64, y: f64, fill_rule: FillRule) -> bool {
+        let fill_rule_str = fill_rule_to_str(fill_rule);
+        js! (
+            return @{&self.0}.isPointInPath(@{path}, @{x}, @{y}, @{fill_rule_str});
+        ).try_into().unwrap()
+    }
+
     /// Reports whether or not the specified point is inside the area contained by the stroking of a path.
     /// 
-    /// ctx.isPointInStroke(path, x, y) is not supported because [(Path2D)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D) is still experimental 
+    /// Use [`is_point_in_stroke_with`](#method.is_point_in_stroke_with) to test against a [`Path2D`](struct.Path2D.html) instead.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/isPointInStroke)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-ispointinstroke
@@ -1160,6 +1335,16 @@ impl CanvasRenderingContext2d {
         ).try_into().unwrap()
     }
 
+    /// Reports whether or not the specified point is inside the area contained by the stroking of the given path.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/isPointInStroke)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-ispointinstroke
+    pub fn is_point_in_stroke_with(&self, path: &Path2D, x: f64, y: f64) -> bool {
+        js! (
+            return @{&self.0}.isPointInStroke(@{path}, @{x}, @{y});
+        ).try_into().unwrap()
+    }
+
     /// Connects the last point in the sub-path to the x, y coordinates with a straight line (but does not actually draw it).
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/lineTo)
@@ -1313,7 +1498,7 @@ impl CanvasRenderingContext2d {
 
     /// Strokes the current or given path with the current stroke style using the non-zero winding rule.
     /// 
-    /// ctx.stroke(path) is not supported because [(Path2D)](https://developer.mozilla.org/en-US/docs/Web/API/Path2D) is still experimental
+    /// Use [`stroke_path`](#method.stroke_path) to stroke a [`Path2D`](struct.Path2D.html) instead.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/stroke)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-stroke
@@ -1323,6 +1508,16 @@ impl CanvasRenderingContext2d {
         }
     }
 
+    /// Strokes the given path with the current stroke style.
+    /// 
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/stroke)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-stroke
+    pub fn stroke_path(&self, path: &Path2D) {
+        js! { @(no_return)
+            @{&self.0}.stroke(@{path});
+        }
+    }
+
     /// Paints a rectangle which has a starting point at (x, y) and has a w width and an h height onto the canvas, using the current stroke style.
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/strokeRect)
@@ -1431,4 +1626,44 @@ mod test {
         let res: Result<CanvasGradient, IndexSizeError> = canvas.create_radial_gradient(100 as f64, 100 as f64, -1 as f64, 100 as f64, 100 as f64, 0 as f64);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_path_2d() {
+        let canvas = new_canvas();
+        let path = Path2D::new();
+        path.move_to(10.0, 10.0);
+        path.line_to(50.0, 10.0);
+        path.bezier_curve_to(60.0, 20.0, 60.0, 40.0, 50.0, 50.0);
+        path.arc(30.0, 30.0, 5.0, 0.0, 3.14, false);
+        path.rect(0.0, 0.0, 5.0, 5.0);
+        path.close_path();
+
+        canvas.fill_path(&path, FillRule::NonZero);
+        canvas.stroke_path(&path);
+        canvas.clip_path(&Path2D::new_from_svg("M10 10 h 80 v 80 h -80 Z"), FillRule::EvenOdd);
+    }
+
+    #[test]
+    fn test_is_point_in_path_with() {
+        let canvas = new_canvas();
+        let path = Path2D::new();
+        path.rect(10.0, 10.0, 20.0, 20.0);
+
+        assert!(canvas.is_point_in_path_with(&path, 15.0, 15.0, FillRule::NonZero));
+        assert!(!canvas.is_point_in_path_with(&path, 50.0, 50.0, FillRule::NonZero));
+        assert!(canvas.is_point_in_stroke_with(&path, 10.0, 15.0));
+        assert!(!canvas.is_point_in_stroke_with(&path, 15.0, 15.0));
+    }
+
+    #[test]
+    fn test_draw_image_from_canvas() {
+        let canvas = new_canvas();
+        let sprites: CanvasElement = document().create_element("canvas").unwrap().try_into().unwrap();
+        sprites.set_width(32);
+        sprites.set_height(16);
+
+        assert!(canvas.draw_image(sprites.clone(), 0.0, 0.0).is_ok());
+        assert!(canvas.draw_image_d(sprites.clone(), 0.0, 0.0, 64.0, 32.0).is_ok());
+        assert!(canvas.draw_image_s(sprites, 16.0, 0.0, 16.0, 16.0, 10.0, 10.0, 16.0, 16.0).is_ok());
+    }
 }
@@ -0,0 +1,43 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::readable_stream::ReadableStream;
+
+/// A reference to a JavaScript `Response` object, as returned by `fetch()`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response)
+// https://fetch.spec.whatwg.org/#response-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Response")]
+pub struct Response( Reference );
+
+impl Response {
+    /// Returns the body of the response as a stream, or `None` if the response has no body.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Body/body)
+    // https://fetch.spec.whatwg.org/#dom-body-body
+    pub fn body( &self ) -> Option< ReadableStream > {
+        js!(
+            return @{self}.body;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the status code of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/status)
+    // https://fetch.spec.whatwg.org/#dom-response-status
+    pub fn status( &self ) -> u16 {
+        js!(
+            return @{self}.status;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the status code of the response is in the range 200-299.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/ok)
+    // https://fetch.spec.whatwg.org/#dom-response-ok
+    pub fn ok( &self ) -> bool {
+        js!(
+            return @{self}.ok;
+        ).try_into().unwrap()
+    }
+}
@@ -0,0 +1,429 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::InvalidStateError;
+use webapi::error::Error;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// A STUN or TURN server used by an [RtcPeerConnection](struct.RtcPeerConnection.html)
+/// to establish a connection.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCIceServer)
+// https://w3c.github.io/webrtc-pc/#rtciceserver-dictionary
+#[derive(Clone, Debug, Default)]
+pub struct RtcIceServer< 'a > {
+    /// The URLs of the server, e.g. `"stun:stun.example.com"`.
+    pub urls: &'a [&'a str],
+    /// The username to use when the server is a TURN server.
+    pub username: Option< &'a str >,
+    /// The credential to use when the server is a TURN server.
+    pub credential: Option< &'a str >
+}
+
+/// The configuration of an [RtcPeerConnection](struct.RtcPeerConnection.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCConfiguration)
+// https://w3c.github.io/webrtc-pc/#rtcconfiguration-dictionary
+#[derive(Clone, Debug, Default)]
+pub struct RtcConfiguration< 'a > {
+    /// The servers which the ICE agent can use to gather candidates.
+    pub ice_servers: &'a [RtcIceServer< 'a >]
+}
+
+/// The type of an [RtcSessionDescription](struct.RtcSessionDescription.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCSessionDescription/type)
+// https://w3c.github.io/webrtc-pc/#dom-rtcsdptype
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RtcSdpType {
+    /// The description is an initial proposal in an offer/answer exchange.
+    Offer,
+    /// The description is a provisional answer.
+    Pranswer,
+    /// The description is the final answer in an offer/answer exchange.
+    Answer,
+    /// The description rolls back to the last stable state.
+    Rollback
+}
+
+/// A session description, describing one end of a connection.
+///
+/// It's produced by [create_offer](struct.RtcPeerConnection.html#method.create_offer) or
+/// [create_answer](struct.RtcPeerConnection.html#method.create_answer) and is meant to
+/// be sent to the remote peer over a signaling channel of your choice.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCSessionDescription)
+// https://w3c.github.io/webrtc-pc/#rtcsessiondescription-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RTCSessionDescription")]
+pub struct RtcSessionDescription( Reference );
+
+impl RtcSessionDescription {
+    /// Creates a session description, typically from one received from the remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCSessionDescription/RTCSessionDescription)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcsessiondescription-constructor
+    pub fn new( kind: RtcSdpType, sdp: &str ) -> Self {
+        js!(
+            return new RTCSessionDescription( { type: @{match kind {
+                RtcSdpType::Offer => "offer",
+                RtcSdpType::Pranswer => "pranswer",
+                RtcSdpType::Answer => "answer",
+                RtcSdpType::Rollback => "rollback"
+            }}, sdp: @{sdp} } );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the type of this description.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCSessionDescription/type)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcsessiondescription-type
+    pub fn kind( &self ) -> RtcSdpType {
+        let kind: String = js!(
+            return @{self}.type;
+        ).try_into().unwrap();
+
+        match kind.as_ref() {
+            "offer" => RtcSdpType::Offer,
+            "pranswer" => RtcSdpType::Pranswer,
+            "answer" => RtcSdpType::Answer,
+            "rollback" => RtcSdpType::Rollback,
+            other => panic!( "Unexpected RTCSdpType: {:?}", other )
+        }
+    }
+
+    /// Returns the SDP (Session Description Protocol) text of this description.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCSessionDescription/sdp)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcsessiondescription-sdp
+    pub fn sdp( &self ) -> String {
+        js!(
+            return @{self}.sdp;
+        ).try_into().unwrap()
+    }
+}
+
+/// A candidate address which can be used to establish a connection to a peer.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCIceCandidate)
+// https://w3c.github.io/webrtc-pc/#rtcicecandidate-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RTCIceCandidate")]
+pub struct RtcIceCandidate( Reference );
+
+impl RtcIceCandidate {
+    /// Creates an ICE candidate, typically from one received from the remote peer.
+    ///
+    /// At least one of `sdp_mid` and `sdp_m_line_index` must be given, otherwise
+    /// an error is returned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCIceCandidate/RTCIceCandidate)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcicecandidate-constructor
+    pub fn new( candidate: &str, sdp_mid: Option< &str >, sdp_m_line_index: Option< u16 > ) -> Result< Self, Error > {
+        js_try!(
+            return new RTCIceCandidate( {
+                candidate: @{candidate},
+                sdpMid: @{sdp_mid},
+                sdpMLineIndex: @{sdp_m_line_index}
+            } );
+        ).unwrap()
+    }
+
+    /// Returns the candidate description in the SDP `candidate-attribute` format.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCIceCandidate/candidate)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcicecandidate-candidate
+    pub fn candidate( &self ) -> String {
+        js!(
+            return @{self}.candidate;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the identification tag of the media stream this candidate is associated with.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCIceCandidate/sdpMid)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcicecandidate-sdpmid
+    pub fn sdp_mid( &self ) -> Option< String > {
+        js!(
+            return @{self}.sdpMid;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the index of the media description this candidate is associated with.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCIceCandidate/sdpMLineIndex)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcicecandidate-sdpmlineindex
+    pub fn sdp_m_line_index( &self ) -> Option< u16 > {
+        js!(
+            return @{self}.sdpMLineIndex;
+        ).try_into().unwrap()
+    }
+}
+
+/// A connection between the local computer and a remote peer.
+///
+/// Local ICE candidates are reported through the
+/// [RtcIceCandidateEvent](../event/struct.RtcIceCandidateEvent.html), and data channels
+/// opened by the remote peer through the [RtcDataChannelEvent](../event/struct.RtcDataChannelEvent.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection)
+// https://w3c.github.io/webrtc-pc/#interface-definition
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RTCPeerConnection")]
+#[reference(subclass_of(EventTarget))]
+pub struct RtcPeerConnection( Reference );
+
+impl IEventTarget for RtcPeerConnection {}
+
+impl RtcPeerConnection {
+    /// Creates a new connection using the given configuration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/RTCPeerConnection)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcpeerconnection
+    pub fn new( configuration: RtcConfiguration ) -> Result< Self, Error > {
+        let ice_servers = js!( return []; );
+        for server in configuration.ice_servers {
+            js!( @(no_return)
+                var username = @{server.username};
+                var credential = @{server.credential};
+                var server = { urls: @{server.urls} };
+                if( username !== null ) {
+                    server.username = username;
+                }
+                if( credential !== null ) {
+                    server.credential = credential;
+                }
+                @{&ice_servers}.push( server );
+            );
+        }
+
+        js_try!(
+            return new RTCPeerConnection( { iceServers: @{ice_servers} } );
+        ).unwrap()
+    }
+
+    /// Creates an offer to start a new connection to a remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createOffer)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcpeerconnection-createoffer
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn create_offer( &self ) -> PromiseFuture< RtcSessionDescription, Error > {
+        js!(
+            return @{self}.createOffer().then( function( description ) {
+                return new RTCSessionDescription( description );
+            } );
+        ).try_into().unwrap()
+    }
+
+    /// Creates an answer to an offer received from a remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createAnswer)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcpeerconnection-createanswer
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn create_answer( &self ) -> PromiseFuture< RtcSessionDescription, Error > {
+        js!(
+            return @{self}.createAnswer().then( function( description ) {
+                return new RTCSessionDescription( description );
+            } );
+        ).try_into().unwrap()
+    }
+
+    /// Changes the local description associated with the connection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/setLocalDescription)
+    // https://w3c.github.io/webrtc-pc/#dom-peerconnection-setlocaldescription
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn set_local_description( &self, description: &RtcSessionDescription ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.setLocalDescription( @{description} );
+        ).try_into().unwrap()
+    }
+
+    /// Changes the remote description associated with the connection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/setRemoteDescription)
+    // https://w3c.github.io/webrtc-pc/#dom-peerconnection-setremotedescription
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn set_remote_description( &self, description: &RtcSessionDescription ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.setRemoteDescription( @{description} );
+        ).try_into().unwrap()
+    }
+
+    /// Adds an ICE candidate received from the remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/addIceCandidate)
+    // https://w3c.github.io/webrtc-pc/#dom-peerconnection-addicecandidate
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn add_ice_candidate( &self, candidate: &RtcIceCandidate ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.addIceCandidate( @{candidate} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the local description of the connection, if one was set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/localDescription)
+    // https://w3c.github.io/webrtc-pc/#dom-peerconnection-localdescription
+    pub fn local_description( &self ) -> Option< RtcSessionDescription > {
+        js!(
+            return @{self}.localDescription;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the remote description of the connection, if one was set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/remoteDescription)
+    // https://w3c.github.io/webrtc-pc/#dom-peerconnection-remotedescription
+    pub fn remote_description( &self ) -> Option< RtcSessionDescription > {
+        js!(
+            return @{self}.remoteDescription;
+        ).try_into().unwrap()
+    }
+
+    /// Creates a new data channel with the given `label` over which
+    /// arbitrary data can be sent to the remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createDataChannel)
+    // https://w3c.github.io/webrtc-pc/#dom-peerconnection-createdatachannel
+    pub fn create_data_channel( &self, label: &str ) -> Result< RtcDataChannel, Error > {
+        js_try!(
+            var channel = @{self}.createDataChannel( @{label} );
+            channel.binaryType = "arraybuffer";
+            return channel;
+        ).unwrap()
+    }
+
+    /// Closes the connection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/close)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcpeerconnection-close
+    pub fn close( &self ) {
+        js!( @(no_return)
+            @{self}.close();
+        );
+    }
+}
+
+/// The state of an [RtcDataChannel](struct.RtcDataChannel.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel/readyState)
+// https://w3c.github.io/webrtc-pc/#dom-rtcdatachannelstate
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RtcDataChannelState {
+    /// The channel is being established.
+    Connecting,
+    /// The channel is open and can be used to send data.
+    Open,
+    /// The channel is being closed.
+    Closing,
+    /// The channel is closed.
+    Closed
+}
+
+/// A bi-directional channel between two peers over which arbitrary data can be sent.
+///
+/// Incoming data is delivered through [SocketMessageEvent](../event/struct.SocketMessageEvent.html)s;
+/// binary messages are received as an [ArrayBuffer](../struct.ArrayBuffer.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel)
+// https://w3c.github.io/webrtc-pc/#rtcdatachannel
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RTCDataChannel")]
+#[reference(subclass_of(EventTarget))]
+pub struct RtcDataChannel( Reference );
+
+impl IEventTarget for RtcDataChannel {}
+
+impl RtcDataChannel {
+    /// Returns the label of this channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel/label)
+    // https://w3c.github.io/webrtc-pc/#dom-datachannel-label
+    pub fn label( &self ) -> String {
+        js!(
+            return @{self}.label;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the state of this channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel/readyState)
+    // https://w3c.github.io/webrtc-pc/#dom-datachannel-readystate
+    pub fn ready_state( &self ) -> RtcDataChannelState {
+        let state: String = js!(
+            return @{self}.readyState;
+        ).try_into().unwrap();
+
+        match state.as_ref() {
+            "connecting" => RtcDataChannelState::Connecting,
+            "open" => RtcDataChannelState::Open,
+            "closing" => RtcDataChannelState::Closing,
+            "closed" => RtcDataChannelState::Closed,
+            other => panic!( "Unexpected RTCDataChannelState: {:?}", other )
+        }
+    }
+
+    /// Sends a text message to the remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel/send)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcdatachannel-send
+    pub fn send( &self, text: &str ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self}.send( @{text} );
+        ).unwrap()
+    }
+
+    /// Sends a binary message to the remote peer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel/send)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcdatachannel-send
+    pub fn send_bytes( &self, bytes: &[u8] ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self}.send( new Uint8Array( @{UnsafeTypedArray( bytes )} ) );
+        ).unwrap()
+    }
+
+    /// Closes the channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel/close)
+    // https://w3c.github.io/webrtc-pc/#dom-rtcdatachannel-close
+    pub fn close( &self ) {
+        js!( @(no_return)
+            @{self}.close();
+        );
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_description() {
+        let description = RtcSessionDescription::new( RtcSdpType::Offer, "" );
+        assert_eq!( description.kind(), RtcSdpType::Offer );
+        assert_eq!( description.sdp(), "" );
+    }
+
+    #[test]
+    fn test_ice_candidate() {
+        let candidate = RtcIceCandidate::new( "", Some( "0" ), None ).unwrap();
+        assert_eq!( candidate.sdp_mid(), Some( "0".to_owned() ) );
+        assert_eq!( candidate.sdp_m_line_index(), None );
+        assert!( RtcIceCandidate::new( "", None, None ).is_err() );
+    }
+
+    #[test]
+    fn test_data_channel() {
+        let connection = RtcPeerConnection::new( RtcConfiguration::default() ).unwrap();
+        let channel = connection.create_data_channel( "chat" ).unwrap();
+        assert_eq!( channel.label(), "chat" );
+        assert_eq!( channel.ready_state(), RtcDataChannelState::Connecting );
+        assert!( channel.send( "too early" ).is_err() );
+        connection.close();
+    }
+}
@@ -0,0 +1,211 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+
+/// The `Screen` interface represents the screen on which the current window is being rendered.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen)
+// https://drafts.csswg.org/cssom-view/#the-screen-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Screen")]
+#[reference(subclass_of(EventTarget))]
+pub struct Screen( Reference );
+
+impl IEventTarget for Screen {}
+
+impl Screen {
+    /// Returns the width of the screen in CSS pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/width)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-width
+    pub fn width( &self ) -> i32 {
+        js!(
+            return @{self}.width;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the height of the screen in CSS pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/height)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-height
+    pub fn height( &self ) -> i32 {
+        js!(
+            return @{self}.height;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the width of the screen in CSS pixels, minus permanent or semipermanent
+    /// user interface features displayed by the operating system, such as the taskbar.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/availWidth)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-availwidth
+    pub fn avail_width( &self ) -> i32 {
+        js!(
+            return @{self}.availWidth;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the height of the screen in CSS pixels, minus permanent or semipermanent
+    /// user interface features displayed by the operating system, such as the taskbar.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/availHeight)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-availheight
+    pub fn avail_height( &self ) -> i32 {
+        js!(
+            return @{self}.availHeight;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the color depth of the screen, in bits per pixel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/colorDepth)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-colordepth
+    pub fn color_depth( &self ) -> u32 {
+        js!(
+            return @{self}.colorDepth;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the orientation of the screen, if supported by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/orientation)
+    // https://w3c.github.io/screen-orientation/#dom-screen-orientation
+    pub fn orientation( &self ) -> Option< ScreenOrientation > {
+        js!(
+            return @{self}.orientation;
+        ).try_into().unwrap()
+    }
+}
+
+/// The orientation of a screen.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/type)
+// https://w3c.github.io/screen-orientation/#dom-orientationtype
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OrientationType {
+    /// The screen is in its primary portrait mode.
+    PortraitPrimary,
+    /// The screen is in its secondary portrait mode.
+    PortraitSecondary,
+    /// The screen is in its primary landscape mode.
+    LandscapePrimary,
+    /// The screen is in its secondary landscape mode.
+    LandscapeSecondary
+}
+
+/// An orientation to which the screen can be locked.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/lock)
+// https://w3c.github.io/screen-orientation/#dom-orientationlocktype
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OrientationLockType {
+    /// Any orientation.
+    Any,
+    /// The natural orientation of the device.
+    Natural,
+    /// Either landscape orientation.
+    Landscape,
+    /// Either portrait orientation.
+    Portrait,
+    /// Only the given orientation.
+    Exactly( OrientationType )
+}
+
+/// The `ScreenOrientation` interface provides information about the current
+/// orientation of the screen.
+///
+/// A [ChangeEvent](event/struct.ChangeEvent.html) is fired on it whenever the orientation changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation)
+// https://w3c.github.io/screen-orientation/#screenorientation-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ScreenOrientation")]
+#[reference(subclass_of(EventTarget))]
+pub struct ScreenOrientation( Reference );
+
+impl IEventTarget for ScreenOrientation {}
+
+impl ScreenOrientation {
+    /// Returns the current orientation type of the screen.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/type)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-type
+    pub fn kind( &self ) -> OrientationType {
+        let kind: String = js!(
+            return @{self}.type;
+        ).try_into().unwrap();
+
+        match kind.as_ref() {
+            "portrait-primary" => OrientationType::PortraitPrimary,
+            "portrait-secondary" => OrientationType::PortraitSecondary,
+            "landscape-primary" => OrientationType::LandscapePrimary,
+            "landscape-secondary" => OrientationType::LandscapeSecondary,
+            other => panic!( "Unexpected OrientationType: {:?}", other )
+        }
+    }
+
+    /// Returns the current orientation angle of the screen, in degrees.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/angle)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-angle
+    pub fn angle( &self ) -> u16 {
+        js!(
+            return @{self}.angle;
+        ).try_into().unwrap()
+    }
+
+    /// Locks the orientation of the screen.
+    ///
+    /// Browsers usually only allow this while the document is in fullscreen;
+    /// otherwise the future fails.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/lock)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-lock
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn lock( &self, orientation: OrientationLockType ) -> PromiseFuture< (), Error > {
+        let orientation = match orientation {
+            OrientationLockType::Any => "any",
+            OrientationLockType::Natural => "natural",
+            OrientationLockType::Landscape => "landscape",
+            OrientationLockType::Portrait => "portrait",
+            OrientationLockType::Exactly( OrientationType::PortraitPrimary ) => "portrait-primary",
+            OrientationLockType::Exactly( OrientationType::PortraitSecondary ) => "portrait-secondary",
+            OrientationLockType::Exactly( OrientationType::LandscapePrimary ) => "landscape-primary",
+            OrientationLockType::Exactly( OrientationType::LandscapeSecondary ) => "landscape-secondary"
+        };
+
+        js!(
+            return @{self}.lock( @{orientation} );
+        ).try_into().unwrap()
+    }
+
+    /// Unlocks the orientation of the screen.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/unlock)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-unlock
+    pub fn unlock( &self ) {
+        js!( @(no_return)
+            @{self}.unlock();
+        );
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::window::window;
+
+    #[test]
+    fn test_screen() {
+        let screen = window().screen();
+        assert!( screen.width() >= screen.avail_width() || screen.avail_width() == 0 );
+        assert!( screen.color_depth() > 0 );
+        if let Some( orientation ) = screen.orientation() {
+            assert!( orientation.angle() < 360 );
+        }
+    }
+}
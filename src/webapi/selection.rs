@@ -0,0 +1,158 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::node::Node;
+use webapi::range::Range;
+use webapi::dom_exception::IndexSizeError;
+
+/// Represents the range of text selected by the user or the current position of the caret.
+///
+/// You can get it with [`Window::get_selection`](struct.Window.html#method.get_selection).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection)
+// https://w3c.github.io/selection-api/#selection-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Selection")]
+pub struct Selection( Reference );
+
+impl Selection {
+    /// Returns the node in which the selection begins.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/anchorNode)
+    // https://w3c.github.io/selection-api/#dom-selection-anchornode
+    pub fn anchor_node( &self ) -> Option< Node > {
+        js!(
+            return @{self}.anchorNode;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the offset within the [anchor node](#method.anchor_node) at which the selection begins.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/anchorOffset)
+    // https://w3c.github.io/selection-api/#dom-selection-anchoroffset
+    pub fn anchor_offset( &self ) -> u32 {
+        js!(
+            return @{self}.anchorOffset;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the node in which the selection ends.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/focusNode)
+    // https://w3c.github.io/selection-api/#dom-selection-focusnode
+    pub fn focus_node( &self ) -> Option< Node > {
+        js!(
+            return @{self}.focusNode;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the offset within the [focus node](#method.focus_node) at which the selection ends.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/focusOffset)
+    // https://w3c.github.io/selection-api/#dom-selection-focusoffset
+    pub fn focus_offset( &self ) -> u32 {
+        js!(
+            return @{self}.focusOffset;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the selection is empty, that is if it's just a caret.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/isCollapsed)
+    // https://w3c.github.io/selection-api/#dom-selection-iscollapsed
+    pub fn is_collapsed( &self ) -> bool {
+        js!(
+            return @{self}.isCollapsed;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of ranges in the selection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/rangeCount)
+    // https://w3c.github.io/selection-api/#dom-selection-rangecount
+    pub fn range_count( &self ) -> u32 {
+        js!(
+            return @{self}.rangeCount;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the range of the selection at the given index.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/getRangeAt)
+    // https://w3c.github.io/selection-api/#dom-selection-getrangeat
+    pub fn get_range_at( &self, index: u32 ) -> Result< Range, IndexSizeError > {
+        js_try!(
+            return @{self}.getRangeAt( @{index} );
+        ).unwrap()
+    }
+
+    /// Adds the given range to the selection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/addRange)
+    // https://w3c.github.io/selection-api/#dom-selection-addrange
+    pub fn add_range( &self, range: &Range ) {
+        js! { @(no_return)
+            @{self}.addRange( @{range} );
+        }
+    }
+
+    /// Removes the given range from the selection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/removeRange)
+    // https://w3c.github.io/selection-api/#dom-selection-removerange
+    pub fn remove_range( &self, range: &Range ) {
+        js! { @(no_return)
+            @{self}.removeRange( @{range} );
+        }
+    }
+
+    /// Removes all ranges from the selection.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/removeAllRanges)
+    // https://w3c.github.io/selection-api/#dom-selection-removeallranges
+    pub fn remove_all_ranges( &self ) {
+        js! { @(no_return)
+            @{self}.removeAllRanges();
+        }
+    }
+
+    /// Returns the currently selected text.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/toString)
+    // https://w3c.github.io/selection-api/#dom-selection-stringifier
+    pub fn to_string( &self ) -> String {
+        js!(
+            return @{self}.toString();
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::window::window;
+    use webapi::node::INode;
+
+    #[test]
+    fn test_selection() {
+        let parent = document().create_element( "div" ).unwrap();
+        parent.append_child( &document().create_text_node( "Selected text" ) );
+        document().body().unwrap().append_child( &parent );
+
+        let range = document().create_range();
+        range.select_node_contents( &parent ).unwrap();
+
+        let selection: Selection = window().get_selection().unwrap();
+        selection.remove_all_ranges();
+        assert_eq!( selection.range_count(), 0 );
+        assert!( selection.get_range_at( 0 ).is_err() );
+
+        selection.add_range( &range );
+        assert_eq!( selection.range_count(), 1 );
+        assert_eq!( selection.to_string(), "Selected text" );
+        assert_eq!( selection.get_range_at( 0 ).unwrap(), range );
+
+        selection.remove_all_ranges();
+        document().body().unwrap().remove_child( &parent ).unwrap();
+    }
+}
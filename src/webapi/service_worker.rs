@@ -0,0 +1,283 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{SecurityError, DataCloneError};
+use webapi::error::TypeError;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::error::Error;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// Gives access to the service workers of the current origin, and allows
+/// registering new ones.
+///
+/// You can get it with [`Navigator::service_worker`](struct.Navigator.html#method.service_worker).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer)
+// https://w3c.github.io/ServiceWorker/#serviceworkercontainer
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ServiceWorkerContainer")]
+#[reference(subclass_of(EventTarget))]
+pub struct ServiceWorkerContainer( Reference );
+
+impl IEventTarget for ServiceWorkerContainer {}
+
+/// Options for [`ServiceWorkerContainer::register`](struct.ServiceWorkerContainer.html#method.register).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/register#Parameters)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServiceWorkerRegistrationOptions {
+    /// The URL prefix of the pages controlled by the service worker. If `None`
+    /// the directory of the service worker's script is used.
+    pub scope: Option< String >,
+}
+
+error_enum_boilerplate! {
+    /// A enum of the exceptions that [`ServiceWorkerContainer::register`](struct.ServiceWorkerContainer.html#method.register)
+    /// may fail with.
+    ServiceWorkerRegisterError,
+    /// The script or the scope isn't a valid URL of the current origin.
+    SecurityError,
+    /// The script couldn't be fetched or failed to evaluate.
+    TypeError
+}
+
+impl ServiceWorkerContainer {
+    /// Returns the service worker which controls the current page, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/controller)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-controller
+    pub fn controller( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.controller;
+        ).try_into().unwrap()
+    }
+
+    /// Registers the service worker script at `url`, and returns a future which
+    /// resolves to its registration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/register)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-register
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn register( &self, url: &str, options: ServiceWorkerRegistrationOptions ) -> PromiseFuture< ServiceWorkerRegistration, ServiceWorkerRegisterError > {
+        js!(
+            var options = {};
+            var scope = @{options.scope};
+            if( scope !== null ) {
+                options.scope = scope;
+            }
+            return @{self}.register( @{url}, options );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the registration of the service worker
+    /// controlling the current page once it's active. It never resolves if there
+    /// is no such service worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/ready)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-ready
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn ready( &self ) -> PromiseFuture< ServiceWorkerRegistration, Error > {
+        js!(
+            return @{self}.ready;
+        ).try_into().unwrap()
+    }
+}
+
+/// Represents the registration of a service worker for a scope.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration)
+// https://w3c.github.io/ServiceWorker/#serviceworkerregistration
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ServiceWorkerRegistration")]
+#[reference(subclass_of(EventTarget))]
+pub struct ServiceWorkerRegistration( Reference );
+
+impl IEventTarget for ServiceWorkerRegistration {}
+
+impl ServiceWorkerRegistration {
+    /// Returns the URL prefix of the pages controlled by this registration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/scope)
+    // https://w3c.github.io/ServiceWorker/#service-worker-registration-scope
+    pub fn scope( &self ) -> String {
+        js!(
+            return @{self}.scope;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the service worker which is currently being installed, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/installing)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-installing
+    pub fn installing( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.installing;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the installed service worker which is waiting to become active, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/waiting)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-waiting
+    pub fn waiting( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.waiting;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the active service worker, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/active)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-active
+    pub fn active( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.active;
+        ).try_into().unwrap()
+    }
+
+    /// Checks whether the service worker script has changed, and installs
+    /// the new version if it has.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/update)
+    // https://w3c.github.io/ServiceWorker/#service-worker-registration-update
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn update( &self ) -> PromiseFuture< (), Error > {
+        js!(
+            return @{self}.update().then( function() {} );
+        ).try_into().unwrap()
+    }
+
+    /// Unregisters the service worker, and returns a future which resolves to
+    /// whether it was unregistered.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/unregister)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-unregister
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn unregister( &self ) -> PromiseFuture< bool, Error > {
+        js!(
+            return @{self}.unregister();
+        ).try_into().unwrap()
+    }
+}
+
+/// The state of a [ServiceWorker](struct.ServiceWorker.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/state)
+// https://w3c.github.io/ServiceWorker/#enumdef-serviceworkerstate
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ServiceWorkerState {
+    /// The script was fetched, but the worker isn't installed yet.
+    Parsed,
+    /// The worker is being installed.
+    Installing,
+    /// The worker is installed and waiting to be activated.
+    Installed,
+    /// The worker is being activated.
+    Activating,
+    /// The worker is active and controls pages.
+    Activated,
+    /// The worker was replaced by another one, or failed to install.
+    Redundant,
+}
+
+/// A service worker, which can be sent messages.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker)
+// https://w3c.github.io/ServiceWorker/#serviceworker
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ServiceWorker")]
+#[reference(subclass_of(EventTarget))]
+pub struct ServiceWorker( Reference );
+
+impl IEventTarget for ServiceWorker {}
+
+impl ServiceWorker {
+    /// Returns the URL of the worker's script.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/scriptURL)
+    // https://w3c.github.io/ServiceWorker/#service-worker-url
+    pub fn script_url( &self ) -> String {
+        js!(
+            return @{self}.scriptURL;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the state of the worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/state)
+    // https://w3c.github.io/ServiceWorker/#service-worker-state
+    pub fn state( &self ) -> ServiceWorkerState {
+        let state: String = js!(
+            return @{self}.state;
+        ).try_into().unwrap();
+
+        match state.as_str() {
+            "parsed" => ServiceWorkerState::Parsed,
+            "installing" => ServiceWorkerState::Installing,
+            "installed" => ServiceWorkerState::Installed,
+            "activating" => ServiceWorkerState::Activating,
+            "activated" => ServiceWorkerState::Activated,
+            "redundant" => ServiceWorkerState::Redundant,
+            _ => panic!( "Unexpected service worker state: {:?}", state )
+        }
+    }
+
+    /// Sends a `message` to the worker, where it's delivered as a `message` event.
+    ///
+    /// Fails with a `DataCloneError` if the `message` can't be cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/postMessage)
+    // https://w3c.github.io/ServiceWorker/#service-worker-postmessage
+    pub fn post_message( &self, message: &Value ) -> Result< (), DataCloneError > {
+        js_try!( @(no_return)
+            @{self}.postMessage( @{message} );
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::navigator::navigator;
+
+    #[test]
+    fn test_controller() {
+        if let Some( container ) = navigator().service_worker() {
+            assert!( container.controller().is_none() );
+        }
+    }
+
+    #[test]
+    fn test_native_type_error() {
+        use webcore::try_from::TryInto;
+
+        // `register()` rejects with a plain `TypeError`, not a `DOMException`.
+        let error: ServiceWorkerRegisterError = js!( return new TypeError( "bad scheme" ); ).try_into().unwrap();
+        match error {
+            ServiceWorkerRegisterError::TypeError( _ ) => {},
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn test_register_unsupported_scheme() {
+        use futures_util::FutureExt;
+        use webcore::promise_future::PromiseFuture;
+
+        let container = match navigator().service_worker() {
+            Some( container ) => container,
+            None => return
+        };
+
+        PromiseFuture::spawn_local(
+            container.register( "data:text/javascript,", ServiceWorkerRegistrationOptions::default() )
+                .map( |registration| panic!( "Registered {:?}", registration ) )
+                .recover( |error| match error {
+                    ServiceWorkerRegisterError::TypeError( _ ) => {},
+                    error => panic!( "Unexpected error: {:?}", error )
+                })
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use webcore::value::Reference;
+use webcore::try_from::TryInto;
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
+use webapi::character_data::ICharacterData;
+use webapi::dom_exception::IndexSizeError;
 
 /// The `TextNode` represents the textual content of an [IElement](trait.IElement.html)
 ///
@@ -18,3 +21,72 @@ pub struct TextNode( Reference );
 
 impl IEventTarget for TextNode {}
 impl INode for TextNode {}
+impl ICharacterData for TextNode {}
+
+impl TextNode {
+    /// Splits this node in two at `offset`, keeping the text before it in this node,
+    /// and returns a new `TextNode` containing the rest of the text.
+    ///
+    /// If this node has a parent, the new node is inserted right after it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Text/splitText)
+    // https://dom.spec.whatwg.org/#dom-text-splittext
+    pub fn split_text( &self, offset: u32 ) -> Result< TextNode, IndexSizeError > {
+        js_try!(
+            return @{self}.splitText( @{offset} );
+        ).unwrap()
+    }
+
+    /// Returns the text of this node concatenated with the text of all
+    /// the adjacent `TextNode`s.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Text/wholeText)
+    // https://dom.spec.whatwg.org/#dom-text-wholetext
+    pub fn whole_text( &self ) -> String {
+        js!(
+            return @{self}.wholeText;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_character_data() {
+        let text = document().create_text_node( "Hello" );
+        assert_eq!( text.data(), "Hello" );
+        assert_eq!( text.length(), 5 );
+
+        text.append_data( " world" );
+        assert_eq!( text.data(), "Hello world" );
+        text.insert_data( 5, "," ).unwrap();
+        assert_eq!( text.data(), "Hello, world" );
+        text.delete_data( 0, 7 ).unwrap();
+        assert_eq!( text.data(), "world" );
+        text.replace_data( 0, 1, "W" ).unwrap();
+        assert_eq!( text.substring_data( 0, 3 ).unwrap(), "Wor" );
+        text.set_data( "abc" );
+        assert_eq!( text.data(), "abc" );
+
+        assert!( text.insert_data( 10, "x" ).is_err() );
+        assert!( text.delete_data( 10, 1 ).is_err() );
+    }
+
+    #[test]
+    fn test_split_text() {
+        let parent = document().create_element( "div" ).unwrap();
+        let text = document().create_text_node( "Hello world" );
+        parent.append_child( &text );
+
+        let rest = text.split_text( 5 ).unwrap();
+        assert_eq!( text.data(), "Hello" );
+        assert_eq!( rest.data(), " world" );
+        assert_eq!( text.next_sibling().unwrap().as_ref(), rest.as_ref() );
+        assert_eq!( text.whole_text(), "Hello world" );
+
+        assert!( text.split_text( 10 ).is_err() );
+    }
+}
@@ -0,0 +1,256 @@
+use std;
+use std::ops::BitOr;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::node::{INode, Node};
+use webapi::document::Document;
+
+/// Specifies which kinds of nodes a [`TreeWalker`](struct.TreeWalker.html) should visit.
+///
+/// Values can be combined with the `|` operator.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createTreeWalker#Parameters)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WhatToShow( u32 );
+
+impl WhatToShow {
+    /// Visit every node.
+    pub const ALL: WhatToShow = WhatToShow( 0xFFFFFFFF );
+    /// Visit `Element` nodes.
+    pub const ELEMENT: WhatToShow = WhatToShow( 0x1 );
+    /// Visit `Text` nodes.
+    pub const TEXT: WhatToShow = WhatToShow( 0x4 );
+    /// Visit `ProcessingInstruction` nodes.
+    pub const PROCESSING_INSTRUCTION: WhatToShow = WhatToShow( 0x40 );
+    /// Visit `Comment` nodes.
+    pub const COMMENT: WhatToShow = WhatToShow( 0x80 );
+    /// Visit `Document` nodes.
+    pub const DOCUMENT: WhatToShow = WhatToShow( 0x100 );
+    /// Visit `DocumentType` nodes.
+    pub const DOCUMENT_TYPE: WhatToShow = WhatToShow( 0x200 );
+    /// Visit `DocumentFragment` nodes.
+    pub const DOCUMENT_FRAGMENT: WhatToShow = WhatToShow( 0x400 );
+}
+
+impl BitOr for WhatToShow {
+    type Output = WhatToShow;
+
+    #[inline]
+    fn bitor( self, rhs: WhatToShow ) -> WhatToShow {
+        WhatToShow( self.0 | rhs.0 )
+    }
+}
+
+/// The result of a [`TreeWalker`](struct.TreeWalker.html) filter.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NodeFilter/acceptNode)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NodeFilterResult {
+    /// The node is visited.
+    Accept,
+    /// The node and all of its children are skipped.
+    Reject,
+    /// The node is skipped, but its children are still visited.
+    Skip,
+}
+
+/// Traverses the nodes of a subtree of the document.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker)
+// https://dom.spec.whatwg.org/#interface-treewalker
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TreeWalker")]
+pub struct TreeWalker( Reference );
+
+impl TreeWalker {
+    /// Returns the node at which the traversal is rooted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/root)
+    // https://dom.spec.whatwg.org/#dom-treewalker-root
+    pub fn root( &self ) -> Node {
+        js!(
+            return @{self}.root;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the node the `TreeWalker` is currently pointing at.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/currentNode)
+    // https://dom.spec.whatwg.org/#dom-treewalker-currentnode
+    pub fn current_node( &self ) -> Node {
+        js!(
+            return @{self}.currentNode;
+        ).try_into().unwrap()
+    }
+
+    /// Moves the `TreeWalker` to the given node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/currentNode)
+    // https://dom.spec.whatwg.org/#dom-treewalker-currentnode
+    pub fn set_current_node< T: INode >( &self, node: &T ) {
+        js! { @(no_return)
+            @{self}.currentNode = @{node.as_ref()};
+        }
+    }
+
+    /// Moves to the closest visible ancestor of the current node, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/parentNode)
+    // https://dom.spec.whatwg.org/#dom-treewalker-parentnode
+    pub fn parent_node( &self ) -> Option< Node > {
+        js!(
+            return @{self}.parentNode();
+        ).try_into().unwrap()
+    }
+
+    /// Moves to the first visible child of the current node, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/firstChild)
+    // https://dom.spec.whatwg.org/#dom-treewalker-firstchild
+    pub fn first_child( &self ) -> Option< Node > {
+        js!(
+            return @{self}.firstChild();
+        ).try_into().unwrap()
+    }
+
+    /// Moves to the last visible child of the current node, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/lastChild)
+    // https://dom.spec.whatwg.org/#dom-treewalker-lastchild
+    pub fn last_child( &self ) -> Option< Node > {
+        js!(
+            return @{self}.lastChild();
+        ).try_into().unwrap()
+    }
+
+    /// Moves to the previous visible sibling of the current node, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/previousSibling)
+    // https://dom.spec.whatwg.org/#dom-treewalker-previoussibling
+    pub fn previous_sibling( &self ) -> Option< Node > {
+        js!(
+            return @{self}.previousSibling();
+        ).try_into().unwrap()
+    }
+
+    /// Moves to the next visible sibling of the current node, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/nextSibling)
+    // https://dom.spec.whatwg.org/#dom-treewalker-nextsibling
+    pub fn next_sibling( &self ) -> Option< Node > {
+        js!(
+            return @{self}.nextSibling();
+        ).try_into().unwrap()
+    }
+
+    /// Moves to the previous visible node in document order, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/previousNode)
+    // https://dom.spec.whatwg.org/#dom-treewalker-previousnode
+    pub fn previous_node( &self ) -> Option< Node > {
+        js!(
+            return @{self}.previousNode();
+        ).try_into().unwrap()
+    }
+
+    /// Moves to the next visible node in document order, and returns it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker/nextNode)
+    // https://dom.spec.whatwg.org/#dom-treewalker-nextnode
+    pub fn next_node( &self ) -> Option< Node > {
+        js!(
+            return @{self}.nextNode();
+        ).try_into().unwrap()
+    }
+}
+
+/// A wrapper around a [`TreeWalker`](struct.TreeWalker.html) which owns its filter callback.
+///
+/// It dereferences to a `TreeWalker`, so it can use the same methods.
+///
+/// When the `TreeWalkerHandle` is dropped the filter callback is released, after which
+/// the underlying `TreeWalker` must not be used anymore.
+#[ derive( Debug ) ]
+pub struct TreeWalkerHandle {
+    tree_walker: TreeWalker,
+    filter_reference: Reference,
+}
+
+impl TreeWalkerHandle {
+    pub(crate) fn new< T, F >( document: &Document, root: &T, what_to_show: WhatToShow, mut filter: F ) -> Self
+        where T: INode, F: FnMut( Node ) -> NodeFilterResult + 'static
+    {
+        let callback = move |node: Node| -> u32 {
+            match filter( node ) {
+                NodeFilterResult::Accept => 1,
+                NodeFilterResult::Reject => 2,
+                NodeFilterResult::Skip => 3
+            }
+        };
+
+        let filter_reference: Reference = js!( return @{callback}; ).try_into().unwrap();
+        let tree_walker = js!(
+            return @{document}.createTreeWalker( @{root.as_ref()}, @{what_to_show.0}, @{&filter_reference} );
+        ).try_into().unwrap();
+
+        TreeWalkerHandle {
+            tree_walker,
+            filter_reference
+        }
+    }
+}
+
+impl std::ops::Deref for TreeWalkerHandle {
+    type Target = TreeWalker;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.tree_walker
+    }
+}
+
+impl Drop for TreeWalkerHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            @{&self.filter_reference}.drop();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_tree_walker_with_filter() {
+        let root = Node::from_html( "<div><p>foo</p><span>bar<b>baz</b></span><p>qux</p></div>" ).unwrap();
+        root.normalize();
+
+        let walker = document().create_tree_walker( &root, WhatToShow::TEXT, |node: Node| {
+            match node.parent_node().unwrap().node_name().as_str() {
+                "B" => NodeFilterResult::Skip,
+                _ => NodeFilterResult::Accept
+            }
+        });
+        assert_eq!( walker.root(), root );
+
+        let mut texts = Vec::new();
+        while let Some( node ) = walker.next_node() {
+            texts.push( node.text_content().unwrap() );
+        }
+        assert_eq!( texts, vec![ "foo", "bar", "qux" ] );
+    }
+
+    #[test]
+    fn test_tree_walker_what_to_show() {
+        let root = Node::from_html( "<div><p>foo</p><!-- comment --></div>" ).unwrap();
+        let walker = document().create_tree_walker( &root, WhatToShow::ELEMENT | WhatToShow::COMMENT, |_| NodeFilterResult::Accept );
+
+        assert_eq!( walker.first_child().unwrap().node_name(), "P" );
+        assert_eq!( walker.next_sibling().unwrap().node_name(), "#comment" );
+        assert!( walker.next_sibling().is_none() );
+        assert_eq!( walker.parent_node().unwrap(), root );
+    }
+}
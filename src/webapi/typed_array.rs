@@ -3,6 +3,7 @@ use std::mem::size_of;
 use webcore::value::Reference;
 use webcore::try_from::TryInto;
 use webcore::instance_of::InstanceOf;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
 use webapi::array_buffer::ArrayBuffer;
 
 pub trait ArrayKind: Sized {
@@ -10,10 +11,15 @@ pub trait ArrayKind: Sized {
     fn into_typed_array( slice: &[Self] ) -> TypedArray< Self >;
     fn into_typed_array_from_array_buffer( buffer: &ArrayBuffer ) -> TypedArray< Self >;
     fn from_typed_array( array: &TypedArray< Self > ) -> Vec< Self >;
+    fn copy_slice_into_typed_array( slice: &[Self], array: &TypedArray< Self >, offset: u32 );
+    fn get_from_typed_array( array: &TypedArray< Self >, index: u32 ) -> Option< Self >;
+    fn set_in_typed_array( array: &TypedArray< Self >, index: u32, value: Self );
+    fn fill_typed_array( array: &TypedArray< Self >, value: Self );
+    fn swap_bytes( self ) -> Self;
 }
 
 macro_rules! arraykind {
-    ($element_type:ty, $js_array_type:ident, $heap_type:ident) => {
+    ($element_type:ty, $js_array_type:ident, $heap_type:ident, |$value:ident| $swap_bytes:expr) => {
         impl ArrayKind for $element_type {
             fn is_typed_array( reference: &Reference ) -> bool {
                 instanceof!( *reference, $js_array_type )
@@ -70,6 +76,47 @@ macro_rules! arraykind {
 
                 vector
             }
+
+            fn copy_slice_into_typed_array( slice: &[Self], array: &TypedArray< Self >, offset: u32 ) {
+                let slice_ptr = (slice.as_ptr() as usize / size_of::<$element_type>()) as i32;
+                js!( @(no_return)
+                    var pointer = @{slice_ptr};
+                    @{array}.set( $heap_type.subarray( pointer, pointer + @{slice.len() as u32} ), @{offset} );
+                );
+            }
+
+            #[allow(trivial_numeric_casts)]
+            fn get_from_typed_array( array: &TypedArray< Self >, index: u32 ) -> Option< Self > {
+                // Every element type is exactly representable as a f64.
+                let value: Option< f64 > = js!(
+                    var array = @{array};
+                    var index = @{index};
+                    if( index >= array.length ) {
+                        return null;
+                    }
+                    return array[ index ];
+                ).try_into().unwrap();
+
+                value.map( |value| value as $element_type )
+            }
+
+            fn set_in_typed_array( array: &TypedArray< Self >, index: u32, value: Self ) {
+                js!( @(no_return)
+                    @{array}[ @{index} ] = @{value};
+                );
+            }
+
+            fn fill_typed_array( array: &TypedArray< Self >, value: Self ) {
+                js!( @(no_return)
+                    @{array}.fill( @{value} );
+                );
+            }
+
+            #[inline]
+            fn swap_bytes( self ) -> Self {
+                let $value = self;
+                $swap_bytes
+            }
         }
 
         impl From< TypedArray< $element_type > > for Vec< $element_type > {
@@ -86,14 +133,106 @@ macro_rules! arraykind {
     }
 }
 
-arraykind!( i8, Int8Array, HEAP8 );
-arraykind!( u8, Uint8Array, HEAPU8 );
-arraykind!( i16, Int16Array, HEAP16 );
-arraykind!( u16, Uint16Array, HEAPU16 );
-arraykind!( i32, Int32Array, HEAP32 );
-arraykind!( u32, Uint32Array, HEAPU32 );
-arraykind!( f32, Float32Array, HEAPF32 );
-arraykind!( f64, Float64Array, HEAPF64 );
+arraykind!( i8, Int8Array, HEAP8, |value| value.swap_bytes() );
+arraykind!( u8, Uint8Array, HEAPU8, |value| value.swap_bytes() );
+arraykind!( i16, Int16Array, HEAP16, |value| value.swap_bytes() );
+arraykind!( u16, Uint16Array, HEAPU16, |value| value.swap_bytes() );
+arraykind!( i32, Int32Array, HEAP32, |value| value.swap_bytes() );
+arraykind!( u32, Uint32Array, HEAPU32, |value| value.swap_bytes() );
+arraykind!( f32, Float32Array, HEAPF32, |value| f32::from_bits( value.to_bits().swap_bytes() ) );
+arraykind!( f64, Float64Array, HEAPF64, |value| f64::from_bits( value.to_bits().swap_bytes() ) );
+
+/// An `u8` which is stored in an `Uint8ClampedArray`.
+///
+/// Values written to a `TypedArray< U8Clamped >` from JavaScript are clamped to
+/// the range 0-255 instead of wrapping around, which is what the `ImageData`
+/// of a canvas uses.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray)
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U8Clamped( pub u8 );
+
+impl U8Clamped {
+    #[inline]
+    fn as_bytes( slice: &[U8Clamped] ) -> &[u8] {
+        // This is safe since `U8Clamped` is a transparent wrapper around an `u8`.
+        unsafe { ::std::slice::from_raw_parts( slice.as_ptr() as *const u8, slice.len() ) }
+    }
+}
+
+impl ArrayKind for U8Clamped {
+    fn is_typed_array( reference: &Reference ) -> bool {
+        instanceof!( *reference, Uint8ClampedArray )
+    }
+
+    fn into_typed_array( slice: &[Self] ) -> TypedArray< Self > {
+        js!(
+            return new Uint8ClampedArray( @{UnsafeTypedArray( U8Clamped::as_bytes( slice ) )} );
+        ).try_into().unwrap()
+    }
+
+    fn into_typed_array_from_array_buffer( buffer: &ArrayBuffer ) -> TypedArray< Self > {
+        js!(
+            return new Uint8ClampedArray( @{buffer} );
+        ).try_into().unwrap()
+    }
+
+    fn from_typed_array( array: &TypedArray< Self > ) -> Vec< Self > {
+        let length = array.len() as usize;
+        let mut vector = Vec::with_capacity( length );
+        let vec_ptr = vector.as_ptr() as usize as i32;
+
+        js!( @(no_return)
+            HEAPU8.set( @{array}, @{vec_ptr} );
+        );
+
+        unsafe {
+            vector.set_len( length );
+        }
+
+        vector
+    }
+
+    fn copy_slice_into_typed_array( slice: &[Self], array: &TypedArray< Self >, offset: u32 ) {
+        js!( @(no_return)
+            @{array}.set( @{UnsafeTypedArray( U8Clamped::as_bytes( slice ) )}, @{offset} );
+        );
+    }
+
+    fn get_from_typed_array( array: &TypedArray< Self >, index: u32 ) -> Option< Self > {
+        u8::get_from_typed_array( &TypedArray( array.0.clone(), PhantomData ), index ).map( U8Clamped )
+    }
+
+    fn set_in_typed_array( array: &TypedArray< Self >, index: u32, value: Self ) {
+        js!( @(no_return)
+            @{array}[ @{index} ] = @{value.0};
+        );
+    }
+
+    fn fill_typed_array( array: &TypedArray< Self >, value: Self ) {
+        js!( @(no_return)
+            @{array}.fill( @{value.0} );
+        );
+    }
+
+    #[inline]
+    fn swap_bytes( self ) -> Self {
+        self
+    }
+}
+
+impl From< TypedArray< U8Clamped > > for Vec< U8Clamped > {
+    fn from( array: TypedArray< U8Clamped > ) -> Self {
+        U8Clamped::from_typed_array( &array )
+    }
+}
+
+impl< 'a > From< &'a TypedArray< U8Clamped > > for Vec< U8Clamped > {
+    fn from( array: &'a TypedArray< U8Clamped > ) -> Self {
+        U8Clamped::from_typed_array( array )
+    }
+}
 
 impl< T: ArrayKind > InstanceOf for TypedArray< T > {
     #[inline]
@@ -104,6 +243,11 @@ impl< T: ArrayKind > InstanceOf for TypedArray< T > {
 
 /// JavaScript typed arrays are array-like objects and provide a mechanism for accessing raw binary data.
 ///
+/// Typed arrays store their elements in the byte order of the platform, which is
+/// little endian everywhere WebAssembly runs. Converting between a `TypedArray` and a
+/// `Vec` or a slice preserves the values of the elements; use the `_le` and `_be`
+/// methods when the underlying bytes are meant to be in a specific byte order.
+///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Typed_arrays)
 // https://www.ecma-international.org/ecma-262/6.0/#sec-typedarray-objects
 #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
@@ -126,10 +270,97 @@ impl< T: ArrayKind > TypedArray< T > {
         js!( return @{reference}.length; ).try_into().unwrap()
     }
 
+    /// Returns the element at the given `index`, or `None` if it's out of bounds.
+    pub fn get( &self, index: u32 ) -> Option< T > {
+        T::get_from_typed_array( self, index )
+    }
+
+    /// Sets the element at the given `index` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set( &self, index: u32, value: T ) {
+        let length = self.len();
+        assert!( index < length, "Index {} is out of bounds of a typed array of length {}", index, length );
+        T::set_in_typed_array( self, index, value );
+    }
+
+    /// Sets every element of the array to `value`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-%typedarray%.prototype.fill
+    pub fn fill( &self, value: T ) {
+        T::fill_typed_array( self, value );
+    }
+
+    /// Returns a new typed array over the elements from `start` up to (but not including)
+    /// `end` of `self`.
+    ///
+    /// No data is copied; the returned array is a view sharing the same
+    /// [ArrayBuffer](struct.ArrayBuffer.html), so writes through either of them are
+    /// visible through the other one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-%typedarray%.prototype.subarray
+    pub fn subarray( &self, start: u32, end: u32 ) -> TypedArray< T > {
+        js!( return @{self}.subarray( @{start}, @{end} ); ).try_into().unwrap()
+    }
+
     /// Copies `self` into a new `Vec`.
     pub fn to_vec( &self ) -> Vec< T > {
         T::from_typed_array( self )
     }
+
+    /// Copies `self` into a new `Vec`, decoding every element from the underlying
+    /// bytes as a little endian number.
+    pub fn to_vec_le( &self ) -> Vec< T > {
+        // Typed arrays are always little endian when running on WebAssembly.
+        self.to_vec()
+    }
+
+    /// Copies `self` into a new `Vec`, decoding every element from the underlying
+    /// bytes as a big endian number.
+    pub fn to_vec_be( &self ) -> Vec< T > {
+        self.to_vec().into_iter().map( T::swap_bytes ).collect()
+    }
+
+    /// Creates a new typed array whose underlying bytes contain the elements
+    /// of `slice` encoded as little endian numbers.
+    pub fn from_slice_le( slice: &[T] ) -> Self {
+        T::into_typed_array( slice )
+    }
+
+    /// Creates a new typed array whose underlying bytes contain the elements
+    /// of `slice` encoded as big endian numbers.
+    pub fn from_slice_be( slice: &[T] ) -> Self where T: Copy {
+        let swapped: Vec< T > = slice.iter().map( |&value| value.swap_bytes() ).collect();
+        T::into_typed_array( &swapped )
+    }
+
+    /// Copies the elements of `source` into `self`, starting at the element `offset`.
+    ///
+    /// The elements are copied straight out of Rust's memory without creating
+    /// an intermediate JavaScript array, so this can be used to upload large
+    /// payloads into a preallocated buffer, possibly in several chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` doesn't fit into `self` at the given `offset`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-%typedarray%.prototype.set-typedarray-offset
+    pub fn copy_from_slice( &self, source: &[T], offset: u32 ) {
+        assert!(
+            offset as u64 + source.len() as u64 <= self.len() as u64,
+            "Slice of length {} doesn't fit into a typed array of length {} at offset {}",
+            source.len(),
+            self.len(),
+            offset
+        );
+
+        T::copy_slice_into_typed_array( source, self, offset );
+    }
 }
 
 impl< 'a, T: ArrayKind > From< &'a [T] > for TypedArray< T > {
@@ -200,6 +431,38 @@ mod tests {
                     assert_eq!( vec, ARRAY);
                 }
 
+                #[test]
+                fn copy_from_slice() {
+                    let typed_array: TypedArray< $element_type > = ArrayBuffer::new( 4 * std::mem::size_of::< $element_type >() as u64 ).unwrap().into();
+                    typed_array.copy_from_slice( ARRAY, 1 );
+                    let vec: Vec< $element_type > = typed_array.into();
+                    assert_eq!( &vec[ 1..3 ], ARRAY );
+                }
+
+                #[test]
+                fn element_access() {
+                    let typed_array: TypedArray< $element_type > = ARRAY.into();
+                    assert_eq!( typed_array.get( 0 ), Some( ARRAY[ 0 ] ) );
+                    assert_eq!( typed_array.get( 2 ), None );
+
+                    typed_array.set( 0, ARRAY[ 1 ] );
+                    assert_eq!( typed_array.get( 0 ), Some( ARRAY[ 1 ] ) );
+
+                    typed_array.fill( ARRAY[ 0 ] );
+                    assert_eq!( typed_array.to_vec(), vec![ ARRAY[ 0 ], ARRAY[ 0 ] ] );
+                }
+
+                #[test]
+                fn subarray() {
+                    let typed_array: TypedArray< $element_type > = ARRAY.into();
+                    let subarray = typed_array.subarray( 1, 2 );
+                    assert_eq!( subarray.len(), 1 );
+                    assert_eq!( subarray.get( 0 ), Some( ARRAY[ 1 ] ) );
+
+                    subarray.set( 0, ARRAY[ 0 ] );
+                    assert_eq!( typed_array.get( 1 ), Some( ARRAY[ 0 ] ) );
+                }
+
                 #[test]
                 fn from_array_buffer() {
                     let value = js!( return new $js_array_type( [@{ARRAY[0]}, @{ARRAY[1]}] ).buffer; );
@@ -222,6 +485,67 @@ mod tests {
     arraykind_test!(f32, Float32Array);
     arraykind_test!(f64, Float64Array);
 
+    mod u8_clamped {
+        use super::super::{TypedArray, U8Clamped};
+        use webcore::try_from::TryInto;
+        use webcore::value::Value;
+        use webapi::array_buffer::ArrayBuffer;
+
+        const ARRAY: &[U8Clamped] = &[ U8Clamped( 0 ), U8Clamped( 255 ) ];
+
+        #[test]
+        fn into() {
+            let typed_array: TypedArray< U8Clamped > = ARRAY.into();
+            assert_eq!(
+                js!( return @{&typed_array} instanceof Uint8ClampedArray; ),
+                Value::Bool( true )
+            );
+            assert_eq!( typed_array.to_vec(), ARRAY );
+        }
+
+        #[test]
+        fn from() {
+            let value = js!( return new Uint8ClampedArray( [-10, 300] ); );
+            let typed_array: TypedArray< U8Clamped > = value.try_into().unwrap();
+            let vec: Vec< U8Clamped > = typed_array.into();
+            assert_eq!( vec, ARRAY );
+        }
+
+        #[test]
+        fn is_distinct_from_u8() {
+            let value = js!( return new Uint8Array( 2 ); );
+            let typed_array: Result< TypedArray< U8Clamped >, _ > = value.try_into();
+            assert!( typed_array.is_err() );
+        }
+
+        #[test]
+        fn element_access() {
+            let typed_array: TypedArray< U8Clamped > = ArrayBuffer::new( 3 ).unwrap().into();
+            typed_array.copy_from_slice( ARRAY, 1 );
+            assert_eq!( typed_array.get( 2 ), Some( U8Clamped( 255 ) ) );
+
+            js!( @{&typed_array}[ 0 ] = 1000; );
+            assert_eq!( typed_array.get( 0 ), Some( U8Clamped( 255 ) ) );
+
+            typed_array.set( 1, U8Clamped( 7 ) );
+            typed_array.fill( U8Clamped( 3 ) );
+            assert_eq!( typed_array.to_vec(), vec![ U8Clamped( 3 ); 3 ] );
+        }
+    }
+
+    #[test]
+    fn byte_order() {
+        let big_endian = TypedArray::< u32 >::from_slice_be( &[0x01020304] );
+        let bytes: TypedArray< u8 > = big_endian.buffer().into();
+        assert_eq!( bytes.to_vec(), vec![ 0x01, 0x02, 0x03, 0x04 ] );
+        assert_eq!( big_endian.to_vec_be(), vec![ 0x01020304 ] );
+
+        let little_endian = TypedArray::< f64 >::from_slice_le( &[1.5] );
+        let bytes: TypedArray< u8 > = little_endian.buffer().into();
+        assert_eq!( bytes.to_vec(), 1.5_f64.to_bits().to_le_bytes().to_vec() );
+        assert_eq!( little_endian.to_vec_le(), vec![ 1.5 ] );
+    }
+
     fn get_refcount() -> i32 {
         js!( return Object.keys( Module.STDWEB_PRIVATE.id_to_ref_map ).length; ).try_into().unwrap()
     }
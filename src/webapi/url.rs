@@ -0,0 +1,310 @@
+use std::fmt;
+use std::ops::Deref;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::blob::IBlob;
+use webapi::error::TypeError;
+use webapi::url_components::IUrlComponents;
+
+/// A parsed URL.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL)
+// https://url.spec.whatwg.org/#url
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "URL")]
+pub struct Url( Reference );
+
+impl IUrlComponents for Url {}
+
+impl Url {
+    /// Parses `url`, resolving it against `base` if it's a relative URL.
+    ///
+    /// Fails with a `TypeError` if the resulting URL isn't valid, for example if
+    /// `url` is relative and no `base` was given.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL)
+    // https://url.spec.whatwg.org/#dom-url-url
+    pub fn new( url: &str, base: Option< &str > ) -> Result< Url, TypeError > {
+        js_try!(
+            var base = @{base};
+            if( base === null ) {
+                return new URL( @{url} );
+            }
+            return new URL( @{url}, base );
+        ).unwrap()
+    }
+
+    /// Replaces the whole URL.
+    ///
+    /// Fails with a `TypeError` if `value` isn't a valid absolute URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/href)
+    // https://url.spec.whatwg.org/#dom-url-href
+    pub fn set_href( &self, value: &str ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            @{self}.href = @{value};
+        ).unwrap()
+    }
+
+    /// Returns the query parameters of the URL. Changing them updates the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/searchParams)
+    // https://url.spec.whatwg.org/#dom-url-searchparams
+    pub fn search_params( &self ) -> UrlSearchParams {
+        js!(
+            return @{self}.searchParams;
+        ).try_into().unwrap()
+    }
+
+    /// Creates a `blob:` URL through which the contents of `blob` can be
+    /// loaded, for example as the `src` of an image.
+    ///
+    /// The URL keeps the blob alive until it's revoked, which happens when
+    /// the returned [ObjectUrl](struct.ObjectUrl.html) is dropped.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/createObjectURL)
+    // https://w3c.github.io/FileAPI/#dfn-createObjectURL
+    pub fn create_object_url< T: IBlob >( blob: &T ) -> ObjectUrl {
+        let url: String = js!(
+            return URL.createObjectURL( @{blob.as_ref()} );
+        ).try_into().unwrap();
+
+        ObjectUrl( url )
+    }
+}
+
+impl fmt::Display for Url {
+    #[inline]
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        formatter.write_str( &self.href() )
+    }
+}
+
+/// The query parameters of a URL.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams)
+// https://url.spec.whatwg.org/#urlsearchparams
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "URLSearchParams")]
+pub struct UrlSearchParams( Reference );
+
+impl UrlSearchParams {
+    /// Parses the given query string, with or without the leading `?`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/URLSearchParams)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-urlsearchparams
+    pub fn new( query: &str ) -> UrlSearchParams {
+        js!(
+            return new URLSearchParams( @{query} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the first value of the parameter `name`, if there is one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/get)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-get
+    pub fn get( &self, name: &str ) -> Option< String > {
+        js!(
+            return @{self}.get( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns all of the values of the parameter `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/getAll)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-getall
+    pub fn get_all( &self, name: &str ) -> Vec< String > {
+        js!(
+            return @{self}.getAll( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether there is a parameter `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/has)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-has
+    pub fn has( &self, name: &str ) -> bool {
+        js!(
+            return @{self}.has( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Sets the parameter `name` to `value`, removing any other values it had.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/set)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-set
+    pub fn set( &self, name: &str, value: &str ) {
+        js! { @(no_return)
+            @{self}.set( @{name}, @{value} );
+        }
+    }
+
+    /// Adds another `value` to the parameter `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/append)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-append
+    pub fn append( &self, name: &str, value: &str ) {
+        js! { @(no_return)
+            @{self}.append( @{name}, @{value} );
+        }
+    }
+
+    /// Removes all of the values of the parameter `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/delete)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-delete
+    pub fn delete( &self, name: &str ) {
+        js! { @(no_return)
+            @{self}.delete( @{name} );
+        }
+    }
+
+    /// Returns all of the parameters as `(name, value)` pairs, in order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/entries)
+    // https://url.spec.whatwg.org/#concept-urlsearchparams-list
+    pub fn entries( &self ) -> Vec< ( String, String ) > {
+        let entries: Vec< Vec< String > > = js!(
+            return Array.from( @{self}.entries() );
+        ).try_into().unwrap();
+
+        entries.into_iter().map( |mut entry| {
+            let value = entry.pop().unwrap();
+            let name = entry.pop().unwrap();
+            ( name, value )
+        }).collect()
+    }
+}
+
+impl fmt::Display for UrlSearchParams {
+    #[inline]
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        let query: String = js!( return @{self}.toString(); ).try_into().unwrap();
+        formatter.write_str( &query )
+    }
+}
+
+/// A `blob:` URL created with [Url::create_object_url](struct.Url.html#method.create_object_url).
+///
+/// It dereferences to the URL string, and the URL is revoked when it's dropped,
+/// after which the blob can no longer be loaded through it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/revokeObjectURL)
+#[derive(Debug, PartialEq, Eq)]
+pub struct ObjectUrl( String );
+
+impl ObjectUrl {
+    /// Revokes the URL right away.
+    ///
+    /// This is the same as dropping the `ObjectUrl`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/revokeObjectURL)
+    // https://w3c.github.io/FileAPI/#dfn-revokeObjectURL
+    #[inline]
+    pub fn revoke( self ) {
+        drop( self );
+    }
+}
+
+impl Deref for ObjectUrl {
+    type Target = str;
+
+    #[inline]
+    fn deref( &self ) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef< str > for ObjectUrl {
+    #[inline]
+    fn as_ref( &self ) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ObjectUrl {
+    #[inline]
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        formatter.write_str( &self.0 )
+    }
+}
+
+impl Drop for ObjectUrl {
+    #[inline]
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            URL.revokeObjectURL( @{&self.0} );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::blob::Blob;
+
+    #[test]
+    fn test_new() {
+        let url = Url::new( "../baz?a=1#top", Some( "https://example.com:8080/foo/bar/" ) ).unwrap();
+        assert_eq!( url.href(), "https://example.com:8080/foo/baz?a=1#top" );
+        assert_eq!( url.origin(), "https://example.com:8080" );
+        assert_eq!( url.protocol(), "https:" );
+        assert_eq!( url.host(), "example.com:8080" );
+        assert_eq!( url.hostname(), "example.com" );
+        assert_eq!( url.port(), "8080" );
+        assert_eq!( url.pathname(), "/foo/baz" );
+        assert_eq!( url.search(), "?a=1" );
+        assert_eq!( url.hash(), "#top" );
+
+        url.set_port( "" );
+        url.set_hash( "" );
+        assert_eq!( url.to_string(), "https://example.com/foo/baz?a=1" );
+
+        assert!( Url::new( "/relative", None ).is_err() );
+        assert!( url.set_href( "not a url" ).is_err() );
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        use webapi::error::IError;
+
+        let error = Url::new( "/relative", None ).unwrap_err();
+        assert_eq!( error.name(), "TypeError" );
+
+        let url = Url::new( "https://example.com/", None ).unwrap();
+        let error = url.set_href( "not a url" ).unwrap_err();
+        assert_eq!( error.name(), "TypeError" );
+        assert_eq!( url.href(), "https://example.com/" );
+    }
+
+    #[test]
+    fn test_search_params() {
+        let url = Url::new( "https://example.com/?a=1&b=2&a=3", None ).unwrap();
+        let params = url.search_params();
+        assert_eq!( params.get( "a" ), Some( "1".to_owned() ) );
+        assert_eq!( params.get_all( "a" ), vec![ "1".to_owned(), "3".to_owned() ] );
+        assert_eq!( params.get( "c" ), None );
+        assert!( params.has( "b" ) );
+
+        params.delete( "a" );
+        params.append( "c", "x y" );
+        assert_eq!( url.search(), "?b=2&c=x+y" );
+        assert_eq!( params.entries(), vec![ ( "b".to_owned(), "2".to_owned() ), ( "c".to_owned(), "x y".to_owned() ) ] );
+
+        let params = UrlSearchParams::new( "?q=rust" );
+        params.set( "q", "stdweb" );
+        assert_eq!( params.to_string(), "q=stdweb" );
+    }
+
+    #[test]
+    fn test_create_object_url() {
+        let blob: Blob = js!( return new Blob( [ "Hello" ], { type: "text/plain" } ); ).try_into().unwrap();
+        let url = Url::create_object_url( &blob );
+        assert!( url.starts_with( "blob:" ) );
+
+        let other_url = Url::create_object_url( &blob );
+        assert_ne!( url, other_url );
+
+        url.revoke();
+    }
+}
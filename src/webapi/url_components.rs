@@ -0,0 +1,218 @@
+use webcore::reference_type::ReferenceType;
+use webcore::try_from::TryInto;
+
+/// The `IUrlComponents` interface gives access to the components of a URL,
+/// like its host or its path. It's shared by [Url](struct.Url.html) and by
+/// hyperlinks like [AnchorElement](struct.AnchorElement.html).
+///
+/// Setting the whole URL isn't part of it, since only `Url` can fail to parse it.
+/// [Location](struct.Location.html) has its own accessors, since it can also
+/// fail with a `SecurityError`.
+///
+/// You most likely don't want to `use` this directly; instead
+/// you should `use stdweb::traits::*;`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL)
+// https://url.spec.whatwg.org/#api
+// https://html.spec.whatwg.org/#htmlhyperlinkelementutils
+pub trait IUrlComponents: ReferenceType {
+    /// Returns the whole URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/href)
+    // https://url.spec.whatwg.org/#dom-url-href
+    #[inline]
+    fn href( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.href;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the origin of the URL, that is its scheme, host and port.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/origin)
+    // https://url.spec.whatwg.org/#dom-url-origin
+    #[inline]
+    fn origin( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.origin;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the scheme of the URL, including the final `:`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/protocol)
+    // https://url.spec.whatwg.org/#dom-url-protocol
+    #[inline]
+    fn protocol( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.protocol;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the scheme of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/protocol)
+    // https://url.spec.whatwg.org/#dom-url-protocol
+    #[inline]
+    fn set_protocol( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.protocol = @{value};
+        }
+    }
+
+    /// Returns the host of the URL, including the port if it isn't the default one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/host)
+    // https://url.spec.whatwg.org/#dom-url-host
+    #[inline]
+    fn host( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.host;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the host of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/host)
+    // https://url.spec.whatwg.org/#dom-url-host
+    #[inline]
+    fn set_host( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.host = @{value};
+        }
+    }
+
+    /// Returns the host name of the URL, without the port.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hostname)
+    // https://url.spec.whatwg.org/#dom-url-hostname
+    #[inline]
+    fn hostname( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.hostname;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the host name of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hostname)
+    // https://url.spec.whatwg.org/#dom-url-hostname
+    #[inline]
+    fn set_hostname( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.hostname = @{value};
+        }
+    }
+
+    /// Returns the port of the URL, or an empty string if it's the default one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/port)
+    // https://url.spec.whatwg.org/#dom-url-port
+    #[inline]
+    fn port( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.port;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the port of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/port)
+    // https://url.spec.whatwg.org/#dom-url-port
+    #[inline]
+    fn set_port( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.port = @{value};
+        }
+    }
+
+    /// Returns the path of the URL, starting with `/`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/pathname)
+    // https://url.spec.whatwg.org/#dom-url-pathname
+    #[inline]
+    fn pathname( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.pathname;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the path of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/pathname)
+    // https://url.spec.whatwg.org/#dom-url-pathname
+    #[inline]
+    fn set_pathname( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.pathname = @{value};
+        }
+    }
+
+    /// Returns the query string of the URL including the leading `?`, or an empty string if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/search)
+    // https://url.spec.whatwg.org/#dom-url-search
+    #[inline]
+    fn search( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.search;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the query string of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/search)
+    // https://url.spec.whatwg.org/#dom-url-search
+    #[inline]
+    fn set_search( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.search = @{value};
+        }
+    }
+
+    /// Returns the fragment of the URL including the leading `#`, or an empty string if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hash)
+    // https://url.spec.whatwg.org/#dom-url-hash
+    #[inline]
+    fn hash( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.hash;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the fragment of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hash)
+    // https://url.spec.whatwg.org/#dom-url-hash
+    #[inline]
+    fn set_hash( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.hash = @{value};
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::url::Url;
+    use webapi::html_elements::AnchorElement;
+
+    fn move_to_other_host< T: IUrlComponents >( url: &T ) -> String {
+        url.set_hostname( "other.example" );
+        url.set_port( "" );
+        url.href()
+    }
+
+    #[test]
+    fn test_generic_url_components() {
+        let url = Url::new( "https://example.com:8080/path?query#hash", None ).unwrap();
+        assert_eq!( move_to_other_host( &url ), "https://other.example/path?query#hash" );
+
+        let anchor: AnchorElement = js!( return document.createElement( "a" ); ).try_into().unwrap();
+        anchor.set_href( "https://example.com:8080/path?query#hash" );
+        assert_eq!( move_to_other_host( &anchor ), "https://other.example/path?query#hash" );
+        assert_eq!( anchor.origin(), url.origin() );
+    }
+}
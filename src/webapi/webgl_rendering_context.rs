@@ -0,0 +1,109 @@
+use webcore::value::{Reference, ConversionError};
+use webcore::try_from::TryInto;
+use webcore::instance_of::InstanceOf;
+use webapi::html_elements::CanvasElement;
+use webapi::rendering_context::RenderingContext;
+use webapi::offscreen_canvas::{OffscreenCanvas, OffscreenRenderingContext};
+
+/// A WebGL rendering context, which draws on a canvas with OpenGL ES 2.0.
+///
+/// Only the basics are bound here. The rest of the API can be used through
+/// `js!`, or through a crate with complete WebGL bindings implementing
+/// [RenderingContext](trait.RenderingContext.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext)
+// https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct WebGlRenderingContext( Reference );
+
+// This is implemented by hand since `WebGLRenderingContext` isn't
+// defined in browsers without WebGL support.
+impl InstanceOf for WebGlRenderingContext {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        __js_raw_asm!( "\
+            var o = Module.STDWEB_PRIVATE.acquire_js_reference( $0 );\
+            return (typeof WebGLRenderingContext !== \"undefined\" && o instanceof WebGLRenderingContext) | 0;\
+        ", reference.as_raw() ) == 1
+    }
+}
+
+impl RenderingContext for WebGlRenderingContext {
+    type Error = ConversionError;
+    fn from_canvas( canvas: &CanvasElement ) -> Result< Self, ConversionError > {
+        js!(
+            return @{canvas}.getContext( "webgl" );
+        ).try_into()
+    }
+}
+
+impl OffscreenRenderingContext for WebGlRenderingContext {
+    type Error = ConversionError;
+    fn from_offscreen_canvas( canvas: &OffscreenCanvas ) -> Result< Self, ConversionError > {
+        js!(
+            return @{canvas}.getContext( "webgl" );
+        ).try_into()
+    }
+}
+
+impl WebGlRenderingContext {
+    /// The bit passed to [clear](#method.clear) to clear the color buffer.
+    pub const COLOR_BUFFER_BIT: u32 = 0x4000;
+    /// The bit passed to [clear](#method.clear) to clear the depth buffer.
+    pub const DEPTH_BUFFER_BIT: u32 = 0x0100;
+    /// The bit passed to [clear](#method.clear) to clear the stencil buffer.
+    pub const STENCIL_BUFFER_BIT: u32 = 0x0400;
+
+    /// Returns the width of the drawing buffer in pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext/drawingBufferWidth)
+    // https://www.khronos.org/registry/webgl/specs/latest/1.0/#DOM-WebGLRenderingContext-drawingBufferWidth
+    pub fn drawing_buffer_width( &self ) -> u32 {
+        js!(
+            return @{self}.drawingBufferWidth;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the height of the drawing buffer in pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext/drawingBufferHeight)
+    // https://www.khronos.org/registry/webgl/specs/latest/1.0/#DOM-WebGLRenderingContext-drawingBufferHeight
+    pub fn drawing_buffer_height( &self ) -> u32 {
+        js!(
+            return @{self}.drawingBufferHeight;
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the context was lost, for example after a GPU reset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext/isContextLost)
+    // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.1
+    pub fn is_context_lost( &self ) -> bool {
+        js!(
+            return @{self}.isContextLost();
+        ).try_into().unwrap()
+    }
+
+    /// Sets the color used by [clear](#method.clear) for the color buffer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext/clearColor)
+    // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.11
+    pub fn clear_color( &self, red: f32, green: f32, blue: f32, alpha: f32 ) {
+        js! { @(no_return)
+            @{self}.clearColor( @{red}, @{green}, @{blue}, @{alpha} );
+        }
+    }
+
+    /// Clears the buffers selected by `mask`, a combination of
+    /// [COLOR_BUFFER_BIT](#associatedconstant.COLOR_BUFFER_BIT),
+    /// [DEPTH_BUFFER_BIT](#associatedconstant.DEPTH_BUFFER_BIT) and
+    /// [STENCIL_BUFFER_BIT](#associatedconstant.STENCIL_BUFFER_BIT).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebGLRenderingContext/clear)
+    // https://www.khronos.org/registry/webgl/specs/latest/1.0/#5.14.11
+    pub fn clear( &self, mask: u32 ) {
+        js! { @(no_return)
+            @{self}.clear( @{mask} );
+        }
+    }
+}
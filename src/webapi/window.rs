@@ -5,6 +5,17 @@ use webapi::window_or_worker::IWindowOrWorker;
 use webapi::storage::Storage;
 use webapi::location::Location;
 use webapi::history::History;
+use webapi::navigator::Navigator;
+use webapi::media_query_list::MediaQueryList;
+use webapi::selection::Selection;
+use webapi::screen::Screen;
+use webapi::performance::Performance;
+use webapi::window_proxy::WindowProxy;
+use webapi::cache_storage::CacheStorage;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::image_bitmap::{ImageBitmap, ImageBitmapSource, CreateImageBitmapError};
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
 use webcore::once::Once;
 use webcore::value::Value;
 
@@ -25,6 +36,70 @@ impl RequestAnimationFrameHandle {
     }
 }
 
+/// Specifies whether scrolling is animated.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScrollToOptions/behavior)
+// https://drafts.csswg.org/cssom-view/#enumdef-scrollbehavior
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ScrollBehavior {
+    /// The behavior is determined by the `scroll-behavior` CSS property.
+    Auto,
+    /// The scrolling is animated.
+    Smooth,
+    /// The scrolling happens in a single jump.
+    Instant
+}
+
+/// The position to scroll to (or by), as passed to [Window::scroll_to](struct.Window.html#method.scroll_to)
+/// and [Window::scroll_by](struct.Window.html#method.scroll_by).
+///
+/// Fields which are `None` leave the corresponding axis alone.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScrollToOptions)
+// https://drafts.csswg.org/cssom-view/#dictdef-scrolltooptions
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ScrollToOptions {
+    /// The horizontal position, in pixels.
+    pub left: Option< f64 >,
+
+    /// The vertical position, in pixels.
+    pub top: Option< f64 >,
+
+    /// Specifies whether the scrolling is animated.
+    pub behavior: ScrollBehavior
+}
+
+impl Default for ScrollToOptions {
+    fn default() -> Self {
+        ScrollToOptions {
+            left: None,
+            top: None,
+            behavior: ScrollBehavior::Auto
+        }
+    }
+}
+
+fn scroll_to_options( options: ScrollToOptions ) -> Value {
+    js!(
+        var options = {
+            behavior: @{match options.behavior {
+                ScrollBehavior::Auto => "auto",
+                ScrollBehavior::Smooth => "smooth",
+                ScrollBehavior::Instant => "instant"
+            }}
+        };
+        var left = @{options.left};
+        var top = @{options.top};
+        if( left !== null ) {
+            options.left = left;
+        }
+        if( top !== null ) {
+            options.top = top;
+        }
+        return options;
+    )
+}
+
 /// The `Window` object represents a window containing a DOM document.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window)
@@ -214,6 +289,46 @@ impl Window {
         ).try_into().unwrap()
     }
 
+    /// Returns the number of pixels the document is currently scrolled horizontally.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollX)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollx
+    pub fn scroll_x( &self ) -> f64 {
+        js!(
+            return @{self}.scrollX;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of pixels the document is currently scrolled vertically.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollY)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrolly
+    pub fn scroll_y( &self ) -> f64 {
+        js!(
+            return @{self}.scrollY;
+        ).try_into().unwrap()
+    }
+
+    /// Scrolls the document to the given position.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollTo)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scroll
+    pub fn scroll_to( &self, options: ScrollToOptions ) {
+        js! { @(no_return)
+            @{self}.scrollTo( @{scroll_to_options( options )} );
+        }
+    }
+
+    /// Scrolls the document by the given amount.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollBy)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollby
+    pub fn scroll_by( &self, options: ScrollToOptions ) {
+        js! { @(no_return)
+            @{self}.scrollBy( @{scroll_to_options( options )} );
+        }
+    }
+
     /// The ratio in resolution from physical pixels to CSS pixels
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio)
@@ -223,4 +338,122 @@ impl Window {
             return @{self}.devicePixelRatio;
         ).try_into().unwrap()
     }
+
+    /// Returns a reference to the [Navigator](struct.Navigator.html) object, which
+    /// can be queried for information about the application running the script.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/navigator)
+    // https://html.spec.whatwg.org/#the-window-object:dom-navigator
+    pub fn navigator( &self ) -> Navigator {
+        unsafe {
+            js!(
+                return @{self}.navigator;
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns the [Performance](struct.Performance.html) object of the window, which gives
+    /// access to timing information about the current page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/performance)
+    // https://w3c.github.io/hr-time/#the-performance-attribute
+    pub fn performance( &self ) -> Performance {
+        js!(
+            return @{self}.performance;
+        ).try_into().unwrap()
+    }
+
+    /// Returns a reference to the [Screen](struct.Screen.html) on which the window is being rendered.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/screen)
+    // https://drafts.csswg.org/cssom-view/#dom-window-screen
+    pub fn screen( &self ) -> Screen {
+        js!(
+            return @{self}.screen;
+        ).try_into().unwrap()
+    }
+
+    /// Loads `url` into the browsing context named `target`, creating a new window
+    /// or tab if no such browsing context exists, and returns it.
+    ///
+    /// `features` is a comma-separated list of window features such as `"width=500,height=600"`,
+    /// or an empty string.
+    ///
+    /// Returns `None` if the window couldn't be opened, for example because it
+    /// was blocked by a popup blocker or `noopener` was specified.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/open)
+    // https://html.spec.whatwg.org/#dom-open
+    pub fn open( &self, url: &str, target: &str, features: &str ) -> Option< WindowProxy > {
+        js!(
+            return @{self}.open( @{url}, @{target}, @{features} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the [CacheStorage](struct.CacheStorage.html) of the current origin.
+    ///
+    /// Returns `None` when the cache storage isn't available, for example
+    /// in insecure contexts.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/caches)
+    // https://w3c.github.io/ServiceWorker/#global-caches
+    pub fn caches( &self ) -> Option< CacheStorage > {
+        js!(
+            return @{self}.caches;
+        ).try_into().unwrap()
+    }
+
+    /// Returns a [Selection](struct.Selection.html) object representing the range of text
+    /// selected by the user or the current position of the caret.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/getSelection)
+    // https://w3c.github.io/selection-api/#dom-window-getselection
+    pub fn get_selection( &self ) -> Option< Selection > {
+        js!(
+            return @{self}.getSelection();
+        ).try_into().unwrap()
+    }
+
+    /// Returns a [MediaQueryList](struct.MediaQueryList.html) object representing
+    /// the results of the specified media query string.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/matchMedia)
+    // https://drafts.csswg.org/cssom-view/#dom-window-matchmedia
+    pub fn match_media( &self, query: &str ) -> MediaQueryList {
+        unsafe {
+            js!(
+                return @{self}.matchMedia( @{query} );
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Decodes the given `source` off the main thread and returns a future which
+    /// resolves to an [ImageBitmap](struct.ImageBitmap.html) that can be drawn to a canvas.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/createImageBitmap)
+    // https://html.spec.whatwg.org/#dom-createimagebitmap
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn create_image_bitmap< T: ImageBitmapSource >( &self, source: &T ) -> PromiseFuture< ImageBitmap, CreateImageBitmapError > {
+        js!(
+            return @{self}.createImageBitmap( @{source.as_ref()} );
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_to() {
+        window().scroll_to( ScrollToOptions {
+            left: Some( 0.0 ),
+            top: Some( 0.0 ),
+            behavior: ScrollBehavior::Instant
+        });
+
+        assert_eq!( window().scroll_x(), 0.0 );
+        assert_eq!( window().scroll_y(), 0.0 );
+        window().scroll_by( ScrollToOptions::default() );
+    }
 }
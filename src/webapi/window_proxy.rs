@@ -0,0 +1,105 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::instance_of::InstanceOf;
+use webapi::dom_exception::DataCloneError;
+
+/// A reference to another browsing context's `Window`, such as a popup
+/// opened with [Window::open](struct.Window.html#method.open).
+///
+/// The window may belong to another origin, in which case only the methods
+/// on this type can be used on it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Glossary/WindowProxy)
+// https://html.spec.whatwg.org/#windowproxy
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct WindowProxy( Reference );
+
+// This is implemented by hand since `instanceof` can't be used on cross-origin
+// windows, while their `window` property is still accessible.
+impl InstanceOf for WindowProxy {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        __js_raw_asm!( "\
+            var o = Module.STDWEB_PRIVATE.acquire_js_reference( $0 );\
+            try {\
+                return (o.window === o) | 0;\
+            } catch( error ) {\
+                return 0;\
+            }\
+        ", reference.as_raw() ) == 1
+    }
+}
+
+impl WindowProxy {
+    /// Closes the window.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/close)
+    // https://html.spec.whatwg.org/#dom-window-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+
+    /// Brings the window to the front.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/focus)
+    // https://html.spec.whatwg.org/#dom-window-focus
+    pub fn focus( &self ) {
+        js! { @(no_return)
+            @{self}.focus();
+        }
+    }
+
+    /// Returns whether the window has been closed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/closed)
+    // https://html.spec.whatwg.org/#dom-window-closed
+    pub fn closed( &self ) -> bool {
+        js!(
+            return @{self}.closed;
+        ).try_into().unwrap()
+    }
+
+    /// Sends a `message` to the window. The message is only delivered if the
+    /// window's origin matches `target_origin`, which can also be `"*"` to match
+    /// any origin.
+    ///
+    /// Fails with a `DataCloneError` if the `message` can't be cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage)
+    // https://html.spec.whatwg.org/#dom-window-postmessage
+    pub fn post_message( &self, message: &Value, target_origin: &str ) -> Result< (), DataCloneError > {
+        js_try!( @(no_return)
+            @{self}.postMessage( @{message}, @{target_origin} );
+        ).unwrap()
+    }
+
+    /// Sends a `message` to the window like [post_message](#method.post_message), and
+    /// transfers the ownership of the objects in `transfer`, such as
+    /// [MessagePort](struct.MessagePort.html)s or [ArrayBuffer](struct.ArrayBuffer.html)s,
+    /// to the window.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage)
+    // https://html.spec.whatwg.org/#dom-window-postmessage
+    pub fn post_message_with_transfer( &self, message: &Value, target_origin: &str, transfer: &[Reference] ) -> Result< (), DataCloneError > {
+        js_try!( @(no_return)
+            @{self}.postMessage( @{message}, @{target_origin}, @{transfer} );
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::window::window;
+
+    #[test]
+    fn test_open_and_close() {
+        // Popups may be blocked when running the tests, in which case there's nothing to check.
+        if let Some( popup ) = window().open( "about:blank", "_blank", "" ) {
+            assert!( !popup.closed() );
+            popup.post_message( &"hello".into(), "*" ).unwrap();
+            popup.close();
+        }
+    }
+}
@@ -5,6 +5,7 @@ use webcore::value::{
     Value,
 };
 use webcore::try_from::TryInto;
+use webapi::dom_exception::InvalidStateError;
 use private::TODO;
 
 /// Use XmlHttpRequest (XHR) objects to interact with servers.
@@ -57,6 +58,21 @@ pub enum XhrResponseType {
 
 impl IEventTarget for XmlHttpRequest {}
 
+/// The object associated with an [XmlHttpRequest](struct.XmlHttpRequest.html) which
+/// can be used to track the progress of the upload of the request's body.
+///
+/// It fires the same progress events as the `XmlHttpRequest` itself, for example
+/// [ProgressEvent](struct.ProgressEvent.html) and [ProgressLoadEvent](struct.ProgressLoadEvent.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/upload)
+// https://xhr.spec.whatwg.org/#xmlhttprequestupload
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "XMLHttpRequestUpload")]
+#[reference(subclass_of(EventTarget))]
+pub struct XmlHttpRequestUpload( Reference );
+
+impl IEventTarget for XmlHttpRequestUpload {}
+
 
 impl XmlHttpRequest {
     /// Creates new `XmlHttpRequest`.
@@ -99,6 +115,27 @@ impl XmlHttpRequest {
         }
     }
 
+    /// Sets the type of the response to the request, which determines the type
+    /// of the value returned by [raw_response](struct.XmlHttpRequest.html#method.raw_response).
+    ///
+    /// It must be called before the response starts loading; afterwards
+    /// this fails with an `InvalidStateError`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/responseType)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-responsetype
+    pub fn set_response_type(&self, response_type: XhrResponseType) -> Result< (), InvalidStateError > {
+        use self::XhrResponseType::*;
+        js_try! ( @(no_return)
+            @{self}.responseType = @{match response_type {
+                ArrayBuffer => "arraybuffer",
+                Blob => "blob",
+                Document => "document",
+                Json => "json",
+                Text => "text"
+            }};
+        ).unwrap()
+    }
+
     /// Returns a string that contains the response to the request as text, or None
     /// if the request was unsuccessful or has not yet been sent.
     ///
@@ -129,6 +166,15 @@ impl XmlHttpRequest {
         js!(return @{self}.status;).try_into().unwrap()
     }
 
+    /// Returns an [XmlHttpRequestUpload](struct.XmlHttpRequestUpload.html) object,
+    /// to which progress listeners can be attached to track the upload of the request's body.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/upload)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-upload
+    pub fn upload(&self) -> XmlHttpRequestUpload {
+        js!(return @{self}.upload;).try_into().unwrap()
+    }
+
     /// Open connection with given method (ie GET or POST), and the url to hit.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/open)
@@ -219,3 +265,38 @@ impl XmlHttpRequest {
         };
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::events::progress::LoadEndEvent;
+
+    #[test]
+    fn test_set_response_type() {
+        let request = XmlHttpRequest::new();
+        assert_eq!( request.response_type(), XhrResponseType::Text );
+
+        request.open( "GET", "/" ).unwrap();
+        for &response_type in &[
+            XhrResponseType::ArrayBuffer,
+            XhrResponseType::Blob,
+            XhrResponseType::Document,
+            XhrResponseType::Json,
+            XhrResponseType::Text
+        ] {
+            request.set_response_type( response_type ).unwrap();
+            assert_eq!( request.response_type(), response_type );
+        }
+
+        // Whether the request succeeds or not, it's done when `loadend` fires,
+        // so the response type can't be changed anymore.
+        request.add_event_listener( {
+            let request = request.clone();
+            move |_: LoadEndEvent| {
+                assert!( request.set_response_type( XhrResponseType::Blob ).is_err() );
+                assert_eq!( request.response_type(), XhrResponseType::Text );
+            }
+        });
+        request.send().unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::value::{Reference, ConversionError};
+use webcore::instance_of::InstanceOf;
+use webcore::number;
+
+/// A type representing a JavaScript `BigInt`.
+///
+/// Rust integers are always passed to JavaScript as plain numbers; to pass
+/// a 64-bit (or wider) integer without losing precision wrap it explicitly,
+/// for example `js!( return @{BigInt::from( id )}; )`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt)
+// https://tc39.github.io/ecma262/#sec-bigint-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct BigInt( Reference );
+
+// A `BigInt` is a primitive, so `instanceof` can't be used.
+impl InstanceOf for BigInt {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        __js_raw_asm!(
+            "return (typeof Module.STDWEB_PRIVATE.acquire_js_reference( $0 ) === \"bigint\") | 0;",
+            reference.as_raw()
+        ) == 1
+    }
+}
+
+impl BigInt {
+    fn to_decimal_string( &self ) -> String {
+        js!(
+            return @{self}.toString();
+        ).try_into().unwrap()
+    }
+}
+
+macro_rules! impl_big_int_conversions {
+    ($($kind:ty)+) => {
+        $(
+            impl From< $kind > for BigInt {
+                #[inline]
+                fn from( value: $kind ) -> Self {
+                    js!(
+                        return BigInt( @{value.to_string()} );
+                    ).try_into().unwrap()
+                }
+            }
+
+            impl TryFrom< BigInt > for $kind {
+                type Error = ConversionError;
+
+                #[inline]
+                fn try_from( value: BigInt ) -> Result< Self, Self::Error > {
+                    value.to_decimal_string().parse().map_err( |_| number::ConversionError::OutOfRange.into() )
+                }
+            }
+        )+
+    };
+}
+
+impl_big_int_conversions!( i64 u64 i128 u128 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use webcore::value::Value;
+
+    #[test]
+    fn round_trip() {
+        let value = BigInt::from( 9007199254740993_i64 );
+        let result: i64 = value.try_into().unwrap();
+        assert_eq!( result, 9007199254740993 );
+
+        let value = BigInt::from( u128::max_value() );
+        let result: u128 = value.try_into().unwrap();
+        assert_eq!( result, u128::max_value() );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let value = BigInt::from( -1_i64 );
+        let result: Result< u64, _ > = value.try_into();
+        assert!( result.is_err() );
+    }
+
+    #[test]
+    fn from_js() {
+        let value: BigInt = js!( return BigInt( 2 ) ** BigInt( 64 ); ).try_into().unwrap();
+        let result: u128 = value.clone().try_into().unwrap();
+        assert_eq!( result, 1 << 64 );
+
+        let result: Value = js!( return @{value} === BigInt( 2 ) ** BigInt( 64 ); );
+        assert_eq!( result, Value::Bool( true ) );
+
+        let result: Result< BigInt, _ > = js!( return 1; ).try_into();
+        assert!( result.is_err() );
+    }
+}
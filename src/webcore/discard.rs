@@ -40,6 +40,10 @@ impl< A: Discard > DiscardOnDrop< A > {
     ///
     /// It will no longer automatically call [`discarder.discard()`](https://docs.rs/discard/%5E1.0.3/discard/trait.Discard.html#tymethod.discard), so this will usually leak
     /// memory unless you manually call [`discarder.discard()`](https://docs.rs/discard/%5E1.0.3/discard/trait.Discard.html#tymethod.discard).
+    ///
+    /// A leaked value can be reclaimed later on by wrapping it again with
+    /// [`DiscardOnDrop::new`](#method.new), after which it will once again be
+    /// discarded when dropped.
     #[inline]
     pub fn leak( self ) -> A {
         discard::DiscardOnDrop::leak( self.0 )
@@ -148,6 +152,18 @@ mod tests {
         assert_eq!( dropped.get(), false );
     }
 
+    #[test]
+    fn leak_and_reclaim() {
+        let foo = DiscardOnDrop::new( Foo::new() );
+
+        let dropped = foo.dropped();
+
+        let foo = DiscardOnDrop::new( foo.leak() );
+        assert_eq!( dropped.get(), false );
+        drop( foo );
+        assert_eq!( dropped.get(), true );
+    }
+
     #[test]
     fn deref_mut() {
         let mut foo = DiscardOnDrop::new( Foo::new() );
@@ -1,6 +1,29 @@
 use webcore::value::Reference;
 
 /// A trait to check whenever a given [Reference](struct.Reference.html) is of a certain type.
+///
+/// This is what [Reference::downcast](struct.Reference.html#method.downcast) and the
+/// `TryFrom` conversions of every [ReferenceType](trait.ReferenceType.html) use to make
+/// sure that a JavaScript value really is of the expected type.
+///
+/// Both the [js_reference!](macro.js_reference.html) macro and `#[derive(ReferenceType)]`
+/// implement it with an `instanceof` check; implement it by hand when a different check
+/// is needed, for example for types which aren't exposed as a global constructor.
+///
+/// # Examples
+///
+/// ```rust
+/// impl InstanceOf for AudioContext {
+///     #[inline]
+///     fn instance_of( reference: &Reference ) -> bool {
+///         js!(
+///             var o = @{reference};
+///             return (typeof AudioContext !== "undefined" && o instanceof AudioContext) ||
+///                    (typeof webkitAudioContext !== "undefined" && o instanceof webkitAudioContext);
+///         ).try_into().unwrap()
+///     }
+/// }
+/// ```
 pub trait InstanceOf {
     /// Checks whenever a given [Reference](struct.Reference.html) if of type `Self`.
     fn instance_of( reference: &Reference ) -> bool;
@@ -0,0 +1,122 @@
+use webcore::try_from::TryInto;
+use webcore::value::{Reference, Value};
+
+/// An iterator over a JavaScript iterable, as created by
+/// [Value::into_iter](struct.Value.html#method.into_iter).
+///
+/// Every call to `next` calls the `next()` method of the underlying
+/// JavaScript iterator, stopping once it reports `done: true`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols)
+// https://tc39.github.io/ecma262/#sec-iteration
+#[derive(Debug)]
+pub struct JsIterator {
+    iterator: Reference,
+    done: bool
+}
+
+impl JsIterator {
+    pub(crate) fn new( iterable: &Value ) -> Option< Self > {
+        let iterator: Option< Reference > = js!(
+            var iterable = @{iterable};
+            if( iterable === null || iterable === undefined || typeof iterable[ Symbol.iterator ] !== "function" ) {
+                return null;
+            }
+            return iterable[ Symbol.iterator ]();
+        ).try_into().unwrap();
+
+        iterator.map( |iterator| JsIterator {
+            iterator,
+            done: false
+        })
+    }
+}
+
+impl Iterator for JsIterator {
+    type Item = Value;
+
+    fn next( &mut self ) -> Option< Self::Item > {
+        if self.done {
+            return None;
+        }
+
+        let result = js!(
+            var result = @{&self.iterator}.next();
+            return result.done ? null : [ result.value ];
+        );
+
+        if result.is_null() {
+            self.done = true;
+            return None;
+        }
+
+        let mut result: Vec< Value > = result.try_into().unwrap();
+        result.pop()
+    }
+}
+
+impl Drop for JsIterator {
+    fn drop( &mut self ) {
+        // Give the iterator a chance to clean up when we stop early,
+        // just like a `for-of` loop which was exited with `break`.
+        if !self.done {
+            js!( @(no_return)
+                var iterator = @{&self.iterator};
+                if( typeof iterator.return === "function" ) {
+                    iterator.return();
+                }
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterate_set() {
+        let values: Vec< Value > = js!( return new Set( [1, 2, 3] ); ).into_iter().unwrap().collect();
+        assert_eq!( values, vec![ Value::from( 1 ), Value::from( 2 ), Value::from( 3 ) ] );
+    }
+
+    #[test]
+    fn iterate_generator() {
+        let iterable = js!(
+            return (function*() {
+                yield "a";
+                yield "b";
+            })();
+        );
+        let values: Vec< String > = iterable.into_iter().unwrap().map( |value| value.try_into().unwrap() ).collect();
+        assert_eq!( values, vec![ "a".to_owned(), "b".to_owned() ] );
+    }
+
+    #[test]
+    fn early_return() {
+        let iterable = js!(
+            var state = { closed: false };
+            return {
+                state: state,
+                [Symbol.iterator]: function() {
+                    return {
+                        next: function() { return { done: false, value: 1 }; },
+                        return: function() { state.closed = true; return { done: true }; }
+                    };
+                }
+            };
+        );
+
+        let first = iterable.clone().into_iter().unwrap().next();
+        assert_eq!( first, Some( Value::from( 1 ) ) );
+        let closed: bool = js!( return @{&iterable}.state.closed; ).try_into().unwrap();
+        assert!( closed );
+    }
+
+    #[test]
+    fn not_iterable() {
+        assert!( js!( return 1; ).into_iter().is_none() );
+        assert!( js!( return {}; ).into_iter().is_none() );
+        assert!( Value::Undefined.into_iter().is_none() );
+    }
+}
@@ -19,6 +19,9 @@ macro_rules! foreach {
     };
 }
 
+// Determines the maximum number of arguments a Rust closure passed
+// into the `js!` macro can take; each identifier here adds one to it,
+// so with `A0` up to `A12` the limit is 13 arguments.
 macro_rules! loop_through_identifiers {
     ($callback:tt) => {
         foreach!( $callback => A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12 );
@@ -730,6 +733,159 @@ macro_rules! error_enum_boilerplate {
     }
 }
 
+/// Defines a typed wrapper for a JavaScript object.
+///
+/// This is meant for binding to Web APIs which aren't covered by this crate yet,
+/// without having to depend on `stdweb-derive`. Given the name of the Rust type and
+/// the name of the JavaScript constructor it will generate a newtype around a
+/// [Reference](struct.Reference.html) which implements [ReferenceType](trait.ReferenceType.html),
+/// an [InstanceOf](trait.InstanceOf.html) check (using `instanceof`), and the usual
+/// conversions from [Value](enum.Value.html) and [Reference](struct.Reference.html).
+///
+/// # Examples
+///
+/// ```rust
+/// js_reference! {
+///     /// A connection between the local computer and a remote peer.
+///     pub struct RtcPeerConnection = "RTCPeerConnection";
+/// }
+///
+/// impl RtcPeerConnection {
+///     pub fn new() -> Self {
+///         js!( return new RTCPeerConnection(); ).try_into().unwrap()
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! js_reference {
+    ($( #[ $meta:meta ] )* $vis:vis struct $name:ident = $js_name:tt;) => {
+        $( #[ $meta ] )*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        $vis struct $name( $crate::Reference );
+
+        impl $crate::InstanceOf for $name {
+            #[inline]
+            fn instance_of( reference: &$crate::Reference ) -> bool {
+                __js_raw_asm!(
+                    concat!( "return (Module.STDWEB_PRIVATE.acquire_js_reference( $0 ) instanceof ", $js_name, ") | 0;" ),
+                    reference.as_raw()
+                ) == 1
+            }
+        }
+
+        impl AsRef< $crate::Reference > for $name {
+            #[inline]
+            fn as_ref( &self ) -> &$crate::Reference {
+                &self.0
+            }
+        }
+
+        impl $crate::ReferenceType for $name {
+            #[inline]
+            unsafe fn from_reference_unchecked( reference: $crate::Reference ) -> Self {
+                $name( reference )
+            }
+        }
+
+        impl From< $name > for $crate::Reference {
+            #[inline]
+            fn from( value: $name ) -> Self {
+                value.0
+            }
+        }
+
+        impl $crate::unstable::TryFrom< $name > for $crate::Reference {
+            type Error = $crate::unstable::Void;
+
+            #[inline]
+            fn try_from( value: $name ) -> Result< Self, Self::Error > {
+                Ok( value.0 )
+            }
+        }
+
+        impl $crate::unstable::TryFrom< $crate::Reference > for $name {
+            type Error = $crate::private::ConversionError;
+
+            #[inline]
+            fn try_from( reference: $crate::Reference ) -> Result< Self, Self::Error > {
+                reference.downcast()
+                    .ok_or_else( || $crate::private::ConversionError::Custom( "reference is of a different type".into() ) )
+            }
+        }
+
+        impl< '_r > $crate::unstable::TryFrom< &'_r $crate::Reference > for $name {
+            type Error = $crate::private::ConversionError;
+
+            #[inline]
+            fn try_from( reference: &$crate::Reference ) -> Result< Self, Self::Error > {
+                $crate::unstable::TryFrom::try_from( reference.clone() )
+            }
+        }
+
+        impl $crate::unstable::TryFrom< $crate::Value > for $name {
+            type Error = $crate::private::ConversionError;
+
+            #[inline]
+            fn try_from( value: $crate::Value ) -> Result< Self, Self::Error > {
+                let reference: $crate::Reference = $crate::unstable::TryInto::try_into( value )?;
+                $crate::unstable::TryFrom::try_from( reference )
+            }
+        }
+
+        impl< '_r > $crate::unstable::TryFrom< &'_r $crate::Value > for $name {
+            type Error = $crate::private::ConversionError;
+
+            #[inline]
+            fn try_from( value: &$crate::Value ) -> Result< Self, Self::Error > {
+                let reference = value.as_reference()
+                    .ok_or_else( || $crate::private::ConversionError::Custom( "not a reference".into() ) )?;
+                $crate::unstable::TryFrom::try_from( reference )
+            }
+        }
+
+        impl $crate::private::JsSerialize for $name {
+            #[doc(hidden)]
+            #[inline]
+            fn _into_js< 'a >( &'a self ) -> $crate::private::SerializedValue< 'a > {
+                $crate::private::JsSerialize::_into_js( &self.0 )
+            }
+        }
+
+        __js_serializable_boilerplate!( $name );
+    };
+}
+
+/// Converts a [Value](enum.Value.html) holding a JavaScript `Promise` into a
+/// [PromiseFuture](struct.PromiseFuture.html).
+///
+/// The resulting future resolves to the promise's value, or fails with the
+/// rejection value, both as a [Value](enum.Value.html). Values which aren't
+/// promises are wrapped with `Promise.resolve`, so the future resolves to them immediately.
+///
+/// # Examples
+///
+/// ```rust
+/// let future = js_await!( js! { return fetch( @{url} ); } );
+///
+/// PromiseFuture::spawn_local(
+///     future.map( |response| {
+///         console!( log, response );
+///     }).map_err( |error| {
+///         console!( error, error );
+///     })
+/// );
+/// ```
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+#[macro_export]
+macro_rules! js_await {
+    ($value:expr) => {{
+        let value: $crate::Value = $value;
+        let future: $crate::PromiseFuture< $crate::Value, $crate::Value > =
+            $crate::unstable::TryInto::try_into( js!( return Promise.resolve( @{value} ); ) ).unwrap();
+        future
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use webcore::value::{ConversionError, Value};
@@ -806,4 +962,47 @@ mod tests {
         let output: Result< Value, String > = js_try!( return null; ).unwrap();
         assert_eq!( output, Ok( Value::Null ) );
     }
+
+    js_reference! {
+        pub struct TestRangeError = "RangeError";
+    }
+
+    #[test]
+    fn js_reference() {
+        use webcore::try_from::TryInto;
+
+        let error: TestRangeError = js!( return new RangeError( "Out of range" ); ).try_into().unwrap();
+        let message: String = js!( return @{&error}.message; ).try_into().unwrap();
+        assert_eq!( message, "Out of range" );
+
+        let error: Result< TestRangeError, _ > = js!( return new TypeError(); ).try_into();
+        assert!( error.is_err() );
+        let error: Result< TestRangeError, _ > = js!( return 1; ).try_into();
+        assert!( error.is_err() );
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn js_await() {
+        use futures_util::FutureExt;
+        use webcore::promise_future::PromiseFuture;
+
+        PromiseFuture::spawn_local(
+            js_await!( js!( return Promise.resolve( 42 ); ) )
+                .map( |value| assert_eq!( value, 42 ) )
+                .recover( |error| panic!( "Promise was rejected with {:?}", error ) )
+        );
+
+        PromiseFuture::spawn_local(
+            js_await!( js!( return Promise.reject( "rejected" ); ) )
+                .map( |value| panic!( "Promise was resolved with {:?}", value ) )
+                .recover( |error| assert_eq!( error, "rejected" ) )
+        );
+
+        PromiseFuture::spawn_local(
+            js_await!( js!( return "not a promise"; ) )
+                .map( |value| assert_eq!( value, "not a promise" ) )
+                .recover( |error| panic!( "Value was rejected with {:?}", error ) )
+        );
+    }
 }
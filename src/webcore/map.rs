@@ -0,0 +1,127 @@
+use webcore::try_from::TryInto;
+use webcore::value::{Reference, Value};
+use webcore::iterator::JsIterator;
+
+/// A type representing a JavaScript `Map`, a collection of key-value
+/// pairs where both keys and values can be any JavaScript value.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+// https://tc39.github.io/ecma262/#sec-map-objects
+#[derive(Clone, PartialEq, Eq, Debug, ReferenceType)]
+#[reference(instance_of = "Map")]
+pub struct Map( Reference );
+
+impl Map {
+    /// Creates a new, empty `Map`.
+    pub fn new() -> Self {
+        js!(
+            return new Map();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of entries in this map.
+    pub fn size( &self ) -> usize {
+        js!(
+            return @{self}.size;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the value associated with the given `key`, if any.
+    pub fn get( &self, key: &Value ) -> Option< Value > {
+        if self.has( key ) {
+            Some( js!( return @{self}.get( @{key} ); ) )
+        } else {
+            None
+        }
+    }
+
+    /// Associates `value` with the given `key`, replacing any previous value.
+    pub fn set< K: Into< Value >, V: Into< Value > >( &self, key: K, value: V ) {
+        js!( @(no_return)
+            @{self}.set( @{key.into()}, @{value.into()} );
+        );
+    }
+
+    /// Returns whether an entry with the given `key` exists.
+    pub fn has( &self, key: &Value ) -> bool {
+        js!(
+            return @{self}.has( @{key} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes the entry with the given `key`, returning whether such an entry existed.
+    pub fn delete( &self, key: &Value ) -> bool {
+        js!(
+            return @{self}.delete( @{key} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes all entries from this map.
+    pub fn clear( &self ) {
+        js!( @(no_return)
+            @{self}.clear();
+        );
+    }
+
+    /// Returns an iterator over the `(key, value)` pairs of this map, in insertion order.
+    pub fn entries( &self ) -> MapEntries {
+        MapEntries {
+            iter: Value::Reference( self.0.clone() ).into_iter().unwrap()
+        }
+    }
+}
+
+impl Default for Map {
+    #[inline]
+    fn default() -> Self {
+        Map::new()
+    }
+}
+
+/// An iterator over the entries of a [Map](struct.Map.html).
+#[derive(Debug)]
+pub struct MapEntries {
+    iter: JsIterator
+}
+
+impl Iterator for MapEntries {
+    type Item = ( Value, Value );
+
+    fn next( &mut self ) -> Option< Self::Item > {
+        self.iter.next().map( |entry| {
+            let mut entry: Vec< Value > = entry.try_into().unwrap();
+            let value = entry.pop().unwrap();
+            let key = entry.pop().unwrap();
+            ( key, value )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let map = Map::new();
+        assert_eq!( map.size(), 0 );
+
+        map.set( "one", 1 );
+        map.set( 2, "two" );
+        assert_eq!( map.size(), 2 );
+        assert!( map.has( &Value::from( "one" ) ) );
+        assert_eq!( map.get( &Value::from( 2 ) ), Some( Value::from( "two" ) ) );
+        assert_eq!( map.get( &Value::from( "two" ) ), None );
+
+        let entries: Vec< _ > = map.entries().collect();
+        assert_eq!( entries, vec![
+            ( Value::from( "one" ), Value::from( 1 ) ),
+            ( Value::from( 2 ), Value::from( "two" ) )
+        ]);
+
+        assert!( map.delete( &Value::from( "one" ) ) );
+        assert!( !map.delete( &Value::from( "one" ) ) );
+        map.clear();
+        assert_eq!( map.size(), 0 );
+    }
+}
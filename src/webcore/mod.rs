@@ -10,7 +10,11 @@ pub mod newtype;
 pub mod try_from;
 pub mod object;
 pub mod array;
+pub mod map;
+pub mod set;
 pub mod symbol;
+pub mod big_int;
+pub mod iterator;
 pub mod type_name;
 pub mod unsafe_typed_array;
 pub mod once;
@@ -53,6 +53,23 @@ pub fn get_storage( number: &Number ) -> &Storage {
     &number.0
 }
 
+// Unlike the `TryFrom` conversions this refuses any number which is
+// outside of the range where an `f64` can represent every integer exactly.
+pub(crate) fn get_safe_integer( number: &Number ) -> Result< i64, ConversionError > {
+    match number.0 {
+        Storage::I32( value ) => Ok( value as i64 ),
+        Storage::F64( value ) => {
+            if value.floor() != value {
+                Err( ConversionError::NotAnInteger )
+            } else if value >= MIN_SAFE_INTEGER_F64 as f64 && value <= MAX_SAFE_INTEGER_F64 as f64 {
+                Ok( value as i64 )
+            } else {
+                Err( ConversionError::OutOfRange )
+            }
+        }
+    }
+}
+
 impl AsRef< Number > for Number {
     #[inline]
     fn as_ref( &self ) -> &Self {
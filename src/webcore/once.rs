@@ -1,4 +1,7 @@
 use std::fmt;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::instance_of::InstanceOf;
 
 /// A wrapper for passing `FnOnce` callbacks into the `js!` macro.
 ///
@@ -8,6 +11,10 @@ use std::fmt;
 ///
 /// You still need to `drop()` any callbacks which were **not** called.
 ///
+/// The resulting JavaScript function can be converted into an
+/// [FnOnceHandle](struct.FnOnceHandle.html), which tells you whether
+/// it was already called.
+///
 /// # Examples
 ///
 /// ```rust
@@ -28,3 +35,43 @@ impl< T > fmt::Debug for Once< T > {
         write!( formatter, "Once" )
     }
 }
+
+/// A reference to the JavaScript function created from a [Once](struct.Once.html) callback.
+///
+/// # Examples
+///
+/// ```rust
+/// let handle: FnOnceHandle = js!( return @{Once( callback )}; ).try_into().unwrap();
+/// assert!( !handle.was_called() );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct FnOnceHandle( Reference );
+
+// This is implemented by hand since the function is a plain JavaScript
+// function, which is only recognizable by the `called` flag it carries.
+impl InstanceOf for FnOnceHandle {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        __js_raw_asm!(
+            "var f = Module.STDWEB_PRIVATE.acquire_js_reference( $0 ); return (typeof f === \"function\" && typeof f.called === \"boolean\") | 0;",
+            reference.as_raw()
+        ) == 1
+    }
+}
+
+impl FnOnceHandle {
+    /// Returns whether the callback was already called from JavaScript.
+    pub fn was_called( &self ) -> bool {
+        js!(
+            return @{self}.called;
+        ).try_into().unwrap()
+    }
+
+    /// Drops the callback without calling it. This does nothing if
+    /// it was already called or dropped.
+    pub fn drop( &self ) {
+        js! { @(no_return)
+            @{self}.drop();
+        }
+    }
+}
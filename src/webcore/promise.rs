@@ -106,9 +106,11 @@ impl Promise {
     ///
     /// This is needed when you want to pass a Rust Future into JavaScript.
     ///
+    /// The returned `Promise` can be passed to JavaScript as-is, e.g. through `js!`.
+    ///
     /// If you simply want to use a JavaScript Promise inside Rust, then you
     /// don't need to use this function: you should use
-    /// [`PromiseFuture`](struct.PromiseFuture.html) and the
+    /// [`from_value`](#method.from_value), [`PromiseFuture`](struct.PromiseFuture.html) and the
     /// [`FutureExt`](https://docs.rs/futures/0.2.*/futures/future/trait.FutureExt.html)
     /// methods instead.
     ///
@@ -161,6 +163,34 @@ impl Promise {
         js!( return new Promise( @{Once( callback )} ); ).try_into().unwrap()
     }
 
+    /// Adopts an arbitrary JavaScript value as a [`PromiseFuture`](struct.PromiseFuture.html).
+    ///
+    /// This follows the semantics of `Promise.resolve`: a `Promise` or Promise-like object
+    /// is adopted and the future resolves with its result, while any other value
+    /// makes the future resolve immediately with that value.
+    ///
+    /// This is useful when awaiting the result of a `js!` snippet which may or may not
+    /// return a `Promise`, without having to write a bespoke wrapper for it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let future: PromiseFuture< String > = Promise::from_value( js!( return fetch_name(); ) );
+    /// ```
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-promise.resolve
+    #[cfg(feature = "futures-support")]
+    pub fn from_value< A, B >( value: Value ) -> PromiseFuture< A, B >
+         where A: TryFrom< Value > + 'static,
+               B: TryFrom< Value > + 'static,
+               A::Error: fmt::Debug,
+               B::Error: fmt::Debug {
+
+        let promise: Promise = js!( return Promise.resolve( @{value} ); ).try_into().unwrap();
+        promise.to_future()
+    }
+
     /// This method is usually not needed, use [`PromiseFuture`](struct.PromiseFuture.html) instead.
     ///
     /// When the `Promise` either succeeds or fails, it calls the `callback` with the result.
@@ -3,6 +3,34 @@ use webcore::instance_of::InstanceOf;
 use webcore::try_from::TryFrom;
 
 /// A trait for types which wrap a reference to a JavaScript object.
+///
+/// This is the trait every typed binding in this crate (e.g. [Element](struct.Element.html)
+/// or [Blob](struct.Blob.html)) implements, and it's part of the public API so that other
+/// crates can define bindings for APIs which `stdweb` doesn't cover yet. A type
+/// implementing it can be passed into the `js!` macro, converted from a
+/// [Value](enum.Value.html) with `try_into`, and obtained from a [Reference](struct.Reference.html)
+/// through [Reference::downcast](struct.Reference.html#method.downcast).
+///
+/// There are two ways to implement it:
+///
+///   * with the [js_reference!](macro.js_reference.html) macro, which only needs
+///     the name of the JavaScript constructor, or
+///   * with `#[derive(ReferenceType)]` from the `stdweb-derive` crate, which
+///     additionally supports subclassing and event types.
+///
+/// # Examples
+///
+/// ```rust
+/// #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+/// #[reference(instance_of = "RTCPeerConnection")]
+/// #[reference(subclass_of(EventTarget))]
+/// pub struct RtcPeerConnection( Reference );
+///
+/// impl IEventTarget for RtcPeerConnection {}
+///
+/// let reference = js!( return new RTCPeerConnection(); ).into_reference().unwrap();
+/// let connection: RtcPeerConnection = reference.downcast().unwrap();
+/// ```
 pub trait ReferenceType: AsRef< Reference > + InstanceOf + TryFrom< Value > + TryFrom< Reference > {
     /// Converts a given reference into a concrete reference-like wrapper.
     /// Doesn't do any type checking; highly unsafe to use!
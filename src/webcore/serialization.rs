@@ -4,6 +4,7 @@ use std::i32;
 use std::collections::{BTreeMap, HashMap};
 use std::marker::PhantomData;
 use std::hash::Hash;
+use std::time::Duration;
 
 use webcore::ffi;
 use webcore::callfn::{CallOnce, CallMut};
@@ -599,6 +600,20 @@ impl JsSerialize for f64 {
 
 __js_serializable_boilerplate!( f64 );
 
+/// A `Duration` is passed to JavaScript as a number of **milliseconds**,
+/// which is the unit used by JavaScript's own timing APIs.
+impl JsSerialize for Duration {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self ) -> SerializedValue< 'a > {
+        SerializedUntaggedF64 {
+            value: self.as_secs() as f64 * 1000.0 + self.subsec_nanos() as f64 / 1_000_000.0
+        }.into()
+    }
+}
+
+__js_serializable_boilerplate!( Duration );
+
 impl JsSerialize for Number {
     #[doc(hidden)]
     #[inline]
@@ -751,6 +766,57 @@ impl< T: JsSerialize > JsSerializeOwned for Newtype< (NonFunctionTag, ()), T > {
     }
 }
 
+// This is what a Rust closure called from JS can return; an `Err`
+// is thrown by the JS wrapper of the closure instead of being returned.
+#[doc(hidden)]
+pub trait FuncallReturn: Sized {
+    type Value: JsSerializeOwned;
+    type Error: JsSerializeOwned;
+
+    fn into_result( self ) -> Result< Self::Value, Self::Error >;
+}
+
+impl< T: JsSerializeOwned > FuncallReturn for T {
+    type Value = T;
+    type Error = ();
+
+    #[inline]
+    fn into_result( self ) -> Result< Self::Value, Self::Error > {
+        Ok( self )
+    }
+}
+
+impl< T: JsSerializeOwned, E: JsSerializeOwned > FuncallReturn for Result< T, E > {
+    type Value = T;
+    type Error = E;
+
+    #[inline]
+    fn into_result( self ) -> Result< Self::Value, Self::Error > {
+        self
+    }
+}
+
+fn return_to_js< T: JsSerializeOwned >( value: T, is_error: bool ) {
+    let mut value = Some( value );
+    let value = JsSerializeOwned::into_js_owned( &mut value );
+    let value = &value as *const _;
+
+    // This is kinda hacky but I'm not sure how else to do it at the moment.
+    __js_raw_asm!(
+        "Module.STDWEB_PRIVATE.tmp = Module.STDWEB_PRIVATE.to_js( $0 ); Module.STDWEB_PRIVATE.tmp_is_error = $1 === 1;",
+        value,
+        is_error as i32
+    );
+}
+
+#[inline]
+fn return_result_to_js< R: FuncallReturn >( result: R ) {
+    match result.into_result() {
+        Ok( value ) => return_to_js( value, false ),
+        Err( error ) => return_to_js( error, true )
+    }
+}
+
 trait FuncallAdapter< F > {
     extern fn funcall_adapter( callback: *mut F, raw_arguments: *mut SerializedUntaggedArray );
     extern fn deallocator( callback: *mut F );
@@ -759,7 +825,7 @@ trait FuncallAdapter< F > {
 macro_rules! impl_for_fn {
     ($next:tt => $($kind:ident),*) => {
         impl< $($kind: TryFrom< Value >,)* F > FuncallAdapter< F > for Newtype< (FunctionTag, ($($kind,)*)), F >
-            where F: CallMut< ($($kind,)*) > + 'static, F::Output: JsSerializeOwned
+            where F: CallMut< ($($kind,)*) > + 'static, F::Output: FuncallReturn
         {
             #[allow(unused_mut, unused_variables, non_snake_case)]
             extern fn funcall_adapter(
@@ -799,13 +865,7 @@ macro_rules! impl_for_fn {
                 $crate::private::noop( &mut nth_argument );
 
                 let result = callback.call_mut( ($($kind,)*) );
-
-                let mut result = Some( result );
-                let result = JsSerializeOwned::into_js_owned( &mut result );
-                let result = &result as *const _;
-
-                // This is kinda hacky but I'm not sure how else to do it at the moment.
-                __js_raw_asm!( "Module.STDWEB_PRIVATE.tmp = Module.STDWEB_PRIVATE.to_js( $0 );", result );
+                return_result_to_js( result );
             }
 
             extern fn deallocator( callback: *mut F ) {
@@ -818,7 +878,7 @@ macro_rules! impl_for_fn {
         }
 
         impl< $($kind: TryFrom< Value >,)* F > FuncallAdapter< F > for Newtype< (FunctionTag, ($($kind,)*)), Once< F > >
-            where F: CallOnce< ($($kind,)*) > + 'static, F::Output: JsSerializeOwned
+            where F: CallOnce< ($($kind,)*) > + 'static, F::Output: FuncallReturn
         {
             #[allow(unused_mut, unused_variables, non_snake_case)]
             extern fn funcall_adapter(
@@ -862,13 +922,7 @@ macro_rules! impl_for_fn {
                 $crate::private::noop( &mut nth_argument );
 
                 let result = callback.call_once( ($($kind,)*) );
-
-                let mut result = Some( result );
-                let result = JsSerializeOwned::into_js_owned( &mut result );
-                let result = &result as *const _;
-
-                // This is kinda hacky but I'm not sure how else to do it at the moment.
-                __js_raw_asm!( "Module.STDWEB_PRIVATE.tmp = Module.STDWEB_PRIVATE.to_js( $0 );", result );
+                return_result_to_js( result );
             }
 
             extern fn deallocator( callback: *mut F ) {
@@ -881,7 +935,7 @@ macro_rules! impl_for_fn {
         }
 
         impl< $($kind: TryFrom< Value >,)* F > JsSerializeOwned for Newtype< (FunctionTag, ($($kind,)*)), Once< F > >
-            where F: CallOnce< ($($kind,)*) > + 'static, F::Output: JsSerializeOwned
+            where F: CallOnce< ($($kind,)*) > + 'static, F::Output: FuncallReturn
         {
             #[inline]
             fn into_js_owned< 'a >( value: &'a mut Option< Self > ) -> SerializedValue< 'a > {
@@ -897,7 +951,7 @@ macro_rules! impl_for_fn {
         }
 
         impl< $($kind: TryFrom< Value >,)* F > JsSerializeOwned for Newtype< (FunctionTag, ($($kind,)*)), F >
-            where F: CallMut< ($($kind,)*) > + 'static, F::Output: JsSerializeOwned
+            where F: CallMut< ($($kind,)*) > + 'static, F::Output: FuncallReturn
         {
             #[inline]
             fn into_js_owned< 'a >( value: &'a mut Option< Self > ) -> SerializedValue< 'a > {
@@ -913,7 +967,7 @@ macro_rules! impl_for_fn {
         }
 
         impl< $($kind: TryFrom< Value >,)* F > JsSerializeOwned for Newtype< (FunctionTag, ($($kind,)*)), Option< F > >
-            where F: CallMut< ($($kind,)*) > + 'static, F::Output: JsSerializeOwned
+            where F: CallMut< ($($kind,)*) > + 'static, F::Output: FuncallReturn
         {
             #[inline]
             fn into_js_owned< 'a >( value: &'a mut Option< Self > ) -> SerializedValue< 'a > {
@@ -1218,6 +1272,27 @@ mod test_deserialization {
         assert_eq!( result, Value::Bool( true ) );
     }
 
+    #[test]
+    fn function_once_reports_whether_it_was_called() {
+        use webcore::once::FnOnceHandle;
+
+        let handle: FnOnceHandle = js!( return @{Once( move || {} )}; ).try_into().unwrap();
+        assert!( !handle.was_called() );
+        js! { @(no_return) @{&handle}(); }
+        assert!( handle.was_called() );
+
+        // Dropping it after it was called does nothing.
+        handle.drop();
+        assert!( handle.was_called() );
+
+        let handle: FnOnceHandle = js!( return @{Once( move || {} )}; ).try_into().unwrap();
+        handle.drop();
+        assert!( !handle.was_called() );
+
+        let function: Result< FnOnceHandle, _ > = js!( return function() {}; ).try_into();
+        assert!( function.is_err() );
+    }
+
     #[test]
     fn function_once_calling_drop_twice_does_not_do_anything() {
         fn call< F: FnOnce() + 'static >( callback: F ) -> Value {
@@ -1240,6 +1315,14 @@ mod test_serialization {
     use super::*;
     use std::borrow::Cow;
 
+    #[test]
+    fn duration_as_milliseconds() {
+        let result = js! {
+            return @{Duration::from_millis( 1500 )} === 1500 && @{Duration::new( 0, 250_000 )} === 0.25;
+        };
+        assert_eq!( result, Value::Bool( true ) );
+    }
+
     #[test]
     fn object_from_btreemap() {
         let object: BTreeMap< _, _ > = [
@@ -1672,6 +1755,96 @@ mod test_reserialization {
         assert_eq!( value, Value::Number( 0x12345678_i32.into() ) );
     }
 
+    #[test]
+    fn closure_with_maximum_number_of_arguments() {
+        let callback = |a0: u32, a1: u32, a2: u32, a3: u32, a4: u32, a5: u32, a6: u32, a7: u32, a8: u32, a9: u32, a10: u32, a11: u32, a12: u32| {
+            a0 + a1 + a2 + a3 + a4 + a5 + a6 + a7 + a8 + a9 + a10 + a11 + a12
+        };
+
+        let value = js! {
+            return @{callback}( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13 );
+        };
+
+        assert_eq!( value, Value::Number( 91.into() ) );
+    }
+
+    #[test]
+    fn closure_returning_result() {
+        let callback = |fail: bool| -> Result< u32, String > {
+            if fail {
+                Err( "failed".to_owned() )
+            } else {
+                Ok( 1 )
+            }
+        };
+
+        let value = js! {
+            var callback = @{callback};
+            var errors = [];
+            var results = [];
+            [false, true].forEach( function( fail ) {
+                try {
+                    results.push( callback( fail ) );
+                } catch( error ) {
+                    errors.push( error );
+                }
+            });
+
+            return [results, errors];
+        };
+
+        assert_eq!( js!( return @{&value}[0][0]; ), 1 );
+        assert_eq!( js!( return @{&value}[0].length; ), 1 );
+        assert_eq!( js!( return @{&value}[1][0]; ), "failed" );
+    }
+
+    #[test]
+    fn closure_returning_unit_error() {
+        let callback = || -> Result< u32, () > { Err( () ) };
+        let identity = |value: u32| value;
+
+        let value = js! {
+            var callback = @{callback};
+            var identity = @{identity};
+            var thrown = false;
+            var error = null;
+            try {
+                callback();
+            } catch( e ) {
+                thrown = true;
+                error = e;
+            }
+
+            // A later call to an infallible closure must not throw.
+            return [thrown, error, identity( 2 )];
+        };
+
+        assert_eq!( js!( return @{&value}[0]; ), true );
+        assert_eq!( js!( return @{&value}[1]; ), Value::Undefined );
+        assert_eq!( js!( return @{&value}[2]; ), 2 );
+    }
+
+    #[test]
+    fn result_returning_closure_as_an_argument() {
+        let call = |callback: Value| -> Value {
+            js!(
+                try {
+                    return { value: @{callback}() };
+                } catch( error ) {
+                    return { error: error };
+                }
+            )
+        };
+
+        let ok = js!( return @{call}( @{|| -> Result< u32, String > { Ok( 1 ) }} ); );
+        assert_eq!( js!( return @{&ok}.value; ), 1 );
+        assert_eq!( js!( return "error" in @{&ok}; ), false );
+
+        let err = js!( return @{call}( @{|| -> Result< u32, String > { Err( "failed".to_owned() ) }} ); );
+        assert_eq!( js!( return "value" in @{&err}; ), false );
+        assert_eq!( js!( return @{&err}.error; ), "failed" );
+    }
+
     #[test]
     fn string_identity_function() {
         fn identity( string: String ) -> String {
@@ -0,0 +1,91 @@
+use webcore::try_from::TryInto;
+use webcore::value::{Reference, Value};
+use webcore::iterator::JsIterator;
+
+/// A type representing a JavaScript `Set`, a collection of unique values.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+// https://tc39.github.io/ecma262/#sec-set-objects
+#[derive(Clone, PartialEq, Eq, Debug, ReferenceType)]
+#[reference(instance_of = "Set")]
+pub struct Set( Reference );
+
+impl Set {
+    /// Creates a new, empty `Set`.
+    pub fn new() -> Self {
+        js!(
+            return new Set();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of values in this set.
+    pub fn size( &self ) -> usize {
+        js!(
+            return @{self}.size;
+        ).try_into().unwrap()
+    }
+
+    /// Adds `value` to this set, if it's not already present.
+    pub fn add< V: Into< Value > >( &self, value: V ) {
+        js!( @(no_return)
+            @{self}.add( @{value.into()} );
+        );
+    }
+
+    /// Returns whether `value` is present in this set.
+    pub fn has( &self, value: &Value ) -> bool {
+        js!(
+            return @{self}.has( @{value} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes `value` from this set, returning whether it was present.
+    pub fn delete( &self, value: &Value ) -> bool {
+        js!(
+            return @{self}.delete( @{value} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes all values from this set.
+    pub fn clear( &self ) {
+        js!( @(no_return)
+            @{self}.clear();
+        );
+    }
+
+    /// Returns an iterator over the values of this set, in insertion order.
+    pub fn values( &self ) -> JsIterator {
+        Value::Reference( self.0.clone() ).into_iter().unwrap()
+    }
+}
+
+impl Default for Set {
+    #[inline]
+    fn default() -> Self {
+        Set::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set() {
+        let set = Set::new();
+        set.add( 1 );
+        set.add( "two" );
+        set.add( 1 );
+        assert_eq!( set.size(), 2 );
+        assert!( set.has( &Value::from( "two" ) ) );
+        assert!( !set.has( &Value::from( 2 ) ) );
+
+        let values: Vec< _ > = set.values().collect();
+        assert_eq!( values, vec![ Value::from( 1 ), Value::from( "two" ) ] );
+
+        assert!( set.delete( &Value::from( 1 ) ) );
+        assert!( !set.delete( &Value::from( 1 ) ) );
+        set.clear();
+        assert_eq!( set.size(), 0 );
+    }
+}
@@ -5,6 +5,48 @@ use webcore::try_from::TryInto;
 #[derive(Debug)]
 pub struct Symbol( pub(crate) i32 );
 
+impl Symbol {
+    /// Creates a new unique symbol with an optional description.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+    pub fn new( description: Option< &str > ) -> Self {
+        js!(
+            var description = @{description};
+            return description === null ? Symbol() : Symbol( description );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the symbol registered in the global symbol registry under the given `key`,
+    /// creating it if it doesn't exist yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for)
+    pub fn for_key( key: &str ) -> Self {
+        js!(
+            return Symbol.for( @{key} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the well-known `Symbol.iterator`, the key of the method which
+    /// returns the default iterator of an object.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/iterator)
+    pub fn iterator() -> Self {
+        js!(
+            return Symbol.iterator;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the well-known `Symbol.asyncIterator`, the key of the method which
+    /// returns the default async iterator of an object.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/asyncIterator)
+    pub fn async_iterator() -> Self {
+        js!(
+            return Symbol.asyncIterator;
+        ).try_into().unwrap()
+    }
+}
+
 impl Clone for Symbol {
     fn clone( &self ) -> Self {
         let id = __js_raw_asm!( concat!(
@@ -39,3 +81,30 @@ impl From< Symbol > for Value {
         Value::Symbol( symbol )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_known_symbols() {
+        let iterator = Symbol::iterator();
+        assert_eq!( iterator, Symbol::iterator() );
+        assert_ne!( iterator, Symbol::async_iterator() );
+
+        let result: bool = js!(
+            var array = [1, 2];
+            return typeof array[ @{&iterator} ] === "function";
+        ).try_into().unwrap();
+        assert!( result );
+    }
+
+    #[test]
+    fn new_and_for_key() {
+        assert_ne!( Symbol::new( Some( "foo" ) ), Symbol::new( Some( "foo" ) ) );
+        assert_eq!( Symbol::for_key( "foo" ), Symbol::for_key( "foo" ) );
+
+        let description: String = js!( return @{Symbol::new( None )}.toString(); ).try_into().unwrap();
+        assert_eq!( description, "Symbol()" );
+    }
+}
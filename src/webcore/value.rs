@@ -3,6 +3,7 @@ use std::hash::Hash;
 use std::fmt;
 use std::error;
 use std::mem;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use webcore::void::Void;
 use webcore::try_from::{TryFrom, TryInto};
 use webcore::number::{self, Number};
@@ -12,6 +13,7 @@ use webcore::serialization::JsSerialize;
 use webcore::reference_type::ReferenceType;
 use webcore::instance_of::InstanceOf;
 use webcore::symbol::Symbol;
+use webcore::iterator::JsIterator;
 
 /// A unit type representing JavaScript's `undefined`.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
@@ -48,6 +50,8 @@ impl Reference {
 
     /// Converts this reference into the given type `T`; checks whenever the reference
     /// is really of type `T` and returns `None` if it's not.
+    ///
+    /// The check is done through the [InstanceOf](trait.InstanceOf.html) implementation of `T`.
     #[inline]
     pub fn downcast< T: ReferenceType >( self ) -> Option< T > {
         if T::instance_of( &self ) {
@@ -56,6 +60,64 @@ impl Reference {
             None
         }
     }
+
+    /// Calls the JavaScript function this reference points to with the given arguments.
+    ///
+    /// This is useful when the number of arguments is only known at runtime,
+    /// which isn't expressible with the `js!` macro.
+    pub fn call_self( &self, arguments: &[Value] ) -> Value {
+        js!(
+            return @{self}.apply( undefined, @{arguments} );
+        )
+    }
+
+    /// Calls the method called `name` of the JavaScript object this reference
+    /// points to with the given arguments.
+    pub fn call_method( &self, name: &str, arguments: &[Value] ) -> Value {
+        js!(
+            var object = @{self};
+            return object[ @{name} ].apply( object, @{arguments} );
+        )
+    }
+
+    /// Calls the JavaScript constructor this reference points to with the given
+    /// arguments, just like `new constructor( ...arguments )` would.
+    pub fn construct( &self, arguments: &[Value] ) -> Value {
+        js!(
+            return Reflect.construct( @{self}, @{arguments} );
+        )
+    }
+
+    /// Returns the value of the property called `key` of the JavaScript object
+    /// this reference points to.
+    pub fn get_property( &self, key: &str ) -> Value {
+        js!(
+            return @{self}[ @{key} ];
+        )
+    }
+
+    /// Sets the property called `key` of the JavaScript object this reference points to.
+    pub fn set_property< V: JsSerialize >( &self, key: &str, value: V ) {
+        js! { @(no_return)
+            @{self}[ @{key} ] = @{value};
+        }
+    }
+
+    /// Returns the element at the given `index` of the array-like JavaScript object
+    /// this reference points to.
+    pub fn get_index( &self, index: u32 ) -> Value {
+        js!(
+            return @{self}[ @{index} ];
+        )
+    }
+
+    /// Sets the element at the given `index` of the array-like JavaScript object
+    /// this reference points to.
+    pub fn set_index< V: JsSerialize >( &self, index: u32, value: V ) {
+        js! { @(no_return)
+            @{self}[ @{index} ] = @{value};
+        }
+    }
 }
 
 impl PartialEq for Reference {
@@ -153,6 +215,10 @@ impl_infallible_try_from! {
 /// let v4_r: Option< String > = v4.try_into().unwrap(); // Will be `None`.
 /// let v5_r: u64 = v5.try_into().unwrap();
 /// ```
+///
+/// When converting into an `Option` both `null` and `undefined` become `None`,
+/// and any other value has to convert into the inner type. In the other direction
+/// `None` is always passed to JavaScript as `null`.
 #[allow(missing_docs)]
 #[derive(Clone, PartialEq, Debug)]
 pub enum Value {
@@ -176,6 +242,28 @@ impl Value {
         }
     }
 
+    /// Checks whenever the Value is of the Undefined variant.
+    #[inline]
+    pub fn is_undefined( &self ) -> bool {
+        if let Value::Undefined = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks whenever the Value is either of the Null or of the Undefined variant.
+    ///
+    /// `undefined` and `null` are always kept apart when values are passed
+    /// between Rust and JavaScript; use this when both should be treated the same.
+    #[inline]
+    pub fn is_nullish( &self ) -> bool {
+        match *self {
+            Value::Null | Value::Undefined => true,
+            _ => false
+        }
+    }
+
     /// Checks whenever the Value is of the Symbol variant.
     #[inline]
     pub fn is_symbol( &self ) -> bool {
@@ -305,6 +393,41 @@ impl Value {
             _ => None
         }
     }
+
+    /// Returns an iterator over the values produced by this JavaScript iterable,
+    /// e.g. a `Map`, a `Set`, a `NodeList` or a generator.
+    ///
+    /// In cases where the value is not iterable a `None` is returned.
+    #[cfg_attr(feature = "cargo-clippy", allow(should_implement_trait))]
+    pub fn into_iter( self ) -> Option< JsIterator > {
+        JsIterator::new( &self )
+    }
+
+    /// Converts the number inside this `Value` into an `i64`.
+    ///
+    /// Since JavaScript numbers are `f64`s, integers beyond 2<sup>53</sup>
+    /// have most likely already been rounded. Unlike the `TryFrom` conversion
+    /// this will return an error if the number is not an exact integer or
+    /// lies outside of the safe integer range (`Number.MAX_SAFE_INTEGER`).
+    pub fn try_as_i64( &self ) -> Result< i64, ConversionError > {
+        match *self {
+            Value::Number( ref number ) => Ok( number::get_safe_integer( number )? ),
+            _ => Err( ConversionError::type_mismatch( self ) )
+        }
+    }
+
+    /// Converts the number inside this `Value` into an `u64`.
+    ///
+    /// Behaves like [try_as_i64](#method.try_as_i64), additionally
+    /// returning an error for negative numbers.
+    pub fn try_as_u64( &self ) -> Result< u64, ConversionError > {
+        let value = self.try_as_i64()?;
+        if value < 0 {
+            return Err( number::ConversionError::OutOfRange.into() );
+        }
+
+        Ok( value as u64 )
+    }
 }
 
 impl AsRef< Value > for Value {
@@ -964,6 +1087,51 @@ macro_rules! impl_try_into_number {
 
 impl_try_into_number!( u8 u16 u32 u64 usize i8 i16 i32 i64 f64 );
 
+fn duration_from_milliseconds( milliseconds: f64 ) -> Result< Duration, ConversionError > {
+    if !milliseconds.is_finite() || milliseconds < 0.0 || milliseconds / 1000.0 >= u64::max_value() as f64 {
+        return Err( ConversionError::Custom( format!( "{} milliseconds is not a valid duration", milliseconds ) ) );
+    }
+
+    let seconds = ( milliseconds / 1000.0 ).floor();
+    let nanoseconds = ( ( milliseconds - seconds * 1000.0 ) * 1_000_000.0 ).round().min( 999_999_999.0 );
+    Ok( Duration::new( seconds as u64, nanoseconds as u32 ) )
+}
+
+/// Converts a JavaScript number into a `Duration`.
+///
+/// Since JavaScript APIs measure time in milliseconds the number is
+/// interpreted as a (possibly fractional) amount of **milliseconds**.
+/// Negative and non-finite numbers are rejected.
+impl TryFrom< Value > for Duration {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let milliseconds: f64 = value.try_into()?;
+        duration_from_milliseconds( milliseconds )
+    }
+}
+
+/// Converts a JavaScript timestamp into a `SystemTime`.
+///
+/// The value must be a number of **milliseconds** since the Unix epoch,
+/// as returned by `Date.now()` or `Date.prototype.getTime()`.
+impl TryFrom< Value > for SystemTime {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let milliseconds: f64 = value.try_into()?;
+        let time = if milliseconds >= 0.0 {
+            UNIX_EPOCH.checked_add( duration_from_milliseconds( milliseconds )? )
+        } else {
+            UNIX_EPOCH.checked_sub( duration_from_milliseconds( -milliseconds )? )
+        };
+
+        time.ok_or_else( || ConversionError::Custom( format!( "{} milliseconds is out of range for a SystemTime", milliseconds ) ) )
+    }
+}
+
 impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Value > for BTreeMap< String, V > {
     type Error = ConversionError;
 
@@ -1134,6 +1302,20 @@ impl_nullable_try_from_value! {
     impl< T > Vec< T > where (T: TryFrom< Value, Error = ConversionError >);
     String;
     Symbol;
+    Duration;
+    SystemTime;
+}
+
+impl TryFrom< Value > for Option< Value > {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::Undefined | Value::Null => Ok( None ),
+            value => Ok( Some( value ) )
+        }
+    }
 }
 
 impl< 'a > TryFrom< &'a Value > for Option< &'a str > {
@@ -1175,6 +1357,8 @@ impl< T: TryFrom< Value, Error = ConversionError > + AsRef< Reference > > TryFro
 #[cfg(test)]
 mod tests {
     use super::{Value, Reference};
+    use std::collections::BTreeMap;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
     use webcore::try_from::TryInto;
 
     #[test]
@@ -1211,6 +1395,40 @@ mod tests {
         assert!( &reference == &value );
     }
 
+    #[test]
+    fn try_as_i64() {
+        assert_eq!( js!( return 123; ).try_as_i64(), Ok( 123 ) );
+        assert_eq!( js!( return -9007199254740991; ).try_as_i64(), Ok( -9007199254740991 ) );
+        assert_eq!( js!( return 9007199254740991; ).try_as_u64(), Ok( 9007199254740991 ) );
+        assert!( js!( return 9007199254740992; ).try_as_i64().is_err() );
+        assert!( js!( return 1.5; ).try_as_i64().is_err() );
+        assert!( js!( return -1; ).try_as_u64().is_err() );
+        assert!( js!( return "123"; ).try_as_i64().is_err() );
+    }
+
+    #[test]
+    fn duration_from_milliseconds() {
+        let duration: Duration = js!( return 1500; ).try_into().unwrap();
+        assert_eq!( duration, Duration::from_millis( 1500 ) );
+
+        let duration: Duration = js!( return 0.25; ).try_into().unwrap();
+        assert_eq!( duration, Duration::new( 0, 250_000 ) );
+
+        let result: Result< Duration, _ > = js!( return -1; ).try_into();
+        assert!( result.is_err() );
+        let result: Result< Duration, _ > = js!( return NaN; ).try_into();
+        assert!( result.is_err() );
+    }
+
+    #[test]
+    fn system_time_from_epoch_milliseconds() {
+        let time: SystemTime = js!( return new Date( 86400000 ).getTime(); ).try_into().unwrap();
+        assert_eq!( time, UNIX_EPOCH + Duration::from_secs( 86400 ) );
+
+        let time: SystemTime = js!( return -1000; ).try_into().unwrap();
+        assert_eq!( time, UNIX_EPOCH - Duration::from_secs( 1 ) );
+    }
+
     #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
     #[reference(instance_of = "Error")]
     pub struct Error( Reference );
@@ -1296,6 +1514,66 @@ mod tests {
         assert!( typed_reference.is_err() );
     }
 
+    #[test]
+    fn null_and_undefined_are_distinct() {
+        assert!( Value::Null.is_null() );
+        assert!( !Value::Null.is_undefined() );
+        assert!( Value::Undefined.is_undefined() );
+        assert!( !Value::Undefined.is_null() );
+        assert!( Value::Null.is_nullish() );
+        assert!( Value::Undefined.is_nullish() );
+        assert!( !Value::Bool( false ).is_nullish() );
+
+        assert_eq!( js! { return @{Value::Undefined}; }, Value::Undefined );
+        assert_eq!( js! { return @{Value::Null}; }, Value::Null );
+        assert_eq!( js! { return @{Value::Undefined} === undefined; }, Value::Bool( true ) );
+        assert_eq!( js! { return @{Value::Null} === null; }, Value::Bool( true ) );
+
+        let values: Vec< Value > = js! { return [undefined, null]; }.try_into().unwrap();
+        assert_eq!( values, vec![ Value::Undefined, Value::Null ] );
+        assert_eq!( js! {
+            var values = @{values};
+            return values[0] === undefined && values[1] === null;
+        }, Value::Bool( true ) );
+
+        let object: BTreeMap< String, Value > = js! { return { a: undefined, b: null }; }.try_into().unwrap();
+        assert_eq!( object[ "a" ], Value::Undefined );
+        assert_eq!( object[ "b" ], Value::Null );
+        assert_eq!( js! {
+            var object = @{object};
+            return object.a === undefined && "a" in object && object.b === null;
+        }, Value::Bool( true ) );
+    }
+
+    #[test]
+    fn convert_from_null_or_undefined_to_option() {
+        let a: Option< i32 > = js! { return null; }.try_into().unwrap();
+        assert_eq!( a, None );
+        let a: Option< i32 > = js! { return undefined; }.try_into().unwrap();
+        assert_eq!( a, None );
+        let a: Option< i32 > = js! { return 1; }.try_into().unwrap();
+        assert_eq!( a, Some( 1 ) );
+        let a: Result< Option< i32 >, _ > = js! { return "1"; }.try_into();
+        assert!( a.is_err() );
+
+        let a: Option< Value > = js! { return undefined; }.try_into().unwrap();
+        assert_eq!( a, None );
+        let a: Option< Value > = js! { return false; }.try_into().unwrap();
+        assert_eq!( a, Some( Value::Bool( false ) ) );
+
+        let a: Option< Duration > = js! { return null; }.try_into().unwrap();
+        assert_eq!( a, None );
+        let a: Option< Duration > = js! { return 1500; }.try_into().unwrap();
+        assert_eq!( a, Some( Duration::from_millis( 1500 ) ) );
+
+        let a: Option< SystemTime > = js! { return undefined; }.try_into().unwrap();
+        assert_eq!( a, None );
+
+        let none: Option< i32 > = None;
+        assert_eq!( js! { return @{none}; }, Value::Null );
+        assert_eq!( js! { return @{Some( 1 )}; }, Value::Number( 1.into() ) );
+    }
+
     #[test]
     fn convert_from_null_or_undefined_to_empty_tuple() {
         let a: Result< (), _ > = js! { return null; }.try_into();
@@ -1334,6 +1612,49 @@ mod tests {
         has_refcount
     }
 
+    #[test]
+    fn reference_call_self() {
+        let function: Reference = js!( return function( a, b ) { return a + b; }; ).try_into().unwrap();
+        let result = function.call_self( &[Value::from( 1 ), Value::from( 2 )] );
+        assert_eq!( result, 3 );
+    }
+
+    #[test]
+    fn reference_call_method() {
+        let array: Reference = js!( return [1, 2]; ).try_into().unwrap();
+        let length = array.call_method( "push", &[Value::from( 3 ), Value::from( 4 )] );
+        assert_eq!( length, 4 );
+        assert_eq!( array.call_method( "join", &[Value::from( "-" )] ), "1-2-3-4" );
+    }
+
+    #[test]
+    fn reference_construct() {
+        let constructor: Reference = js!( return Date; ).try_into().unwrap();
+        let date = constructor.construct( &[Value::from( 0 )] );
+        assert_eq!( js!( return @{&date} instanceof Date; ), true );
+        assert_eq!( js!( return @{&date}.getTime(); ), 0 );
+    }
+
+    #[test]
+    fn reference_properties() {
+        let object: Reference = js!( return { name: "Bob" }; ).try_into().unwrap();
+        assert_eq!( object.get_property( "name" ), "Bob" );
+        assert_eq!( object.get_property( "age" ), Value::Undefined );
+
+        object.set_property( "age", 42 );
+        assert_eq!( object.get_property( "age" ), 42 );
+    }
+
+    #[test]
+    fn reference_indices() {
+        let array: Reference = js!( return [1, 2]; ).try_into().unwrap();
+        assert_eq!( array.get_index( 1 ), 2 );
+
+        array.set_index( 2, "three" );
+        assert_eq!( array.get_index( 2 ), "three" );
+        assert_eq!( array.get_property( "length" ), 3 );
+    }
+
     #[test]
     fn reference_refcount() {
         let obj = js! { return new Object(); };
@@ -100,6 +100,11 @@ Module.STDWEB_PRIVATE.to_js = function to_js( address ) {
             var result = Module.STDWEB_PRIVATE.tmp;
             Module.STDWEB_PRIVATE.tmp = null;
 
+            if( Module.STDWEB_PRIVATE.tmp_is_error ) {
+                Module.STDWEB_PRIVATE.tmp_is_error = false;
+                throw result;
+            }
+
             return result;
         };
 
@@ -121,6 +126,7 @@ Module.STDWEB_PRIVATE.to_js = function to_js( address ) {
                 throw new ReferenceError( "Already called or dropped FnOnce function called!" );
             }
 
+            output.called = true;
             output.drop = Module.STDWEB_PRIVATE.noop;
             var function_pointer = pointer;
             pointer = 0;
@@ -131,9 +137,15 @@ Module.STDWEB_PRIVATE.to_js = function to_js( address ) {
             var result = Module.STDWEB_PRIVATE.tmp;
             Module.STDWEB_PRIVATE.tmp = null;
 
+            if( Module.STDWEB_PRIVATE.tmp_is_error ) {
+                Module.STDWEB_PRIVATE.tmp_is_error = false;
+                throw result;
+            }
+
             return result;
         };
 
+        output.called = false;
         output.drop = function() {
             output.drop = Module.STDWEB_PRIVATE.noop;
             var function_pointer = pointer;